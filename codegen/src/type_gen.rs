@@ -11,6 +11,7 @@ use crate::snake_to_camel;
 
 const PG_TYPE_DAT: &str = include_str!("pg_type.dat");
 const PG_RANGE_DAT: &str = include_str!("pg_range.dat");
+const GAUSS_TYPE_DAT: &str = include_str!("gauss_type.dat");
 
 struct Type {
     name: String,
@@ -23,7 +24,7 @@ struct Type {
 }
 
 pub fn build() {
-    let mut file = BufWriter::new(File::create("../postgres-types/src/type_gen.rs").unwrap());
+    let mut file = BufWriter::new(File::create("../gaussdb-types/src/type_gen.rs").unwrap());
     let types = parse_types();
 
     make_header(&mut file);
@@ -169,7 +170,8 @@ impl<'a> DatParser<'a> {
 }
 
 fn parse_types() -> BTreeMap<u32, Type> {
-    let raw_types = DatParser::new(PG_TYPE_DAT).parse_array();
+    let mut raw_types = DatParser::new(PG_TYPE_DAT).parse_array();
+    raw_types.extend(DatParser::new(GAUSS_TYPE_DAT).parse_array());
     let raw_ranges = DatParser::new(PG_RANGE_DAT).parse_array();
 
     let oids_by_name = raw_types
@@ -211,7 +213,12 @@ fn parse_types() -> BTreeMap<u32, Type> {
         let variant = snake_to_camel(&ident);
         let ident = ident.to_ascii_uppercase();
 
-        let kind = raw_type["typcategory"].clone();
+        let mut kind = raw_type["typcategory"].clone();
+
+        // arrays of records are pseudo-types, but are still decoded like any other array
+        if kind == "P" && raw_type.get("typinput").map(String::as_str) == Some("array_in") {
+            kind = "A".to_string();
+        }
 
         // we need to be able to pull composite fields and enum variants at runtime
         if kind == "C" || kind == "E" {
@@ -0,0 +1,45 @@
+use gaussdb::FromRow;
+
+#[derive(FromRow)]
+enum Foo {
+    A,
+}
+
+#[derive(FromRow)]
+struct Bar(i32);
+
+#[derive(FromRow)]
+#[gaussdb(crate = "gaussdb")]
+struct Baz {
+    #[gaussdb(flatten, rename = "x")]
+    a: Bar,
+}
+
+#[derive(FromRow)]
+#[gaussdb(crate = "gaussdb")]
+struct Qux {
+    #[gaussdb(try_from = "i64", with = "convert")]
+    a: i32,
+}
+
+#[derive(FromRow)]
+#[gaussdb(crate = "gaussdb")]
+struct Quux {
+    #[gaussdb(rename_all = "camelCase")]
+    a: i32,
+}
+
+#[derive(FromRow)]
+#[gaussdb(crate = "gaussdb", foo = "bar")]
+struct Corge {
+    a: i32,
+}
+
+#[derive(FromRow)]
+#[gaussdb(crate = "gaussdb")]
+struct Grault {
+    #[gaussdb(skip)]
+    a: i32,
+}
+
+fn main() {}
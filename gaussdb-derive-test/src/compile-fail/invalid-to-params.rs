@@ -0,0 +1,29 @@
+use gaussdb_types::ToParams;
+
+#[derive(ToParams)]
+enum Foo {
+    A,
+}
+
+#[derive(ToParams)]
+struct Bar(i32);
+
+#[derive(ToParams)]
+struct Baz {
+    #[gaussdb(flatten)]
+    a: i32,
+}
+
+#[derive(ToParams)]
+struct Qux {
+    #[gaussdb(rename_all = "camelCase")]
+    a: i32,
+}
+
+#[derive(ToParams)]
+#[gaussdb(skip)]
+struct Quux {
+    a: i32,
+}
+
+fn main() {}
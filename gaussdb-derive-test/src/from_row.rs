@@ -0,0 +1,226 @@
+use gaussdb::{Client, FromRow, NoTls, SimpleQueryMessage, SimpleQueryRow};
+use std::convert::TryFrom;
+use std::fmt;
+
+fn connect() -> Client {
+    Client::connect(
+        "user=gaussdb password=Gaussdb@123 host=localhost port=5433 dbname=postgres",
+        NoTls,
+    )
+    .unwrap()
+}
+
+fn simple_row(conn: &mut Client, query: &str) -> SimpleQueryRow {
+    conn.simple_query(query)
+        .unwrap()
+        .into_iter()
+        .find_map(|m| match m {
+            SimpleQueryMessage::Row(row) => Some(row),
+            _ => None,
+        })
+        .unwrap()
+}
+
+#[test]
+fn defaults() {
+    #[derive(FromRow, Debug, PartialEq)]
+    #[gaussdb(crate = "gaussdb")]
+    struct Item {
+        id: i32,
+        name: String,
+        price: Option<f64>,
+        r#type: bool,
+    }
+
+    let mut conn = connect();
+    let query = "SELECT 1::INT AS id, 'apple'::TEXT AS name, NULL::FLOAT8 AS price, true AS type";
+    let expected = Item {
+        id: 1,
+        name: "apple".to_string(),
+        price: None,
+        r#type: true,
+    };
+
+    let item = conn.query_one_as::<Item, _>(query, &[]).unwrap();
+    assert_eq!(item, expected);
+    assert_eq!(
+        Item::from_row(&simple_row(&mut conn, query)).unwrap(),
+        expected
+    );
+
+    let items = conn
+        .query_as::<Item, _>(
+            "SELECT i AS id, 'x' AS name, i * 1.5::FLOAT8 AS price, false AS type FROM generate_series(1, 3) i",
+            &[],
+        )
+        .unwrap();
+    assert_eq!(items.len(), 3);
+    assert_eq!(items[2].price, Some(4.5));
+
+    let item = conn
+        .query_opt_as::<Item, _>(&format!("{} WHERE false", query), &[])
+        .unwrap();
+    assert_eq!(item, None);
+}
+
+#[test]
+fn rename() {
+    #[derive(FromRow, Debug, PartialEq)]
+    #[gaussdb(crate = "gaussdb", rename_all = "camelCase")]
+    struct Item {
+        item_id: i32,
+        #[gaussdb(rename = "label")]
+        item_name: String,
+    }
+
+    let mut conn = connect();
+    let query = r#"SELECT 1 AS "itemId", 'apple' AS label"#;
+    let expected = Item {
+        item_id: 1,
+        item_name: "apple".to_string(),
+    };
+
+    assert_eq!(conn.query_one_as::<Item, _>(query, &[]).unwrap(), expected);
+    assert_eq!(
+        Item::from_row(&simple_row(&mut conn, query)).unwrap(),
+        expected
+    );
+}
+
+#[test]
+fn flatten_and_default() {
+    #[derive(FromRow, Debug, PartialEq)]
+    #[gaussdb(crate = "gaussdb")]
+    struct Audit {
+        created_by: String,
+        #[gaussdb(default)]
+        updated_by: Option<String>,
+    }
+
+    #[derive(FromRow, Debug, PartialEq)]
+    #[gaussdb(crate = "gaussdb")]
+    struct Item {
+        id: i32,
+        #[gaussdb(flatten)]
+        audit: Audit,
+        #[gaussdb(default)]
+        tags: Vec<String>,
+    }
+
+    let mut conn = connect();
+    let query = "SELECT 1 AS id, 'alice' AS created_by";
+    let item = conn.query_one_as::<Item, _>(query, &[]).unwrap();
+    assert_eq!(
+        item,
+        Item {
+            id: 1,
+            audit: Audit {
+                created_by: "alice".to_string(),
+                updated_by: None,
+            },
+            tags: vec![],
+        }
+    );
+
+    let row = conn
+        .query_one(
+            "SELECT 1 AS id, 'alice' AS created_by, 'bob' AS updated_by, ARRAY['a'] AS tags",
+            &[],
+        )
+        .unwrap();
+    let item = Item::from_row(&row).unwrap();
+    assert_eq!(item.audit.updated_by.as_deref(), Some("bob"));
+    assert_eq!(item.tags, ["a"]);
+
+    // defaults only apply to missing columns
+    let err = conn
+        .query_one_as::<Item, _>("SELECT 1 AS id, NULL::TEXT AS created_by", &[])
+        .unwrap_err();
+    assert!(err.to_string().contains("error deserializing column 1"));
+
+    let err = conn
+        .query_one_as::<Item, _>("SELECT 1 AS id", &[])
+        .unwrap_err();
+    assert!(err.to_string().contains("invalid column `created_by`"));
+}
+
+#[test]
+fn conversions() {
+    #[derive(Debug, PartialEq)]
+    struct Email(String);
+
+    #[derive(Debug)]
+    struct InvalidEmail;
+
+    impl fmt::Display for InvalidEmail {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("invalid email")
+        }
+    }
+
+    impl std::error::Error for InvalidEmail {}
+
+    fn parse_email(s: String) -> Result<Email, InvalidEmail> {
+        if s.contains('@') {
+            Ok(Email(s))
+        } else {
+            Err(InvalidEmail)
+        }
+    }
+
+    #[derive(FromRow, Debug, PartialEq)]
+    #[gaussdb(crate = "gaussdb")]
+    struct User {
+        #[gaussdb(try_from = "i32")]
+        age: u8,
+        #[gaussdb(with = "parse_email")]
+        email: Email,
+    }
+
+    let mut conn = connect();
+    let query = "SELECT 42 AS age, 'a@example.com' AS email";
+    let expected = User {
+        age: 42,
+        email: Email("a@example.com".to_string()),
+    };
+    assert_eq!(conn.query_one_as::<User, _>(query, &[]).unwrap(), expected);
+    assert_eq!(
+        User::from_row(&simple_row(&mut conn, query)).unwrap(),
+        expected
+    );
+
+    let err = conn
+        .query_one_as::<User, _>("SELECT 1000 AS age, 'a@example.com' AS email", &[])
+        .unwrap_err();
+    assert!(err.to_string().contains("error deserializing column 0"));
+    assert!(u8::try_from(1000i32).is_err());
+
+    let err = conn
+        .query_one_as::<User, _>("SELECT 1 AS age, 'nobody' AS email", &[])
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "error deserializing column 1: invalid email"
+    );
+}
+
+#[test]
+fn default_crate_path() {
+    #[derive(tokio_gaussdb::FromRow, Debug, PartialEq)]
+    struct Pair {
+        a: i32,
+        b: String,
+    }
+
+    let mut conn = connect();
+    let pair = conn
+        .query_one_as::<Pair, _>("SELECT 1 AS a, 'b' AS b", &[])
+        .unwrap();
+    assert_eq!(
+        pair,
+        Pair {
+            a: 1,
+            b: "b".to_string()
+        }
+    );
+}
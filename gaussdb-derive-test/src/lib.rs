@@ -7,6 +7,8 @@ use std::fmt;
 mod composites;
 mod domains;
 mod enums;
+mod from_row;
+mod to_params;
 mod transparent;
 
 pub fn test_type<T, S>(conn: &mut Client, sql_type: &str, checks: &[(T, S)])
@@ -0,0 +1,167 @@
+use gaussdb::binary_copy::BinaryCopyInWriter;
+use gaussdb::types::Type;
+use gaussdb::{Client, NoTls};
+use gaussdb_types::ToParams;
+
+fn connect() -> Client {
+    Client::connect(
+        "user=gaussdb password=Gaussdb@123 host=localhost port=5433 dbname=postgres",
+        NoTls,
+    )
+    .unwrap()
+}
+
+#[derive(ToParams)]
+struct NewItem {
+    id: i32,
+    name: String,
+    #[gaussdb(rename = "cost")]
+    price: Option<f64>,
+    #[gaussdb(skip)]
+    #[allow(dead_code)]
+    note: String,
+}
+
+fn item(id: i32, name: &str, price: Option<f64>) -> NewItem {
+    NewItem {
+        id,
+        name: name.to_string(),
+        price,
+        note: "not stored".to_string(),
+    }
+}
+
+fn create_items(conn: &mut Client) {
+    conn.batch_execute("CREATE TEMPORARY TABLE items (id INT, name TEXT, cost FLOAT8)")
+        .unwrap();
+}
+
+fn items(conn: &mut Client) -> Vec<(i32, String, Option<f64>)> {
+    conn.query("SELECT id, name, cost FROM items ORDER BY id", &[])
+        .unwrap()
+        .iter()
+        .map(|row| (row.get(0), row.get(1), row.get(2)))
+        .collect()
+}
+
+#[test]
+fn names() {
+    #[derive(ToParams)]
+    #[gaussdb(rename_all = "camelCase")]
+    struct Renamed {
+        user_id: i32,
+        r#type: String,
+        #[gaussdb(rename = "full name")]
+        name: String,
+    }
+
+    assert_eq!(Renamed::param_names(), &["userId", "type", "full name"]);
+    assert_eq!(Renamed::columns(), r#""userId", type, "full name""#);
+    assert_eq!(Renamed::placeholders(), "$1, $2, $3");
+
+    assert_eq!(NewItem::param_names(), &["id", "name", "cost"]);
+    assert_eq!(
+        NewItem::insert_query("items"),
+        "INSERT INTO items (id, name, cost) VALUES ($1, $2, $3)"
+    );
+    assert_eq!(
+        NewItem::copy_in_query("items"),
+        "COPY items (id, name, cost) FROM STDIN BINARY"
+    );
+}
+
+#[test]
+fn crate_path() {
+    #[derive(ToParams)]
+    #[gaussdb(crate = "gaussdb::types")]
+    struct Item<T> {
+        id: i32,
+        name: T,
+    }
+
+    let value = Item {
+        id: 1,
+        name: "apple",
+    };
+    assert_eq!(
+        <Item<&str> as gaussdb::types::ToParams>::param_names(),
+        &["id", "name"]
+    );
+    assert_eq!(value.to_params().len(), 2);
+}
+
+#[test]
+fn insert() {
+    let mut conn = connect();
+    create_items(&mut conn);
+
+    let query = NewItem::insert_query("items");
+    conn.execute(&*query, &item(1, "apple", Some(1.5)).to_params())
+        .unwrap();
+    conn.execute(&*query, &item(2, "pear", None).to_params())
+        .unwrap();
+
+    assert_eq!(
+        items(&mut conn),
+        vec![
+            (1, "apple".to_string(), Some(1.5)),
+            (2, "pear".to_string(), None),
+        ]
+    );
+}
+
+#[test]
+fn named() {
+    let mut conn = connect();
+    create_items(&mut conn);
+
+    let value = item(1, "apple", Some(1.5));
+    conn.execute_named(
+        "INSERT INTO items (cost, id, name) VALUES (:cost, :id, :name)",
+        &value.to_named_params(),
+    )
+    .unwrap();
+
+    assert_eq!(items(&mut conn), vec![(1, "apple".to_string(), Some(1.5))]);
+}
+
+#[test]
+fn generic() {
+    #[derive(ToParams)]
+    struct Pair<T> {
+        id: i32,
+        name: T,
+    }
+
+    let mut conn = connect();
+    create_items(&mut conn);
+
+    let value = Pair {
+        id: 1,
+        name: "apple",
+    };
+    conn.execute(&*Pair::<&str>::insert_query("items"), &value.to_params())
+        .unwrap();
+
+    assert_eq!(items(&mut conn), vec![(1, "apple".to_string(), None)]);
+}
+
+#[test]
+fn copy_in() {
+    let mut conn = connect();
+    create_items(&mut conn);
+
+    let sink = conn.copy_in(&*NewItem::copy_in_query("items")).unwrap();
+    let mut writer = BinaryCopyInWriter::new(sink, &[Type::INT4, Type::TEXT, Type::FLOAT8]);
+    writer.write_params(&item(1, "apple", Some(1.5))).unwrap();
+    writer.write_params(&item(2, "pear", None)).unwrap();
+    assert_eq!(writer.finish().unwrap(), 2);
+
+    assert_eq!(
+        items(&mut conn),
+        vec![
+            (1, "apple".to_string(), Some(1.5)),
+            (2, "pear".to_string(), None),
+        ]
+    );
+}
@@ -0,0 +1,307 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Attribute, Data, DataStruct, DeriveInput, Error, Fields, Ident, Meta, Path, Type};
+
+use crate::case::RenameRule;
+use crate::overrides::{attr_items, rename_rule, string_value};
+
+struct ContainerAttrs {
+    rename_all: Option<RenameRule>,
+    krate: Path,
+}
+
+struct FieldAttrs {
+    rename: Option<String>,
+    flatten: bool,
+    default: bool,
+    try_from: Option<Type>,
+    with: Option<Path>,
+}
+
+enum Source {
+    Column(String),
+    Flatten,
+}
+
+struct Field {
+    ident: Ident,
+    type_: Type,
+    source: Source,
+    default: bool,
+    try_from: Option<Type>,
+    with: Option<Path>,
+}
+
+pub fn expand_derive_fromrow(input: DeriveInput) -> Result<TokenStream, Error> {
+    let container = ContainerAttrs::extract(&input.attrs)?;
+
+    let fields = match input.data {
+        Data::Struct(DataStruct {
+            fields: Fields::Named(ref fields),
+            ..
+        }) => fields
+            .named
+            .iter()
+            .map(|field| Field::parse(field, container.rename_all))
+            .collect::<Result<Vec<_>, _>>()?,
+        _ => {
+            return Err(Error::new_spanned(
+                input,
+                "#[derive(FromRow)] may only be applied to structs with named fields",
+            ))
+        }
+    };
+
+    let krate = &container.krate;
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let row_fields = fields.iter().map(|f| f.expand(krate, false));
+    let simple_fields = fields.iter().map(|f| f.expand(krate, true));
+
+    // The simple query impl is bounded on its fields being decodable from text, so that deriving `FromRow` on a
+    // struct containing a type without a text representation only fails if it is actually used with a
+    // `SimpleQueryRow`. The bounds are higher-ranked to keep them from being rejected as trivially false when they
+    // do not mention a lifetime.
+    let mut simple_where = where_clause
+        .cloned()
+        .unwrap_or_else(|| syn::parse_quote!(where));
+    for field in &fields {
+        let ty = &field.type_;
+        let predicate = match (&field.source, field.source_type()) {
+            (Source::Flatten, _) => {
+                quote!(for<'__r> #ty: #krate::row::FromRow<#krate::row::SimpleQueryRow>)
+            }
+            (Source::Column(_), Some(ty)) => {
+                quote!(for<'__r> #ty: #krate::types::FromSqlText<'__r>)
+            }
+            (Source::Column(_), None) => continue,
+        };
+        simple_where.predicates.push(syn::parse2(predicate)?);
+    }
+
+    let out = quote! {
+        impl #impl_generics #krate::row::FromRow<#krate::row::Row> for #ident #ty_generics #where_clause {
+            fn from_row(row: &#krate::row::Row)
+                        -> std::result::Result<#ident #ty_generics, #krate::Error> {
+                std::result::Result::Ok(#ident {
+                    #(#row_fields,)*
+                })
+            }
+        }
+
+        impl #impl_generics #krate::row::FromRow<#krate::row::SimpleQueryRow> for #ident #ty_generics #simple_where {
+            fn from_row(row: &#krate::row::SimpleQueryRow)
+                        -> std::result::Result<#ident #ty_generics, #krate::Error> {
+                std::result::Result::Ok(#ident {
+                    #(#simple_fields,)*
+                })
+            }
+        }
+    };
+
+    Ok(out)
+}
+
+impl Field {
+    fn parse(raw: &syn::Field, rename_all: Option<RenameRule>) -> Result<Field, Error> {
+        let attrs = FieldAttrs::extract(&raw.attrs)?;
+        let ident = raw.ident.as_ref().unwrap().clone();
+
+        let source = if attrs.flatten {
+            Source::Flatten
+        } else {
+            // field level rename takes precedence over container level rename_all
+            let name = match attrs.rename {
+                Some(name) => name,
+                None => {
+                    let name = ident.to_string();
+                    let stripped = name.strip_prefix("r#").map(String::from).unwrap_or(name);
+
+                    match rename_all {
+                        Some(rule) => rule.apply_to_field(&stripped),
+                        None => stripped,
+                    }
+                }
+            };
+            Source::Column(name)
+        };
+
+        Ok(Field {
+            ident,
+            type_: raw.ty.clone(),
+            source,
+            default: attrs.default,
+            try_from: attrs.try_from,
+            with: attrs.with,
+        })
+    }
+
+    /// Returns the type read from the row before any conversion is applied, if it can be named.
+    fn source_type(&self) -> Option<TokenStream> {
+        match (&self.try_from, &self.with) {
+            (Some(ty), _) => Some(quote!(#ty)),
+            // the argument type of a `with` function is only known at the call site
+            (None, Some(_)) => None,
+            (None, None) => {
+                let ty = &self.type_;
+                Some(quote!(#ty))
+            }
+        }
+    }
+
+    fn expand(&self, krate: &Path, simple: bool) -> TokenStream {
+        let ident = &self.ident;
+        let ty = &self.type_;
+
+        let name = match &self.source {
+            Source::Flatten => {
+                let row_ty = if simple {
+                    quote!(#krate::row::SimpleQueryRow)
+                } else {
+                    quote!(#krate::row::Row)
+                };
+                return quote! {
+                    #ident: <#ty as #krate::row::FromRow<#row_ty>>::from_row(row)?
+                };
+            }
+            Source::Column(name) => name,
+        };
+
+        let get = |src: TokenStream| quote!(row.try_get::<_, #src>(#name)?);
+
+        let value = match (&self.try_from, &self.with) {
+            (Some(src), _) => {
+                let value = get(quote!(#src));
+                quote! {
+                    #krate::row::__private::convert(
+                        row.columns(), #name, #value, std::convert::TryFrom::try_from)?
+                }
+            }
+            (None, Some(with)) => {
+                let value = get(quote!(_));
+                quote!(#krate::row::__private::convert(row.columns(), #name, #value, #with)?)
+            }
+            (None, None) => get(quote!(#ty)),
+        };
+
+        if self.default {
+            quote! {
+                #ident: if #krate::row::__private::has_column(row.columns(), #name) {
+                    #value
+                } else {
+                    std::default::Default::default()
+                }
+            }
+        } else {
+            quote!(#ident: #value)
+        }
+    }
+}
+
+impl ContainerAttrs {
+    fn extract(attrs: &[Attribute]) -> Result<ContainerAttrs, Error> {
+        let mut out = ContainerAttrs {
+            rename_all: None,
+            krate: syn::parse_quote!(tokio_gaussdb),
+        };
+
+        for attr in attrs {
+            let items = match attr_items(attr)? {
+                Some(items) => items,
+                None => continue,
+            };
+
+            for item in items {
+                match item {
+                    Meta::NameValue(meta) if meta.path.is_ident("rename_all") => {
+                        out.rename_all = Some(rename_rule(&meta.value)?);
+                    }
+                    Meta::NameValue(meta) if meta.path.is_ident("crate") => {
+                        out.krate = string_value(&meta.value)?.parse()?;
+                    }
+                    Meta::NameValue(meta) => {
+                        return Err(Error::new_spanned(&meta.path, "unknown override"))
+                    }
+                    Meta::Path(path) => return Err(Error::new_spanned(path, "unknown override")),
+                    bad => return Err(Error::new_spanned(bad, "unknown attribute")),
+                }
+            }
+        }
+
+        Ok(out)
+    }
+}
+
+impl FieldAttrs {
+    fn extract(attrs: &[Attribute]) -> Result<FieldAttrs, Error> {
+        let mut out = FieldAttrs {
+            rename: None,
+            flatten: false,
+            default: false,
+            try_from: None,
+            with: None,
+        };
+        let mut flatten_span = None;
+
+        for attr in attrs {
+            let items = match attr_items(attr)? {
+                Some(items) => items,
+                None => continue,
+            };
+
+            for item in items {
+                match item {
+                    Meta::NameValue(meta) if meta.path.is_ident("rename") => {
+                        out.rename = Some(string_value(&meta.value)?.value());
+                    }
+                    Meta::NameValue(meta) if meta.path.is_ident("try_from") => {
+                        if out.with.is_some() {
+                            return Err(Error::new_spanned(
+                                &meta.path,
+                                "#[gaussdb(try_from = \"...\")] is not allowed with #[gaussdb(with = \"...\")]",
+                            ));
+                        }
+                        out.try_from = Some(string_value(&meta.value)?.parse()?);
+                    }
+                    Meta::NameValue(meta) if meta.path.is_ident("with") => {
+                        if out.try_from.is_some() {
+                            return Err(Error::new_spanned(
+                                &meta.path,
+                                "#[gaussdb(with = \"...\")] is not allowed with #[gaussdb(try_from = \"...\")]",
+                            ));
+                        }
+                        out.with = Some(string_value(&meta.value)?.parse()?);
+                    }
+                    Meta::NameValue(meta) if meta.path.is_ident("rename_all") => {
+                        return Err(Error::new_spanned(
+                            &meta.path,
+                            "rename_all is a container attribute",
+                        ))
+                    }
+                    Meta::NameValue(meta) => {
+                        return Err(Error::new_spanned(&meta.path, "unknown override"))
+                    }
+                    Meta::Path(path) if path.is_ident("flatten") => {
+                        out.flatten = true;
+                        flatten_span = Some(path);
+                    }
+                    Meta::Path(path) if path.is_ident("default") => out.default = true,
+                    Meta::Path(path) => return Err(Error::new_spanned(path, "unknown override")),
+                    bad => return Err(Error::new_spanned(bad, "unknown attribute")),
+                }
+            }
+        }
+
+        if let Some(path) = flatten_span {
+            if out.rename.is_some() || out.default || out.try_from.is_some() || out.with.is_some() {
+                return Err(Error::new_spanned(
+                    path,
+                    "#[gaussdb(flatten)] is not allowed with other field attributes",
+                ));
+            }
+        }
+
+        Ok(out)
+    }
+}
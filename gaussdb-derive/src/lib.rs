@@ -10,8 +10,10 @@ mod accepts;
 mod case;
 mod composites;
 mod enums;
+mod fromrow;
 mod fromsql;
 mod overrides;
+mod toparams;
 mod tosql;
 
 #[proc_macro_derive(ToSql, attributes(gaussdb, postgres))]
@@ -31,3 +33,21 @@ pub fn derive_fromsql(input: TokenStream) -> TokenStream {
         .unwrap_or_else(|e| e.to_compile_error())
         .into()
 }
+
+#[proc_macro_derive(FromRow, attributes(gaussdb, postgres))]
+pub fn derive_fromrow(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input);
+
+    fromrow::expand_derive_fromrow(input)
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
+
+#[proc_macro_derive(ToParams, attributes(gaussdb, postgres))]
+pub fn derive_toparams(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input);
+
+    toparams::expand_derive_toparams(input)
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
@@ -1,5 +1,5 @@
 use syn::punctuated::Punctuated;
-use syn::{Attribute, Error, Expr, ExprLit, Lit, Meta, Token};
+use syn::{Attribute, Error, Expr, ExprLit, Lit, LitStr, Meta, Token};
 
 use crate::case::{RenameRule, RENAME_RULES};
 
@@ -114,3 +114,53 @@ impl Overrides {
         Ok(overrides)
     }
 }
+
+/// Returns the items of a `#[gaussdb(...)]` or `#[postgres(...)]` attribute, or `None` for other attributes.
+pub fn attr_items(attr: &Attribute) -> Result<Option<Punctuated<Meta, Token![,]>>, Error> {
+    if !attr.path().is_ident("postgres") && !attr.path().is_ident("gaussdb") {
+        return Ok(None);
+    }
+
+    let attr_name = if attr.path().is_ident("postgres") {
+        "postgres"
+    } else {
+        "gaussdb"
+    };
+    let list = match &attr.meta {
+        Meta::List(ref list) => list,
+        bad => {
+            return Err(Error::new_spanned(
+                bad,
+                format!("expected a #[{}(...)]", attr_name),
+            ))
+        }
+    };
+
+    list.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)
+        .map(Some)
+}
+
+pub fn string_value(value: &Expr) -> Result<&LitStr, Error> {
+    match value {
+        Expr::Lit(ExprLit {
+            lit: Lit::Str(lit), ..
+        }) => Ok(lit),
+        bad => Err(Error::new_spanned(bad, "expected a string literal")),
+    }
+}
+
+pub fn rename_rule(value: &Expr) -> Result<RenameRule, Error> {
+    RenameRule::from_str(&string_value(value)?.value()).ok_or_else(|| {
+        Error::new_spanned(
+            value,
+            format!(
+                "invalid rename_all rule, expected one of: {}",
+                RENAME_RULES
+                    .iter()
+                    .map(|rule| format!("\"{}\"", rule))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        )
+    })
+}
@@ -0,0 +1,155 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Attribute, Data, DataStruct, DeriveInput, Error, Fields, Ident, Meta, Path};
+
+use crate::case::RenameRule;
+use crate::composites::append_generic_bound;
+use crate::overrides::{attr_items, rename_rule, string_value};
+
+struct ContainerAttrs {
+    rename_all: Option<RenameRule>,
+    krate: Path,
+}
+
+struct Field {
+    name: String,
+    ident: Ident,
+}
+
+pub fn expand_derive_toparams(input: DeriveInput) -> Result<TokenStream, Error> {
+    let container = ContainerAttrs::extract(&input.attrs)?;
+
+    let fields = match input.data {
+        Data::Struct(DataStruct {
+            fields: Fields::Named(ref fields),
+            ..
+        }) => {
+            let mut out = vec![];
+            for field in &fields.named {
+                if let Some(field) = Field::parse(field, container.rename_all)? {
+                    out.push(field);
+                }
+            }
+            out
+        }
+        _ => {
+            return Err(Error::new_spanned(
+                input,
+                "#[derive(ToParams)] may only be applied to structs with named fields",
+            ))
+        }
+    };
+
+    let krate = &container.krate;
+    let ident = &input.ident;
+    let generics =
+        append_generic_bound(input.generics.to_owned(), &syn::parse_quote!(#krate::ToSql));
+    let generics = append_generic_bound(generics, &syn::parse_quote!(std::marker::Sync));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let names = fields.iter().map(|f| &f.name);
+    let idents = fields.iter().map(|f| &f.ident);
+
+    let out = quote! {
+        impl #impl_generics #krate::ToParams for #ident #ty_generics #where_clause {
+            fn param_names() -> &'static [&'static str] {
+                &[#(#names),*]
+            }
+
+            fn to_params(&self) -> std::vec::Vec<&(dyn #krate::ToSql + std::marker::Sync)> {
+                std::vec![#(&self.#idents),*]
+            }
+        }
+    };
+
+    Ok(out)
+}
+
+impl Field {
+    fn parse(raw: &syn::Field, rename_all: Option<RenameRule>) -> Result<Option<Field>, Error> {
+        let ident = raw.ident.as_ref().unwrap().clone();
+        let mut rename = None;
+        let mut skip = false;
+
+        for attr in &raw.attrs {
+            let items = match attr_items(attr)? {
+                Some(items) => items,
+                None => continue,
+            };
+
+            for item in items {
+                match item {
+                    Meta::NameValue(meta) if meta.path.is_ident("rename") => {
+                        rename = Some(string_value(&meta.value)?.value());
+                    }
+                    Meta::NameValue(meta) if meta.path.is_ident("rename_all") => {
+                        return Err(Error::new_spanned(
+                            &meta.path,
+                            "rename_all is a container attribute",
+                        ))
+                    }
+                    Meta::NameValue(meta) => {
+                        return Err(Error::new_spanned(&meta.path, "unknown override"))
+                    }
+                    Meta::Path(path) if path.is_ident("skip") => skip = true,
+                    Meta::Path(path) => return Err(Error::new_spanned(path, "unknown override")),
+                    bad => return Err(Error::new_spanned(bad, "unknown attribute")),
+                }
+            }
+        }
+
+        if skip {
+            return Ok(None);
+        }
+
+        // field level rename takes precedence over container level rename_all
+        let name = match rename {
+            Some(name) => name,
+            None => {
+                let name = ident.to_string();
+                let stripped = name.strip_prefix("r#").map(String::from).unwrap_or(name);
+
+                match rename_all {
+                    Some(rule) => rule.apply_to_field(&stripped),
+                    None => stripped,
+                }
+            }
+        };
+
+        Ok(Some(Field { name, ident }))
+    }
+}
+
+impl ContainerAttrs {
+    fn extract(attrs: &[Attribute]) -> Result<ContainerAttrs, Error> {
+        let mut out = ContainerAttrs {
+            rename_all: None,
+            krate: syn::parse_quote!(gaussdb_types),
+        };
+
+        for attr in attrs {
+            let items = match attr_items(attr)? {
+                Some(items) => items,
+                None => continue,
+            };
+
+            for item in items {
+                match item {
+                    Meta::NameValue(meta) if meta.path.is_ident("rename_all") => {
+                        out.rename_all = Some(rename_rule(&meta.value)?);
+                    }
+                    Meta::NameValue(meta) if meta.path.is_ident("crate") => {
+                        out.krate = string_value(&meta.value)?.parse()?;
+                    }
+                    Meta::NameValue(meta) => {
+                        return Err(Error::new_spanned(&meta.path, "unknown override"))
+                    }
+                    Meta::Path(path) => return Err(Error::new_spanned(path, "unknown override")),
+                    bad => return Err(Error::new_spanned(bad, "unknown attribute")),
+                }
+            }
+        }
+
+        Ok(out)
+    }
+}
@@ -153,3 +153,31 @@ async fn runtime() {
     assert_eq!(rows.len(), 1);
     assert_eq!(rows[0].get::<_, i32>(0), 1);
 }
+
+#[tokio::test]
+#[ignore] // GaussDB test environment doesn't support TLS/SSL connections
+#[cfg(feature = "runtime")]
+async fn runtime_statement_timeout() {
+    let mut builder = SslConnector::builder(SslMethod::tls()).unwrap();
+    builder.set_ca_file("../test/server.crt").unwrap();
+    let connector = MakeTlsConnector::new(builder.build());
+
+    let mut config = "host=localhost port=5433 user=ssl_user password=password sslmode=require"
+        .parse::<tokio_gaussdb::Config>()
+        .unwrap();
+    config.statement_timeout(std::time::Duration::from_millis(100));
+    let (mut client, connection) = config.connect(connector.clone()).await.unwrap();
+    let connection = connection.map(|r| r.unwrap());
+    tokio::spawn(connection);
+    client.set_cancel_tls(connector);
+
+    // the cancellation request is sent over TLS as well
+    let err = client
+        .batch_execute("SELECT pg_sleep(100)")
+        .await
+        .unwrap_err();
+    assert!(err.is_timeout());
+
+    let rows = client.query("SELECT 1", &[]).await.unwrap();
+    assert_eq!(rows.len(), 1);
+}
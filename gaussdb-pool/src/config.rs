@@ -0,0 +1,186 @@
+//! Pool configuration.
+
+use crate::{Error, Pool};
+use std::time::Duration;
+use tokio_gaussdb::tls::{MakeTlsConnect, TlsConnect};
+use tokio_gaussdb::{Config, Socket};
+
+/// How a pooled connection is checked before it is handed out again.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum RecycleCheck {
+    /// Only check that the connection has not been closed.
+    ///
+    /// This does not involve the server, but will not detect a connection that has been dropped silently.
+    Fast,
+    /// Check that the connection has not been closed, then execute an empty query on it, waiting at most the
+    /// specified amount of time for the response.
+    Ping(Duration),
+}
+
+/// Connection pool configuration.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PoolConfig {
+    pub(crate) min_size: usize,
+    pub(crate) max_size: usize,
+    pub(crate) acquire_timeout: Option<Duration>,
+    pub(crate) idle_timeout: Option<Duration>,
+    pub(crate) max_lifetime: Option<Duration>,
+    pub(crate) recycle_check: RecycleCheck,
+    pub(crate) statement_cache_capacity: usize,
+    pub(crate) maintenance_interval: Duration,
+}
+
+impl Default for PoolConfig {
+    fn default() -> PoolConfig {
+        PoolConfig::new()
+    }
+}
+
+impl PoolConfig {
+    /// Creates a new configuration.
+    pub fn new() -> PoolConfig {
+        PoolConfig {
+            min_size: 0,
+            max_size: 10,
+            acquire_timeout: Some(Duration::from_secs(30)),
+            idle_timeout: Some(Duration::from_secs(10 * 60)),
+            max_lifetime: Some(Duration::from_secs(30 * 60)),
+            recycle_check: RecycleCheck::Fast,
+            statement_cache_capacity: 100,
+            maintenance_interval: Duration::from_secs(30),
+        }
+    }
+
+    /// Sets the number of connections the pool tries to keep open, even when they are idle.
+    ///
+    /// Defaults to 0.
+    pub fn min_size(&mut self, min_size: usize) -> &mut PoolConfig {
+        self.min_size = min_size;
+        self
+    }
+
+    /// Gets the minimum number of connections.
+    pub fn get_min_size(&self) -> usize {
+        self.min_size
+    }
+
+    /// Sets the maximum number of connections managed by the pool, whether idle or in use.
+    ///
+    /// Defaults to 10.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_size` is 0.
+    pub fn max_size(&mut self, max_size: usize) -> &mut PoolConfig {
+        assert!(max_size > 0, "max_size must be positive");
+        self.max_size = max_size;
+        self
+    }
+
+    /// Gets the maximum number of connections.
+    pub fn get_max_size(&self) -> usize {
+        self.max_size
+    }
+
+    /// Sets how long a request for a client waits before giving up, including the time taken to open a new
+    /// connection.
+    ///
+    /// Defaults to 30 seconds. `None` waits forever.
+    pub fn acquire_timeout(&mut self, acquire_timeout: Option<Duration>) -> &mut PoolConfig {
+        self.acquire_timeout = acquire_timeout;
+        self
+    }
+
+    /// Gets the acquire timeout.
+    pub fn get_acquire_timeout(&self) -> Option<&Duration> {
+        self.acquire_timeout.as_ref()
+    }
+
+    /// Sets how long a connection may stay idle before it is closed.
+    ///
+    /// Connections are not closed for being idle if that would leave fewer than `min_size` connections. Defaults to
+    /// 10 minutes. `None` keeps idle connections open forever.
+    pub fn idle_timeout(&mut self, idle_timeout: Option<Duration>) -> &mut PoolConfig {
+        self.idle_timeout = idle_timeout;
+        self
+    }
+
+    /// Gets the idle timeout.
+    pub fn get_idle_timeout(&self) -> Option<&Duration> {
+        self.idle_timeout.as_ref()
+    }
+
+    /// Sets how long a connection may be used before it is closed.
+    ///
+    /// Connections which are in use when they expire are closed when they are returned to the pool. Defaults to 30
+    /// minutes. `None` allows connections to be used forever.
+    pub fn max_lifetime(&mut self, max_lifetime: Option<Duration>) -> &mut PoolConfig {
+        self.max_lifetime = max_lifetime;
+        self
+    }
+
+    /// Gets the maximum lifetime.
+    pub fn get_max_lifetime(&self) -> Option<&Duration> {
+        self.max_lifetime.as_ref()
+    }
+
+    /// Sets the check run on an idle connection before it is handed out again.
+    ///
+    /// Connections failing the check are closed. Defaults to `RecycleCheck::Fast`.
+    pub fn recycle_check(&mut self, recycle_check: RecycleCheck) -> &mut PoolConfig {
+        self.recycle_check = recycle_check;
+        self
+    }
+
+    /// Gets the recycle check.
+    pub fn get_recycle_check(&self) -> RecycleCheck {
+        self.recycle_check
+    }
+
+    /// Sets the number of prepared statements cached by each connection.
+    ///
+    /// This overrides the capacity set in the connection's `Config`, so the cache is also used for query strings passed
+    /// to methods like `query` and `execute`.
+    ///
+    /// Defaults to 100. A capacity of 0 disables the cache.
+    pub fn statement_cache_capacity(&mut self, capacity: usize) -> &mut PoolConfig {
+        self.statement_cache_capacity = capacity;
+        self
+    }
+
+    /// Gets the statement cache capacity.
+    pub fn get_statement_cache_capacity(&self) -> usize {
+        self.statement_cache_capacity
+    }
+
+    /// Sets how often the pool closes expired idle connections and opens new ones to maintain `min_size`.
+    ///
+    /// Defaults to 30 seconds.
+    pub fn maintenance_interval(&mut self, maintenance_interval: Duration) -> &mut PoolConfig {
+        self.maintenance_interval = maintenance_interval;
+        self
+    }
+
+    /// Gets the maintenance interval.
+    pub fn get_maintenance_interval(&self) -> Duration {
+        self.maintenance_interval
+    }
+
+    /// Creates a pool of connections to the database described by `config`, opening `min_size` connections up
+    /// front.
+    ///
+    /// The pool spawns its connections and a maintenance task onto the current tokio runtime.
+    pub async fn connect<T>(&self, config: Config, tls: T) -> Result<Pool, Error>
+    where
+        T: MakeTlsConnect<Socket> + Clone + Sync + Send + 'static,
+        T::Stream: Sync + Send,
+        T::TlsConnect: Sync + Send,
+        <T::TlsConnect as TlsConnect<Socket>>::Future: Send,
+    {
+        let pool = Pool::new(self.clone(), config, tls);
+        pool.fill().await?;
+        pool.spawn_maintenance();
+        Ok(pool)
+    }
+}
@@ -0,0 +1,35 @@
+//! Errors.
+
+use std::error;
+use std::fmt;
+
+/// An error returned when requesting a client from a pool.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum Error {
+    /// No client became available before the acquire timeout expired.
+    Timeout,
+    /// The pool has been closed.
+    Closed,
+    /// A new connection could not be opened.
+    Connect(tokio_gaussdb::Error),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Timeout => fmt.write_str("timed out waiting for a connection"),
+            Error::Closed => fmt.write_str("connection pool closed"),
+            Error::Connect(_) => fmt.write_str("error opening connection"),
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Error::Connect(e) => Some(e),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,41 @@
+//! A connection pool for `tokio-gaussdb`.
+//!
+//! The pool keeps between `min_size` and `max_size` connections open, handing them out as [`PooledClient`]s which
+//! dereference to a [`tokio_gaussdb::Client`] and return the connection to the pool when dropped. Connections are
+//! closed once they have been idle or open for too long, and are checked before being reused. Each connection has
+//! its own cache of prepared statements, used through [`PooledClient::prepare_cached`].
+//!
+//! The pool spawns its connections onto the tokio runtime, so it must be created within one. Synchronous users
+//! should use the blocking pool provided by the `gaussdb` crate's `pool` feature instead.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use gaussdb_pool::PoolConfig;
+//! use tokio_gaussdb::NoTls;
+//!
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let config = "host=localhost user=gaussdb".parse::<tokio_gaussdb::Config>()?;
+//! let pool = PoolConfig::new().max_size(16).connect(config, NoTls).await?;
+//!
+//! let client = pool.get().await?;
+//! let row = client.query_one("SELECT 1::INT", &[]).await?;
+//! assert_eq!(row.get::<_, i32>(0), 1);
+//!
+//! let status = pool.status();
+//! println!("{} of {} connections in use", status.in_use, status.size);
+//! # Ok(())
+//! # }
+//! ```
+#![warn(rust_2018_idioms, clippy::all, missing_docs)]
+
+pub use crate::config::{PoolConfig, RecycleCheck};
+pub use crate::error::Error;
+pub use crate::pool::{Metrics, Pool, PooledClient, Status};
+
+pub mod config;
+pub mod error;
+mod pool;
+#[cfg(test)]
+mod test;
@@ -0,0 +1,594 @@
+use crate::{Error, PoolConfig, RecycleCheck};
+use log::{debug, warn};
+use parking_lot::Mutex;
+use std::collections::VecDeque;
+use std::fmt;
+use std::future::Future;
+use std::ops::{Deref, DerefMut};
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, Weak};
+use std::time::{Duration, Instant};
+use tokio::sync::{Notify, OwnedSemaphorePermit, Semaphore};
+use tokio_gaussdb::tls::{MakeTlsConnect, TlsConnect};
+use tokio_gaussdb::types::Type;
+use tokio_gaussdb::{Client, Config, Socket, Statement};
+
+type ConnectFuture = Pin<Box<dyn Future<Output = Result<Client, tokio_gaussdb::Error>> + Send>>;
+
+/// A snapshot of the state of a pool.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct Status {
+    /// The maximum number of connections.
+    pub max_size: usize,
+    /// The number of open connections, including those being opened.
+    pub size: usize,
+    /// The number of idle connections.
+    pub idle: usize,
+    /// The number of clients currently handed out.
+    pub in_use: usize,
+    /// The number of requests waiting for a client.
+    pub waiting: usize,
+}
+
+/// Counters describing the activity of a pool since it was created.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct Metrics {
+    /// The number of connections opened.
+    pub connections_created: u64,
+    /// The number of connections closed by the pool, whether expired, broken, or evicted.
+    pub connections_closed: u64,
+    /// The number of clients handed out.
+    pub acquired: u64,
+    /// The number of requests for a client which timed out.
+    pub acquire_timeouts: u64,
+    /// The total time spent waiting for clients.
+    pub acquire_wait_time: Duration,
+    /// The number of statements found in a connection's statement cache.
+    pub statement_cache_hits: u64,
+    /// The number of statements which had to be prepared because they were not in a connection's statement cache.
+    pub statement_cache_misses: u64,
+}
+
+/// Atomic counters backing [`Metrics`].
+#[derive(Default)]
+pub(crate) struct Counters {
+    connections_created: AtomicU64,
+    connections_closed: AtomicU64,
+    acquired: AtomicU64,
+    acquire_timeouts: AtomicU64,
+    acquire_wait_micros: AtomicU64,
+    statement_cache_hits: AtomicU64,
+    statement_cache_misses: AtomicU64,
+}
+
+impl Counters {
+    fn connection_created(&self) {
+        self.connections_created.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn connection_closed(&self) {
+        self.connections_closed.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn acquired(&self, wait: Duration) {
+        self.acquired.fetch_add(1, Ordering::Relaxed);
+        self.acquire_wait_micros
+            .fetch_add(wait.as_micros() as u64, Ordering::Relaxed);
+    }
+
+    fn acquire_timed_out(&self) {
+        self.acquire_timeouts.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn statement_cache_hit(&self) {
+        self.statement_cache_hits.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn statement_cache_miss(&self) {
+        self.statement_cache_misses.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn metrics(&self) -> Metrics {
+        Metrics {
+            connections_created: self.connections_created.load(Ordering::Relaxed),
+            connections_closed: self.connections_closed.load(Ordering::Relaxed),
+            acquired: self.acquired.load(Ordering::Relaxed),
+            acquire_timeouts: self.acquire_timeouts.load(Ordering::Relaxed),
+            acquire_wait_time: Duration::from_micros(
+                self.acquire_wait_micros.load(Ordering::Relaxed),
+            ),
+            statement_cache_hits: self.statement_cache_hits.load(Ordering::Relaxed),
+            statement_cache_misses: self.statement_cache_misses.load(Ordering::Relaxed),
+        }
+    }
+}
+
+struct Conn {
+    client: Client,
+    created: Instant,
+    last_used: Instant,
+}
+
+impl Conn {
+    fn is_expired(&self, config: &PoolConfig, now: Instant) -> bool {
+        config
+            .max_lifetime
+            .is_some_and(|max| now.duration_since(self.created) >= max)
+    }
+
+    fn is_idle_expired(&self, config: &PoolConfig, now: Instant) -> bool {
+        config
+            .idle_timeout
+            .is_some_and(|max| now.duration_since(self.last_used) >= max)
+    }
+}
+
+struct Inner {
+    config: PoolConfig,
+    connect: Box<dyn Fn() -> ConnectFuture + Sync + Send>,
+    semaphore: Arc<Semaphore>,
+    idle: Mutex<VecDeque<Conn>>,
+    // the number of open connections, including those being opened
+    size: AtomicUsize,
+    waiting: AtomicUsize,
+    closed: AtomicBool,
+    returned: Notify,
+    counters: Counters,
+}
+
+impl Inner {
+    // reserves room for a new connection, provided fewer than `limit` are open
+    fn reserve(&self, limit: usize) -> Option<Reservation<'_>> {
+        self.size
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |size| {
+                if size < limit {
+                    Some(size + 1)
+                } else {
+                    None
+                }
+            })
+            .ok()
+            .map(|_| Reservation(self))
+    }
+
+    fn release(&self) {
+        self.size.fetch_sub(1, Ordering::AcqRel);
+        self.returned.notify_one();
+    }
+
+    async fn open(&self, reservation: Reservation<'_>) -> Result<Conn, Error> {
+        let mut client = (self.connect)().await.map_err(Error::Connect)?;
+        client.set_statement_cache_capacity(self.config.statement_cache_capacity);
+        reservation.keep();
+        debug!("opened pooled connection");
+        self.counters.connection_created();
+        let now = Instant::now();
+        Ok(Conn {
+            client,
+            created: now,
+            last_used: now,
+        })
+    }
+
+    fn discard(&self, conn: Conn) {
+        drop(conn);
+        self.counters.connection_closed();
+        self.release();
+    }
+
+    fn put(&self, mut conn: Conn) {
+        let now = Instant::now();
+        if self.closed.load(Ordering::Acquire)
+            || conn.client.is_closed()
+            || conn.is_expired(&self.config, now)
+        {
+            self.discard(conn);
+            return;
+        }
+
+        conn.last_used = now;
+        self.idle.lock().push_back(conn);
+        self.returned.notify_one();
+    }
+
+    async fn recycle(&self, conn: &Conn) -> bool {
+        let now = Instant::now();
+        if conn.client.is_closed()
+            || conn.is_expired(&self.config, now)
+            || conn.is_idle_expired(&self.config, now)
+        {
+            return false;
+        }
+
+        match self.config.recycle_check {
+            RecycleCheck::Fast => true,
+            RecycleCheck::Ping(timeout) => {
+                match tokio::time::timeout(timeout, conn.client.simple_query("")).await {
+                    Ok(Ok(_)) => true,
+                    Ok(Err(e)) => {
+                        debug!("pooled connection failed recycle check: {}", e);
+                        false
+                    }
+                    Err(_) => {
+                        debug!("pooled connection recycle check timed out");
+                        false
+                    }
+                }
+            }
+        }
+    }
+
+    async fn acquire(
+        self: &Arc<Inner>,
+        permit: OwnedSemaphorePermit,
+    ) -> Result<PooledClient, Error> {
+        let mut permit = Some(permit);
+        loop {
+            let conn = self.idle.lock().pop_back();
+            if let Some(conn) = conn {
+                // if we're cancelled during the check, the connection goes back to the pool
+                let mut client = PooledClient {
+                    conn: Some(conn),
+                    pool: Arc::downgrade(self),
+                    _permit: permit.take(),
+                };
+                if self.recycle(client.conn()).await {
+                    return Ok(client);
+                }
+                self.discard(client.conn.take().unwrap());
+                permit = client._permit.take();
+                continue;
+            }
+
+            if let Some(reservation) = self.reserve(self.config.max_size) {
+                let conn = self.open(reservation).await?;
+                return Ok(PooledClient {
+                    conn: Some(conn),
+                    pool: Arc::downgrade(self),
+                    _permit: permit,
+                });
+            }
+
+            // every connection is in use or being opened by the maintenance task
+            self.returned.notified().await;
+        }
+    }
+
+    // closes idle connections which have expired, keeping at least `min_size` open
+    fn reap(&self) {
+        let now = Instant::now();
+        let mut expired = vec![];
+        {
+            let mut idle = self.idle.lock();
+            let mut i = 0;
+            while i < idle.len() {
+                let conn = &idle[i];
+                let removable = conn.is_expired(&self.config, now)
+                    || conn.client.is_closed()
+                    || (conn.is_idle_expired(&self.config, now)
+                        && self.size.load(Ordering::Acquire) - expired.len()
+                            > self.config.min_size);
+                if removable {
+                    expired.extend(idle.remove(i));
+                } else {
+                    i += 1;
+                }
+            }
+        }
+
+        for conn in expired {
+            debug!("closing expired pooled connection");
+            self.discard(conn);
+        }
+    }
+
+    async fn fill(&self) -> Result<(), Error> {
+        while !self.closed.load(Ordering::Acquire) {
+            let reservation = match self.reserve(self.config.min_size) {
+                Some(reservation) => reservation,
+                None => break,
+            };
+            let conn = self.open(reservation).await?;
+            self.put(conn);
+        }
+        Ok(())
+    }
+}
+
+/// An asynchronous pool of database connections.
+///
+/// Pools are created with [`PoolConfig::connect`]. Cloning a pool creates a new handle to the same set of
+/// connections.
+///
+/// # Examples
+///
+/// ```no_run
+/// use gaussdb_pool::PoolConfig;
+/// use std::time::Duration;
+/// use tokio_gaussdb::NoTls;
+///
+/// # async fn f() -> Result<(), Box<dyn std::error::Error>> {
+/// let config = "host=localhost user=gaussdb".parse::<tokio_gaussdb::Config>()?;
+/// let pool = PoolConfig::new()
+///     .min_size(2)
+///     .max_size(16)
+///     .acquire_timeout(Some(Duration::from_secs(5)))
+///     .connect(config, NoTls)
+///     .await?;
+///
+/// let client = pool.get().await?;
+/// let stmt = client.prepare_cached("SELECT name FROM users WHERE id = $1").await?;
+/// let rows = client.query(&stmt, &[&1i32]).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct Pool {
+    inner: Arc<Inner>,
+}
+
+impl fmt::Debug for Pool {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Pool")
+            .field("config", &self.inner.config)
+            .field("status", &self.status())
+            .finish()
+    }
+}
+
+impl Pool {
+    pub(crate) fn new<T>(mut config: PoolConfig, connect_config: Config, tls: T) -> Pool
+    where
+        T: MakeTlsConnect<Socket> + Clone + Sync + Send + 'static,
+        T::Stream: Sync + Send,
+        T::TlsConnect: Sync + Send,
+        <T::TlsConnect as TlsConnect<Socket>>::Future: Send,
+    {
+        config.min_size = config.min_size.min(config.max_size);
+        let connect = move || -> ConnectFuture {
+            let config = connect_config.clone();
+            let tls = tls.clone();
+            Box::pin(async move {
+                let (client, connection) = config.connect(tls).await?;
+                tokio::spawn(async move {
+                    if let Err(e) = connection.await {
+                        warn!("pooled connection error: {}", e);
+                    }
+                });
+                Ok(client)
+            })
+        };
+
+        Pool {
+            inner: Arc::new(Inner {
+                semaphore: Arc::new(Semaphore::new(config.max_size)),
+                config,
+                connect: Box::new(connect),
+                idle: Mutex::new(VecDeque::new()),
+                size: AtomicUsize::new(0),
+                waiting: AtomicUsize::new(0),
+                closed: AtomicBool::new(false),
+                returned: Notify::new(),
+                counters: Counters::default(),
+            }),
+        }
+    }
+
+    pub(crate) async fn fill(&self) -> Result<(), Error> {
+        self.inner.fill().await
+    }
+
+    pub(crate) fn spawn_maintenance(&self) {
+        let inner = Arc::downgrade(&self.inner);
+        let interval = self.inner.config.maintenance_interval;
+        tokio::spawn(maintain(inner, interval));
+    }
+
+    /// Returns the pool's configuration.
+    pub fn config(&self) -> &PoolConfig {
+        &self.inner.config
+    }
+
+    /// Retrieves a client from the pool, opening a new connection if none are idle and the pool is not full.
+    ///
+    /// Waits for a client to be returned if the pool is full, failing with [`Error::Timeout`] once the acquire
+    /// timeout expires.
+    pub async fn get(&self) -> Result<PooledClient, Error> {
+        let start = Instant::now();
+        let future = async {
+            let permit = {
+                let _waiting = Waiting::new(&self.inner.waiting);
+                self.inner
+                    .semaphore
+                    .clone()
+                    .acquire_owned()
+                    .await
+                    .map_err(|_| Error::Closed)?
+            };
+            self.inner.acquire(permit).await
+        };
+
+        let result = match self.inner.config.acquire_timeout {
+            Some(timeout) => match tokio::time::timeout(timeout, future).await {
+                Ok(result) => result,
+                Err(_) => {
+                    self.inner.counters.acquire_timed_out();
+                    return Err(Error::Timeout);
+                }
+            },
+            None => future.await,
+        };
+
+        let client = result?;
+        self.inner.counters.acquired(start.elapsed());
+        Ok(client)
+    }
+
+    /// Returns the current state of the pool.
+    pub fn status(&self) -> Status {
+        let idle = self.inner.idle.lock().len();
+        let size = self.inner.size.load(Ordering::Acquire);
+        Status {
+            max_size: self.inner.config.max_size,
+            size,
+            idle,
+            in_use: self.inner.config.max_size - self.inner.semaphore.available_permits(),
+            waiting: self.inner.waiting.load(Ordering::Acquire),
+        }
+    }
+
+    /// Returns counters describing the activity of the pool.
+    pub fn metrics(&self) -> Metrics {
+        self.inner.counters.metrics()
+    }
+
+    /// Closes the pool.
+    ///
+    /// Idle connections are closed immediately, and clients in use are closed when they are returned. Pending and
+    /// future requests for a client fail with [`Error::Closed`].
+    pub fn close(&self) {
+        self.inner.closed.store(true, Ordering::Release);
+        self.inner.semaphore.close();
+        let idle = std::mem::take(&mut *self.inner.idle.lock());
+        for conn in idle {
+            self.inner.discard(conn);
+        }
+    }
+
+    /// Determines if the pool has been closed.
+    pub fn is_closed(&self) -> bool {
+        self.inner.closed.load(Ordering::Acquire)
+    }
+}
+
+async fn maintain(inner: Weak<Inner>, interval: Duration) {
+    loop {
+        tokio::time::sleep(interval).await;
+        let inner = match inner.upgrade() {
+            Some(inner) => inner,
+            None => return,
+        };
+        if inner.closed.load(Ordering::Acquire) {
+            return;
+        }
+
+        inner.reap();
+        if let Err(e) = inner.fill().await {
+            warn!("error opening pooled connection: {}", e);
+        }
+    }
+}
+
+// releases a reserved connection slot unless the connection is opened
+struct Reservation<'a>(&'a Inner);
+
+impl Reservation<'_> {
+    fn keep(self) {
+        std::mem::forget(self);
+    }
+}
+
+impl Drop for Reservation<'_> {
+    fn drop(&mut self) {
+        self.0.release();
+    }
+}
+
+struct Waiting<'a>(&'a AtomicUsize);
+
+impl<'a> Waiting<'a> {
+    fn new(waiting: &'a AtomicUsize) -> Waiting<'a> {
+        waiting.fetch_add(1, Ordering::AcqRel);
+        Waiting(waiting)
+    }
+}
+
+impl Drop for Waiting<'_> {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::AcqRel);
+    }
+}
+
+/// A client checked out of a [`Pool`].
+///
+/// The client dereferences to a [`Client`], and is returned to the pool when dropped. Connections which have been
+/// closed or have outlived the pool's maximum lifetime are closed instead of being returned.
+pub struct PooledClient {
+    conn: Option<Conn>,
+    pool: Weak<Inner>,
+    _permit: Option<OwnedSemaphorePermit>,
+}
+
+impl fmt::Debug for PooledClient {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PooledClient")
+            .field("client", self.client())
+            .finish()
+    }
+}
+
+impl PooledClient {
+    fn conn(&self) -> &Conn {
+        self.conn.as_ref().unwrap()
+    }
+
+    fn client(&self) -> &Client {
+        &self.conn().client
+    }
+
+    /// Like [`Client::prepare`], but reuses the statement previously prepared for the same query on this
+    /// connection.
+    pub async fn prepare_cached(&self, query: &str) -> Result<Statement, tokio_gaussdb::Error> {
+        self.prepare_typed_cached(query, &[]).await
+    }
+
+    /// Like [`Client::prepare_typed`], but reuses the statement previously prepared for the same query and
+    /// parameter types on this connection.
+    pub async fn prepare_typed_cached(
+        &self,
+        query: &str,
+        types: &[Type],
+    ) -> Result<Statement, tokio_gaussdb::Error> {
+        let pool = self.pool.upgrade();
+        let statements = self.client().statement_cache();
+        if let Some(statement) = statements.get(query, types) {
+            if let Some(pool) = &pool {
+                pool.counters.statement_cache_hit();
+            }
+            return Ok(statement);
+        }
+
+        if let Some(pool) = &pool {
+            pool.counters.statement_cache_miss();
+        }
+        let statement = self.client().prepare_typed(query, types).await?;
+        statements.insert(query, types, statement.clone());
+        Ok(statement)
+    }
+}
+
+impl Deref for PooledClient {
+    type Target = Client;
+
+    fn deref(&self) -> &Client {
+        self.client()
+    }
+}
+
+impl DerefMut for PooledClient {
+    fn deref_mut(&mut self) -> &mut Client {
+        &mut self.conn.as_mut().unwrap().client
+    }
+}
+
+impl Drop for PooledClient {
+    fn drop(&mut self) {
+        if let Some(conn) = self.conn.take() {
+            match self.pool.upgrade() {
+                Some(pool) => pool.put(conn),
+                None => drop(conn),
+            }
+        }
+    }
+}
@@ -0,0 +1,209 @@
+use std::time::Duration;
+use tokio_gaussdb::{Config, NoTls};
+
+use super::*;
+
+fn config() -> Config {
+    "host=localhost port=5433 user=gaussdb password=Gaussdb@123 dbname=postgres"
+        .parse()
+        .unwrap()
+}
+
+async fn backend_pid(client: &PooledClient) -> i32 {
+    client
+        .query_one("SELECT pg_backend_pid()", &[])
+        .await
+        .unwrap()
+        .get(0)
+}
+
+#[tokio::test]
+async fn smoke_test() {
+    let pool = PoolConfig::new().connect(config(), NoTls).await.unwrap();
+
+    let client = pool.get().await.unwrap();
+    let row = client.query_one("SELECT $1::INT4", &[&1i32]).await.unwrap();
+    assert_eq!(row.get::<_, i32>(0), 1);
+
+    let status = pool.status();
+    assert_eq!(status.size, 1);
+    assert_eq!(status.in_use, 1);
+    assert_eq!(status.idle, 0);
+
+    drop(client);
+    let status = pool.status();
+    assert_eq!(status.in_use, 0);
+    assert_eq!(status.idle, 1);
+}
+
+#[tokio::test]
+async fn min_size() {
+    let pool = PoolConfig::new()
+        .min_size(2)
+        .max_size(4)
+        .connect(config(), NoTls)
+        .await
+        .unwrap();
+
+    let status = pool.status();
+    assert_eq!(status.size, 2);
+    assert_eq!(status.idle, 2);
+    assert_eq!(pool.metrics().connections_created, 2);
+}
+
+#[tokio::test]
+async fn reuse() {
+    let pool = PoolConfig::new().connect(config(), NoTls).await.unwrap();
+
+    let pid = backend_pid(&pool.get().await.unwrap()).await;
+    assert_eq!(backend_pid(&pool.get().await.unwrap()).await, pid);
+
+    let metrics = pool.metrics();
+    assert_eq!(metrics.connections_created, 1);
+    assert_eq!(metrics.acquired, 2);
+}
+
+#[tokio::test]
+async fn acquire_timeout() {
+    let pool = PoolConfig::new()
+        .max_size(1)
+        .acquire_timeout(Some(Duration::from_millis(100)))
+        .connect(config(), NoTls)
+        .await
+        .unwrap();
+
+    let client = pool.get().await.unwrap();
+    match pool.get().await {
+        Err(Error::Timeout) => {}
+        r => panic!("unexpected result {:?}", r),
+    }
+    assert_eq!(pool.metrics().acquire_timeouts, 1);
+    assert_eq!(pool.status().waiting, 0);
+
+    drop(client);
+    pool.get().await.unwrap();
+}
+
+#[tokio::test]
+async fn wait_for_client() {
+    let pool = PoolConfig::new()
+        .max_size(1)
+        .connect(config(), NoTls)
+        .await
+        .unwrap();
+
+    let client = pool.get().await.unwrap();
+    let pid = backend_pid(&client).await;
+
+    let waiter = tokio::spawn({
+        let pool = pool.clone();
+        async move { backend_pid(&pool.get().await.unwrap()).await }
+    });
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    assert_eq!(pool.status().waiting, 1);
+
+    drop(client);
+    assert_eq!(waiter.await.unwrap(), pid);
+}
+
+#[tokio::test]
+async fn max_lifetime() {
+    let pool = PoolConfig::new()
+        .max_lifetime(Some(Duration::from_millis(0)))
+        .connect(config(), NoTls)
+        .await
+        .unwrap();
+
+    let pid = backend_pid(&pool.get().await.unwrap()).await;
+    assert_ne!(backend_pid(&pool.get().await.unwrap()).await, pid);
+    assert_eq!(pool.metrics().connections_closed, 2);
+    assert_eq!(pool.status().size, 0);
+}
+
+#[tokio::test]
+async fn idle_timeout() {
+    let pool = PoolConfig::new()
+        .idle_timeout(Some(Duration::from_millis(50)))
+        .maintenance_interval(Duration::from_millis(20))
+        .connect(config(), NoTls)
+        .await
+        .unwrap();
+
+    drop(pool.get().await.unwrap());
+    assert_eq!(pool.status().idle, 1);
+
+    tokio::time::sleep(Duration::from_millis(200)).await;
+    assert_eq!(pool.status().size, 0);
+    assert_eq!(pool.metrics().connections_closed, 1);
+}
+
+#[tokio::test]
+async fn recycle_ping() {
+    let pool = PoolConfig::new()
+        .recycle_check(RecycleCheck::Ping(Duration::from_secs(5)))
+        .connect(config(), NoTls)
+        .await
+        .unwrap();
+
+    let pid = backend_pid(&pool.get().await.unwrap()).await;
+
+    let (admin, connection) = config().connect(NoTls).await.unwrap();
+    tokio::spawn(connection);
+    admin
+        .execute("SELECT pg_terminate_backend($1)", &[&pid])
+        .await
+        .unwrap();
+    tokio::time::sleep(Duration::from_millis(50)).await;
+
+    let client = pool.get().await.unwrap();
+    assert_ne!(backend_pid(&client).await, pid);
+    assert_eq!(pool.metrics().connections_closed, 1);
+}
+
+#[tokio::test]
+async fn statement_cache() {
+    let pool = PoolConfig::new()
+        .max_size(1)
+        .statement_cache_capacity(1)
+        .connect(config(), NoTls)
+        .await
+        .unwrap();
+
+    let client = pool.get().await.unwrap();
+    client.prepare_cached("SELECT 1::INT").await.unwrap();
+    client.prepare_cached("SELECT 1::INT").await.unwrap();
+    drop(client);
+
+    let client = pool.get().await.unwrap();
+    let stmt = client.prepare_cached("SELECT 1::INT").await.unwrap();
+    let row = client.query_one(&stmt, &[]).await.unwrap();
+    assert_eq!(row.get::<_, i32>(0), 1);
+
+    client.prepare_cached("SELECT 2::INT").await.unwrap();
+    assert_eq!(client.statement_cache().len(), 1);
+    client.prepare_cached("SELECT 1::INT").await.unwrap();
+
+    let metrics = pool.metrics();
+    assert_eq!(metrics.statement_cache_hits, 2);
+    assert_eq!(metrics.statement_cache_misses, 3);
+}
+
+#[tokio::test]
+async fn close() {
+    let pool = PoolConfig::new()
+        .min_size(1)
+        .connect(config(), NoTls)
+        .await
+        .unwrap();
+
+    let client = pool.get().await.unwrap();
+    pool.close();
+    assert!(pool.is_closed());
+    match pool.get().await {
+        Err(Error::Closed) => {}
+        r => panic!("unexpected result {:?}", r),
+    }
+
+    drop(client);
+    assert_eq!(pool.status().size, 0);
+}
@@ -22,6 +22,15 @@ const RANGE_EMPTY: u8 = 0b0000_0001;
 const PGSQL_AF_INET: u8 = 2;
 const PGSQL_AF_INET6: u8 = 3;
 
+const TS_MAX_POSITION: u16 = (1 << 14) - 1;
+
+const TS_QUERY_VALUE: u8 = 1;
+const TS_QUERY_OPERATOR: u8 = 2;
+const TS_QUERY_NOT: u8 = 1;
+const TS_QUERY_AND: u8 = 2;
+const TS_QUERY_OR: u8 = 3;
+const TS_QUERY_PHRASE: u8 = 4;
+
 /// Serializes a `BOOL` value.
 #[inline]
 pub fn bool_to_sql(v: bool, buf: &mut BytesMut) {
@@ -78,6 +87,22 @@ pub fn char_from_sql(mut buf: &[u8]) -> Result<i8, StdBox<dyn Error + Sync + Sen
     Ok(v)
 }
 
+/// Serializes an `INT1` value.
+#[inline]
+pub fn int1_to_sql(v: u8, buf: &mut BytesMut) {
+    buf.put_u8(v);
+}
+
+/// Deserializes an `INT1` value.
+#[inline]
+pub fn int1_from_sql(mut buf: &[u8]) -> Result<u8, StdBox<dyn Error + Sync + Send>> {
+    let v = buf.read_u8()?;
+    if !buf.is_empty() {
+        return Err("invalid buffer size".into());
+    }
+    Ok(v)
+}
+
 /// Serializes an `INT2` value.
 #[inline]
 pub fn int2_to_sql(v: i16, buf: &mut BytesMut) {
@@ -142,6 +167,22 @@ pub fn int8_from_sql(mut buf: &[u8]) -> Result<i64, StdBox<dyn Error + Sync + Se
     Ok(v)
 }
 
+/// Serializes an `INT16` value.
+#[inline]
+pub fn int16_to_sql(v: i128, buf: &mut BytesMut) {
+    buf.put_i128(v);
+}
+
+/// Deserializes an `INT16` value.
+#[inline]
+pub fn int16_from_sql(mut buf: &[u8]) -> Result<i128, StdBox<dyn Error + Sync + Send>> {
+    let v = buf.read_i128::<BigEndian>()?;
+    if !buf.is_empty() {
+        return Err("invalid buffer size".into());
+    }
+    Ok(v)
+}
+
 /// Serializes a `PG_LSN` value.
 #[inline]
 pub fn lsn_to_sql(v: Lsn, buf: &mut BytesMut) {
@@ -409,7 +450,7 @@ pub fn time_to_sql(v: i64, buf: &mut BytesMut) {
     buf.put_i64(v);
 }
 
-/// Deserializes a `TIME` or `TIMETZ` value.
+/// Deserializes a `TIME` value.
 ///
 /// The value represents the number of microseconds since midnight.
 #[inline]
@@ -421,6 +462,30 @@ pub fn time_from_sql(mut buf: &[u8]) -> Result<i64, StdBox<dyn Error + Sync + Se
     Ok(v)
 }
 
+/// Serializes a `TIMETZ` value.
+///
+/// The time should represent the number of microseconds since midnight, and the offset the number of seconds east of
+/// UTC.
+#[inline]
+pub fn timetz_to_sql(time: i64, offset: i32, buf: &mut BytesMut) {
+    buf.put_i64(time);
+    // the wire format stores the offset in seconds west of UTC
+    buf.put_i32(-offset);
+}
+
+/// Deserializes a `TIMETZ` value.
+///
+/// Returns the number of microseconds since midnight and the offset in seconds east of UTC.
+#[inline]
+pub fn timetz_from_sql(mut buf: &[u8]) -> Result<(i64, i32), StdBox<dyn Error + Sync + Send>> {
+    let time = buf.read_i64::<BigEndian>()?;
+    let zone = buf.read_i32::<BigEndian>()?;
+    if !buf.is_empty() {
+        return Err("invalid message length: timetz not drained".into());
+    }
+    Ok((time, -zone))
+}
+
 /// Serializes a `MACADDR` value.
 #[inline]
 pub fn macaddr_to_sql(v: [u8; 6], buf: &mut BytesMut) {
@@ -438,6 +503,43 @@ pub fn macaddr_from_sql(buf: &[u8]) -> Result<[u8; 6], StdBox<dyn Error + Sync +
     Ok(out)
 }
 
+/// Serializes a `MACADDR8` value.
+#[inline]
+pub fn macaddr8_to_sql(v: [u8; 8], buf: &mut BytesMut) {
+    buf.put_slice(&v);
+}
+
+/// Deserializes a `MACADDR8` value.
+#[inline]
+pub fn macaddr8_from_sql(buf: &[u8]) -> Result<[u8; 8], StdBox<dyn Error + Sync + Send>> {
+    if buf.len() != 8 {
+        return Err("invalid message length: macaddr8 length mismatch".into());
+    }
+    let mut out = [0; 8];
+    out.copy_from_slice(buf);
+    Ok(out)
+}
+
+/// Serializes a `MONEY` value.
+///
+/// The value should be the amount in the currency's minor unit, e.g. cents.
+#[inline]
+pub fn money_to_sql(v: i64, buf: &mut BytesMut) {
+    buf.put_i64(v);
+}
+
+/// Deserializes a `MONEY` value.
+///
+/// The value is the amount in the currency's minor unit, e.g. cents.
+#[inline]
+pub fn money_from_sql(mut buf: &[u8]) -> Result<i64, StdBox<dyn Error + Sync + Send>> {
+    let v = buf.read_i64::<BigEndian>()?;
+    if !buf.is_empty() {
+        return Err("invalid message length: money not drained".into());
+    }
+    Ok(v)
+}
+
 /// Serializes a `UUID` value.
 #[inline]
 pub fn uuid_to_sql(v: [u8; 16], buf: &mut BytesMut) {
@@ -977,6 +1079,194 @@ impl FallibleIterator for PathPoints<'_> {
     }
 }
 
+/// Serializes a line segment value.
+#[inline]
+pub fn lseg_to_sql(x1: f64, y1: f64, x2: f64, y2: f64, buf: &mut BytesMut) {
+    buf.put_f64(x1);
+    buf.put_f64(y1);
+    buf.put_f64(x2);
+    buf.put_f64(y2);
+}
+
+/// Deserializes a line segment value.
+#[inline]
+pub fn lseg_from_sql(mut buf: &[u8]) -> Result<Lseg, StdBox<dyn Error + Sync + Send>> {
+    let x1 = buf.read_f64::<BigEndian>()?;
+    let y1 = buf.read_f64::<BigEndian>()?;
+    let x2 = buf.read_f64::<BigEndian>()?;
+    let y2 = buf.read_f64::<BigEndian>()?;
+    if !buf.is_empty() {
+        return Err("invalid buffer size".into());
+    }
+    Ok(Lseg {
+        start: Point { x: x1, y: y1 },
+        end: Point { x: x2, y: y2 },
+    })
+}
+
+/// A Postgres line segment.
+#[derive(Copy, Clone)]
+pub struct Lseg {
+    start: Point,
+    end: Point,
+}
+
+impl Lseg {
+    /// Returns the start point of the segment.
+    #[inline]
+    pub fn start(&self) -> Point {
+        self.start
+    }
+
+    /// Returns the end point of the segment.
+    #[inline]
+    pub fn end(&self) -> Point {
+        self.end
+    }
+}
+
+/// Serializes a line value, given the coefficients of its equation `Ax + By + C = 0`.
+#[inline]
+pub fn line_to_sql(a: f64, b: f64, c: f64, buf: &mut BytesMut) {
+    buf.put_f64(a);
+    buf.put_f64(b);
+    buf.put_f64(c);
+}
+
+/// Deserializes a line value.
+#[inline]
+pub fn line_from_sql(mut buf: &[u8]) -> Result<Line, StdBox<dyn Error + Sync + Send>> {
+    let a = buf.read_f64::<BigEndian>()?;
+    let b = buf.read_f64::<BigEndian>()?;
+    let c = buf.read_f64::<BigEndian>()?;
+    if !buf.is_empty() {
+        return Err("invalid buffer size".into());
+    }
+    Ok(Line { a, b, c })
+}
+
+/// A Postgres line, represented by the equation `Ax + By + C = 0`.
+#[derive(Copy, Clone)]
+pub struct Line {
+    a: f64,
+    b: f64,
+    c: f64,
+}
+
+impl Line {
+    /// Returns the `A` coefficient of the line.
+    #[inline]
+    pub fn a(&self) -> f64 {
+        self.a
+    }
+
+    /// Returns the `B` coefficient of the line.
+    #[inline]
+    pub fn b(&self) -> f64 {
+        self.b
+    }
+
+    /// Returns the `C` coefficient of the line.
+    #[inline]
+    pub fn c(&self) -> f64 {
+        self.c
+    }
+}
+
+/// Serializes a Postgres polygon.
+#[inline]
+pub fn polygon_to_sql<I>(
+    points: I,
+    buf: &mut BytesMut,
+) -> Result<(), StdBox<dyn Error + Sync + Send>>
+where
+    I: IntoIterator<Item = (f64, f64)>,
+{
+    let points_idx = buf.len();
+    buf.put_i32(0);
+
+    let mut num_points = 0;
+    for (x, y) in points {
+        num_points += 1;
+        buf.put_f64(x);
+        buf.put_f64(y);
+    }
+
+    let num_points = i32::from_usize(num_points)?;
+    BigEndian::write_i32(&mut buf[points_idx..], num_points);
+
+    Ok(())
+}
+
+/// Deserializes a Postgres polygon.
+#[inline]
+pub fn polygon_from_sql(mut buf: &[u8]) -> Result<Polygon<'_>, StdBox<dyn Error + Sync + Send>> {
+    let points = buf.read_i32::<BigEndian>()?;
+
+    Ok(Polygon { points, buf })
+}
+
+/// A Postgres polygon.
+pub struct Polygon<'a> {
+    points: i32,
+    buf: &'a [u8],
+}
+
+impl<'a> Polygon<'a> {
+    /// Returns an iterator over the vertices of the polygon.
+    #[inline]
+    pub fn points(&self) -> PathPoints<'a> {
+        PathPoints {
+            remaining: self.points,
+            buf: self.buf,
+        }
+    }
+}
+
+/// Serializes a circle value.
+#[inline]
+pub fn circle_to_sql(x: f64, y: f64, radius: f64, buf: &mut BytesMut) {
+    buf.put_f64(x);
+    buf.put_f64(y);
+    buf.put_f64(radius);
+}
+
+/// Deserializes a circle value.
+#[inline]
+pub fn circle_from_sql(mut buf: &[u8]) -> Result<Circle, StdBox<dyn Error + Sync + Send>> {
+    let x = buf.read_f64::<BigEndian>()?;
+    let y = buf.read_f64::<BigEndian>()?;
+    let radius = buf.read_f64::<BigEndian>()?;
+    if !buf.is_empty() {
+        return Err("invalid buffer size".into());
+    }
+    Ok(Circle {
+        center: Point { x, y },
+        radius,
+    })
+}
+
+/// A Postgres circle.
+#[derive(Copy, Clone)]
+pub struct Circle {
+    center: Point,
+    radius: f64,
+}
+
+impl Circle {
+    /// Returns the center of the circle.
+    #[inline]
+    pub fn center(&self) -> Point {
+        self.center
+    }
+
+    /// Returns the radius of the circle.
+    #[inline]
+    pub fn radius(&self) -> f64 {
+        self.radius
+    }
+}
+
 /// Serializes a Postgres inet.
 #[inline]
 pub fn inet_to_sql(addr: IpAddr, netmask: u8, buf: &mut BytesMut) {
@@ -1116,3 +1406,323 @@ pub fn ltxtquery_from_sql(buf: &[u8]) -> Result<&str, StdBox<dyn Error + Sync +
         _ => Err("ltxtquery version 1 only supported".into()),
     }
 }
+
+/// Serializes a `TSVECTOR` value.
+///
+/// Each lexeme is given along with its positions, as pairs of a position and a weight from 0 (`D`) to 3 (`A`).
+#[inline]
+pub fn tsvector_to_sql<'a, I, P>(
+    lexemes: I,
+    buf: &mut BytesMut,
+) -> Result<(), StdBox<dyn Error + Sync + Send>>
+where
+    I: IntoIterator<Item = (&'a str, P)>,
+    P: IntoIterator<Item = (u16, u8)>,
+{
+    let count_idx = buf.len();
+    buf.put_i32(0);
+
+    let mut count = 0;
+    for (lexeme, positions) in lexemes {
+        count += 1;
+        write_cstr(lexeme, buf)?;
+
+        let positions_idx = buf.len();
+        buf.put_i16(0);
+
+        let mut num_positions = 0;
+        for (position, weight) in positions {
+            if position > TS_MAX_POSITION || weight > 3 {
+                return Err("invalid tsvector position".into());
+            }
+            num_positions += 1;
+            buf.put_u16(u16::from(weight) << 14 | position);
+        }
+
+        let num_positions = i16::from_usize(num_positions)?;
+        BigEndian::write_i16(&mut buf[positions_idx..], num_positions);
+    }
+
+    let count = i32::from_usize(count)?;
+    BigEndian::write_i32(&mut buf[count_idx..], count);
+
+    Ok(())
+}
+
+/// Deserializes a `TSVECTOR` value.
+#[inline]
+pub fn tsvector_from_sql(
+    mut buf: &[u8],
+) -> Result<TsVectorLexemes<'_>, StdBox<dyn Error + Sync + Send>> {
+    let remaining = buf.read_i32::<BigEndian>()?;
+    if remaining < 0 {
+        return Err("invalid lexeme count".into());
+    }
+
+    Ok(TsVectorLexemes { remaining, buf })
+}
+
+/// A fallible iterator over the lexemes of a `TSVECTOR`.
+pub struct TsVectorLexemes<'a> {
+    remaining: i32,
+    buf: &'a [u8],
+}
+
+impl<'a> FallibleIterator for TsVectorLexemes<'a> {
+    type Item = TsLexeme<'a>;
+    type Error = StdBox<dyn Error + Sync + Send>;
+
+    #[inline]
+    fn next(&mut self) -> Result<Option<TsLexeme<'a>>, StdBox<dyn Error + Sync + Send>> {
+        if self.remaining == 0 {
+            if !self.buf.is_empty() {
+                return Err("invalid buffer size".into());
+            }
+            return Ok(None);
+        }
+        self.remaining -= 1;
+
+        let lexeme = read_cstr(&mut self.buf)?;
+        let count = self.buf.read_u16::<BigEndian>()?;
+        let len = usize::from(count) * 2;
+        if self.buf.len() < len {
+            return Err("invalid buffer size".into());
+        }
+        let (positions, rest) = self.buf.split_at(len);
+        self.buf = rest;
+
+        Ok(Some(TsLexeme { lexeme, positions }))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.remaining as usize;
+        (len, Some(len))
+    }
+}
+
+/// A lexeme of a `TSVECTOR`.
+pub struct TsLexeme<'a> {
+    lexeme: &'a str,
+    positions: &'a [u8],
+}
+
+impl<'a> TsLexeme<'a> {
+    /// Returns the lexeme itself.
+    #[inline]
+    pub fn lexeme(&self) -> &'a str {
+        self.lexeme
+    }
+
+    /// Returns an iterator over the positions of the lexeme.
+    #[inline]
+    pub fn positions(&self) -> TsPositions<'a> {
+        TsPositions {
+            buf: self.positions,
+        }
+    }
+}
+
+/// An iterator over the positions of a `TSVECTOR` lexeme.
+pub struct TsPositions<'a> {
+    buf: &'a [u8],
+}
+
+impl Iterator for TsPositions<'_> {
+    type Item = TsPosition;
+
+    #[inline]
+    fn next(&mut self) -> Option<TsPosition> {
+        if self.buf.len() < 2 {
+            return None;
+        }
+        let (raw, rest) = self.buf.split_at(2);
+        self.buf = rest;
+        Some(TsPosition(BigEndian::read_u16(raw)))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.buf.len() / 2;
+        (len, Some(len))
+    }
+}
+
+/// A position of a `TSVECTOR` lexeme.
+#[derive(Copy, Clone)]
+pub struct TsPosition(u16);
+
+impl TsPosition {
+    /// Returns the position of the lexeme in the document.
+    #[inline]
+    pub fn position(&self) -> u16 {
+        self.0 & TS_MAX_POSITION
+    }
+
+    /// Returns the weight of the position, from 0 (`D`) to 3 (`A`).
+    #[inline]
+    pub fn weight(&self) -> u8 {
+        (self.0 >> 14) as u8
+    }
+}
+
+/// An item of a `TSQUERY`.
+///
+/// Queries are transmitted as a sequence of items in prefix order. Each operator is followed by its right operand and
+/// then, unless it is `Not`, its left operand.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TsQueryItem<'a> {
+    /// A lexeme to search for.
+    Operand {
+        /// The lexeme.
+        lexeme: &'a str,
+        /// A bitmask of the weights the lexeme must have, with bit 3 for `A` down to bit 0 for `D`. No bits set
+        /// means any weight.
+        weights: u8,
+        /// Whether the lexeme matches as a prefix.
+        prefix: bool,
+    },
+    /// The `!` operator.
+    Not,
+    /// The `&` operator.
+    And,
+    /// The `|` operator.
+    Or,
+    /// The `<N>` phrase operator, with the distance between its operands.
+    Phrase(u16),
+}
+
+/// Serializes a `TSQUERY` value.
+#[inline]
+pub fn tsquery_to_sql<'a, I>(
+    items: I,
+    buf: &mut BytesMut,
+) -> Result<(), StdBox<dyn Error + Sync + Send>>
+where
+    I: IntoIterator<Item = TsQueryItem<'a>>,
+{
+    let count_idx = buf.len();
+    buf.put_i32(0);
+
+    let mut count = 0;
+    for item in items {
+        count += 1;
+        match item {
+            TsQueryItem::Operand {
+                lexeme,
+                weights,
+                prefix,
+            } => {
+                buf.put_u8(TS_QUERY_VALUE);
+                buf.put_u8(weights);
+                buf.put_u8(prefix as u8);
+                write_cstr(lexeme, buf)?;
+            }
+            TsQueryItem::Not => {
+                buf.put_u8(TS_QUERY_OPERATOR);
+                buf.put_u8(TS_QUERY_NOT);
+            }
+            TsQueryItem::And => {
+                buf.put_u8(TS_QUERY_OPERATOR);
+                buf.put_u8(TS_QUERY_AND);
+            }
+            TsQueryItem::Or => {
+                buf.put_u8(TS_QUERY_OPERATOR);
+                buf.put_u8(TS_QUERY_OR);
+            }
+            TsQueryItem::Phrase(distance) => {
+                if distance > TS_MAX_POSITION + 1 {
+                    return Err("invalid tsquery phrase distance".into());
+                }
+                buf.put_u8(TS_QUERY_OPERATOR);
+                buf.put_u8(TS_QUERY_PHRASE);
+                buf.put_u16(distance);
+            }
+        }
+    }
+
+    let count = i32::from_usize(count)?;
+    BigEndian::write_i32(&mut buf[count_idx..], count);
+
+    Ok(())
+}
+
+/// Deserializes a `TSQUERY` value.
+#[inline]
+pub fn tsquery_from_sql(
+    mut buf: &[u8],
+) -> Result<TsQueryItems<'_>, StdBox<dyn Error + Sync + Send>> {
+    let remaining = buf.read_i32::<BigEndian>()?;
+    if remaining < 0 {
+        return Err("invalid tsquery item count".into());
+    }
+
+    Ok(TsQueryItems { remaining, buf })
+}
+
+/// A fallible iterator over the items of a `TSQUERY`, in prefix order.
+pub struct TsQueryItems<'a> {
+    remaining: i32,
+    buf: &'a [u8],
+}
+
+impl<'a> FallibleIterator for TsQueryItems<'a> {
+    type Item = TsQueryItem<'a>;
+    type Error = StdBox<dyn Error + Sync + Send>;
+
+    #[inline]
+    fn next(&mut self) -> Result<Option<TsQueryItem<'a>>, StdBox<dyn Error + Sync + Send>> {
+        if self.remaining == 0 {
+            if !self.buf.is_empty() {
+                return Err("invalid buffer size".into());
+            }
+            return Ok(None);
+        }
+        self.remaining -= 1;
+
+        let item = match self.buf.read_u8()? {
+            TS_QUERY_VALUE => {
+                let weights = self.buf.read_u8()?;
+                let prefix = self.buf.read_u8()? != 0;
+                let lexeme = read_cstr(&mut self.buf)?;
+                TsQueryItem::Operand {
+                    lexeme,
+                    weights,
+                    prefix,
+                }
+            }
+            TS_QUERY_OPERATOR => match self.buf.read_u8()? {
+                TS_QUERY_NOT => TsQueryItem::Not,
+                TS_QUERY_AND => TsQueryItem::And,
+                TS_QUERY_OR => TsQueryItem::Or,
+                TS_QUERY_PHRASE => TsQueryItem::Phrase(self.buf.read_u16::<BigEndian>()?),
+                _ => return Err("invalid tsquery operator".into()),
+            },
+            _ => return Err("invalid tsquery item type".into()),
+        };
+
+        Ok(Some(item))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.remaining as usize;
+        (len, Some(len))
+    }
+}
+
+fn write_cstr(s: &str, buf: &mut BytesMut) -> Result<(), StdBox<dyn Error + Sync + Send>> {
+    if s.contains('\0') {
+        return Err("string contains embedded null".into());
+    }
+    buf.put_slice(s.as_bytes());
+    buf.put_u8(0);
+    Ok(())
+}
+
+fn read_cstr<'a>(buf: &mut &'a [u8]) -> Result<&'a str, StdBox<dyn Error + Sync + Send>> {
+    let end = memchr::memchr(0, buf).ok_or("unexpected EOF")?;
+    let s = str::from_utf8(&buf[..end])?;
+    *buf = &buf[end + 1..];
+    Ok(s)
+}
@@ -17,6 +17,13 @@ fn bool() {
     assert_eq!(bool_from_sql(&buf).unwrap(), false);
 }
 
+#[test]
+fn int1() {
+    let mut buf = BytesMut::new();
+    int1_to_sql(0xfe, &mut buf);
+    assert_eq!(int1_from_sql(&buf).unwrap(), 0xfe);
+}
+
 #[test]
 fn int2() {
     let mut buf = BytesMut::new();
@@ -38,6 +45,43 @@ fn int8() {
     assert_eq!(int8_from_sql(&buf).unwrap(), 0x0102_0304_0506_0708);
 }
 
+#[test]
+fn int16() {
+    let mut buf = BytesMut::new();
+    int16_to_sql(0x0102_0304_0506_0708_090a_0b0c_0d0e_0f10, &mut buf);
+    assert_eq!(buf.len(), 16);
+    assert_eq!(
+        int16_from_sql(&buf).unwrap(),
+        0x0102_0304_0506_0708_090a_0b0c_0d0e_0f10
+    );
+}
+
+#[test]
+fn timetz() {
+    let mut buf = BytesMut::new();
+    timetz_to_sql(3_723_000_001, 19_800, &mut buf);
+    assert_eq!(&buf[8..], &(-19_800i32).to_be_bytes());
+    assert_eq!(timetz_from_sql(&buf).unwrap(), (3_723_000_001, 19_800));
+}
+
+#[test]
+fn macaddr8() {
+    let mut buf = BytesMut::new();
+    macaddr8_to_sql([8, 0, 0x2b, 1, 2, 3, 4, 5], &mut buf);
+    assert_eq!(
+        macaddr8_from_sql(&buf).unwrap(),
+        [8, 0, 0x2b, 1, 2, 3, 4, 5]
+    );
+    assert!(macaddr8_from_sql(&buf[..6]).is_err());
+}
+
+#[test]
+fn money() {
+    let mut buf = BytesMut::new();
+    money_to_sql(-12_345, &mut buf);
+    assert_eq!(money_from_sql(&buf).unwrap(), -12_345);
+}
+
 #[test]
 #[allow(clippy::float_cmp)]
 fn float4() {
@@ -54,6 +98,42 @@ fn float8() {
     assert_eq!(float8_from_sql(&buf).unwrap(), 10343.95);
 }
 
+#[test]
+#[allow(clippy::float_cmp)]
+fn lseg() {
+    let mut buf = BytesMut::new();
+    lseg_to_sql(1., 2., -3., 4.5, &mut buf);
+    let lseg = lseg_from_sql(&buf).unwrap();
+    assert_eq!((lseg.start().x(), lseg.start().y()), (1., 2.));
+    assert_eq!((lseg.end().x(), lseg.end().y()), (-3., 4.5));
+}
+
+#[test]
+#[allow(clippy::float_cmp)]
+fn polygon() {
+    let points = vec![(0., 0.), (1., 0.), (1., 1.)];
+
+    let mut buf = BytesMut::new();
+    polygon_to_sql(points.iter().cloned(), &mut buf).unwrap();
+    let polygon = polygon_from_sql(&buf).unwrap();
+    let actual = polygon
+        .points()
+        .map(|p| Ok((p.x(), p.y())))
+        .collect::<Vec<_>>()
+        .unwrap();
+    assert_eq!(actual, points);
+}
+
+#[test]
+#[allow(clippy::float_cmp)]
+fn circle() {
+    let mut buf = BytesMut::new();
+    circle_to_sql(1., -2., 3.5, &mut buf);
+    let circle = circle_from_sql(&buf).unwrap();
+    assert_eq!((circle.center().x(), circle.center().y()), (1., -2.));
+    assert_eq!(circle.radius(), 3.5);
+}
+
 #[test]
 fn hstore() {
     let mut map = HashMap::new();
@@ -240,3 +320,67 @@ fn ltxtquery_wrong_version() {
 
     assert!(ltree_from_sql(query.as_slice()).is_err())
 }
+
+#[test]
+fn tsvector() {
+    let lexemes = vec![
+        ("a", vec![(1, 3)]),
+        ("cat", vec![]),
+        ("fat", vec![(2, 2), (4, 0)]),
+    ];
+
+    let mut buf = BytesMut::new();
+    tsvector_to_sql(
+        lexemes.iter().map(|(l, p)| (*l, p.iter().cloned())),
+        &mut buf,
+    )
+    .unwrap();
+
+    let actual = tsvector_from_sql(&buf)
+        .unwrap()
+        .map(|l| {
+            let positions = l.positions().map(|p| (p.position(), p.weight())).collect();
+            Ok((l.lexeme(), positions))
+        })
+        .collect::<Vec<(&str, Vec<(u16, u8)>)>>()
+        .unwrap();
+    assert_eq!(actual, lexemes);
+}
+
+#[test]
+fn tsquery() {
+    // 'fat':*A & !'rat' <2> 'cat'
+    let items = vec![
+        TsQueryItem::And,
+        TsQueryItem::Phrase(2),
+        TsQueryItem::Operand {
+            lexeme: "cat",
+            weights: 0,
+            prefix: false,
+        },
+        TsQueryItem::Not,
+        TsQueryItem::Operand {
+            lexeme: "rat",
+            weights: 0,
+            prefix: false,
+        },
+        TsQueryItem::Operand {
+            lexeme: "fat",
+            weights: 1 << 3,
+            prefix: true,
+        },
+    ];
+
+    let mut buf = BytesMut::new();
+    tsquery_to_sql(items.iter().cloned(), &mut buf).unwrap();
+    let actual = tsquery_from_sql(&buf).unwrap().collect::<Vec<_>>().unwrap();
+    assert_eq!(actual, items);
+
+    let mut buf = BytesMut::new();
+    let items = vec![TsQueryItem::Operand {
+        lexeme: "a\0b",
+        weights: 0,
+        prefix: false,
+    }];
+    assert!(tsquery_to_sql(items, &mut buf).is_err());
+}
@@ -0,0 +1,247 @@
+//! Multidimensional arrays.
+
+use bytes::BytesMut;
+use fallible_iterator::FallibleIterator;
+use gaussdb_protocol::types::{self, ArrayDimension};
+use std::convert::TryFrom;
+use std::error::Error;
+use std::fmt;
+use std::slice;
+use std::vec;
+
+use crate::text::md_array_from_text;
+use crate::{FromSql, FromSqlText, FromSqlTextOwned, IsNull, Kind, ToSql, Type};
+
+/// A dimension of an [`Array`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Dimension {
+    /// The number of elements in the dimension.
+    pub len: i32,
+    /// The index of the first element of the dimension.
+    pub lower_bound: i32,
+}
+
+impl Dimension {
+    /// Creates a new dimension.
+    pub fn new(len: i32, lower_bound: i32) -> Dimension {
+        Dimension { len, lower_bound }
+    }
+}
+
+/// Error creating an [`Array`] whose elements do not fill its dimensions.
+#[derive(Debug)]
+pub struct ArrayShapeError(pub(crate) ());
+
+impl fmt::Display for ArrayShapeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("array elements do not match its dimensions")
+    }
+}
+
+impl Error for ArrayShapeError {}
+
+/// A Postgres array of any number of dimensions.
+///
+/// Unlike `Vec<T>`, which only maps to one-dimensional arrays starting at index 1, an `Array` keeps the length and
+/// lower bound of each of its dimensions. The elements are stored in row-major order, which is the order Postgres uses
+/// as well, so the last index varies fastest.
+///
+/// Arrays without elements have no dimensions, as on the server. `NULL` elements can be represented with an
+/// `Array<Option<T>>`; decoding them into any other element type fails.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Array<T> {
+    dimensions: Vec<Dimension>,
+    elements: Vec<T>,
+}
+
+impl<T> Array<T> {
+    /// Creates a one-dimensional array whose first element has the index `lower_bound`.
+    pub fn from_vec(elements: Vec<T>, lower_bound: i32) -> Array<T> {
+        let dimensions = if elements.is_empty() {
+            vec![]
+        } else {
+            let len = i32::try_from(elements.len()).expect("array too large");
+            vec![Dimension::new(len, lower_bound)]
+        };
+        Array {
+            dimensions,
+            elements,
+        }
+    }
+
+    /// Creates an array from its elements in row-major order and its dimensions.
+    ///
+    /// Returns an error if the number of elements differs from the product of the lengths of the dimensions.
+    pub fn from_parts(
+        elements: Vec<T>,
+        mut dimensions: Vec<Dimension>,
+    ) -> Result<Array<T>, ArrayShapeError> {
+        let len = dimensions.iter().try_fold(1usize, |len, dim| {
+            usize::try_from(dim.len)
+                .ok()
+                .and_then(|dim_len| len.checked_mul(dim_len))
+        });
+        if dimensions.is_empty() || len == Some(0) {
+            if !elements.is_empty() {
+                return Err(ArrayShapeError(()));
+            }
+            dimensions.clear();
+        } else if len != Some(elements.len()) {
+            return Err(ArrayShapeError(()));
+        }
+
+        Ok(Array {
+            dimensions,
+            elements,
+        })
+    }
+
+    /// Returns the dimensions of the array.
+    pub fn dimensions(&self) -> &[Dimension] {
+        &self.dimensions
+    }
+
+    /// Returns the total number of elements in the array.
+    pub fn len(&self) -> usize {
+        self.elements.len()
+    }
+
+    /// Determines if the array has no elements.
+    pub fn is_empty(&self) -> bool {
+        self.elements.is_empty()
+    }
+
+    /// Returns the element at the given indices, which are relative to the lower bound of each dimension.
+    ///
+    /// Returns `None` if the number of indices differs from the number of dimensions or if any index is out of bounds.
+    pub fn get(&self, indices: &[i32]) -> Option<&T> {
+        self.offset(indices).map(|i| &self.elements[i])
+    }
+
+    /// Like `Array::get`, but returns a mutable reference.
+    pub fn get_mut(&mut self, indices: &[i32]) -> Option<&mut T> {
+        self.offset(indices).map(move |i| &mut self.elements[i])
+    }
+
+    fn offset(&self, indices: &[i32]) -> Option<usize> {
+        if indices.len() != self.dimensions.len() || indices.is_empty() {
+            return None;
+        }
+
+        let mut offset = 0;
+        for (&index, dim) in indices.iter().zip(&self.dimensions) {
+            let index = i64::from(index) - i64::from(dim.lower_bound);
+            if index < 0 || index >= i64::from(dim.len) {
+                return None;
+            }
+            offset = offset * dim.len as usize + index as usize;
+        }
+        Some(offset)
+    }
+
+    /// Returns an iterator over the elements of the array in row-major order.
+    pub fn iter(&self) -> slice::Iter<'_, T> {
+        self.elements.iter()
+    }
+
+    /// Returns an iterator over mutable references to the elements of the array in row-major order.
+    pub fn iter_mut(&mut self) -> slice::IterMut<'_, T> {
+        self.elements.iter_mut()
+    }
+
+    /// Consumes the array, returning its elements in row-major order.
+    pub fn into_inner(self) -> Vec<T> {
+        self.elements
+    }
+}
+
+impl<T> IntoIterator for Array<T> {
+    type Item = T;
+    type IntoIter = vec::IntoIter<T>;
+
+    fn into_iter(self) -> vec::IntoIter<T> {
+        self.elements.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Array<T> {
+    type Item = &'a T;
+    type IntoIter = slice::Iter<'a, T>;
+
+    fn into_iter(self) -> slice::Iter<'a, T> {
+        self.elements.iter()
+    }
+}
+
+impl<'a, T: FromSql<'a>> FromSql<'a> for Array<T> {
+    fn from_sql(ty: &Type, raw: &'a [u8]) -> Result<Array<T>, Box<dyn Error + Sync + Send>> {
+        let member_type = match *ty.kind() {
+            Kind::Array(ref member) => member,
+            _ => panic!("expected array type"),
+        };
+
+        let array = types::array_from_sql(raw)?;
+        let dimensions = array
+            .dimensions()
+            .map(|d| Ok(Dimension::new(d.len, d.lower_bound)))
+            .collect::<Vec<_>>()?;
+        let elements = array
+            .values()
+            .map(|v| T::from_sql_nullable(member_type, v))
+            .collect::<Vec<_>>()?;
+
+        Array::from_parts(elements, dimensions).map_err(Into::into)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        match *ty.kind() {
+            Kind::Array(ref inner) => T::accepts(inner),
+            _ => false,
+        }
+    }
+}
+
+impl<'a, T: FromSqlTextOwned> FromSqlText<'a> for Array<T> {
+    fn from_sql_text(raw: &'a str) -> Result<Array<T>, Box<dyn Error + Sync + Send>> {
+        let (dimensions, values) = md_array_from_text(raw, T::ARRAY_DELIMITER)?;
+        let elements = values
+            .iter()
+            .map(|v| T::from_sql_text_nullable(v.as_deref()))
+            .collect::<Result<_, _>>()?;
+
+        Array::from_parts(elements, dimensions).map_err(Into::into)
+    }
+}
+
+impl<T: ToSql> ToSql for Array<T> {
+    fn to_sql(&self, ty: &Type, w: &mut BytesMut) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+        let member_type = match *ty.kind() {
+            Kind::Array(ref member) => member,
+            _ => panic!("expected array type"),
+        };
+
+        types::array_to_sql(
+            self.dimensions.iter().map(|d| ArrayDimension {
+                len: d.len,
+                lower_bound: d.lower_bound,
+            }),
+            member_type.oid(),
+            self.elements.iter(),
+            |e, w| match e.to_sql(member_type, w)? {
+                IsNull::No => Ok(gaussdb_protocol::IsNull::No),
+                IsNull::Yes => Ok(gaussdb_protocol::IsNull::Yes),
+            },
+            w,
+        )?;
+        Ok(IsNull::No)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        match *ty.kind() {
+            Kind::Array(ref member) => T::accepts(member),
+            _ => false,
+        }
+    }
+
+    to_sql_checked!();
+}
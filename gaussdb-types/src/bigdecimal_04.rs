@@ -0,0 +1,31 @@
+use bigdecimal_04::BigDecimal;
+use bytes::BytesMut;
+use std::error::Error;
+
+use crate::{FromSql, FromSqlText, IsNull, Numeric, ToSql, Type};
+
+impl<'a> FromSql<'a> for BigDecimal {
+    fn from_sql(ty: &Type, raw: &[u8]) -> Result<BigDecimal, Box<dyn Error + Sync + Send>> {
+        let numeric = Numeric::from_sql(ty, raw)?;
+        BigDecimal::from_sql_text(&numeric.to_string())
+    }
+
+    accepts!(NUMERIC);
+}
+
+impl<'a> FromSqlText<'a> for BigDecimal {
+    fn from_sql_text(raw: &'a str) -> Result<BigDecimal, Box<dyn Error + Sync + Send>> {
+        raw.parse()
+            .map_err(|_| format!("numeric `{}` cannot be represented as a BigDecimal", raw).into())
+    }
+}
+
+impl ToSql for BigDecimal {
+    fn to_sql(&self, ty: &Type, w: &mut BytesMut) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+        let numeric = self.to_string().parse::<Numeric>()?;
+        numeric.to_sql(ty, w)
+    }
+
+    accepts!(NUMERIC);
+    to_sql_checked!();
+}
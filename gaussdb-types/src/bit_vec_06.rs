@@ -3,7 +3,7 @@ use bytes::BytesMut;
 use gaussdb_protocol::types;
 use std::error::Error;
 
-use crate::{FromSql, IsNull, ToSql, Type};
+use crate::{FromSql, FromSqlText, IsNull, ToSql, Type};
 
 impl<'a> FromSql<'a> for BitVec {
     fn from_sql(_: &Type, raw: &[u8]) -> Result<BitVec, Box<dyn Error + Sync + Send>> {
@@ -19,6 +19,18 @@ impl<'a> FromSql<'a> for BitVec {
     accepts!(BIT, VARBIT);
 }
 
+impl<'a> FromSqlText<'a> for BitVec {
+    fn from_sql_text(raw: &'a str) -> Result<BitVec, Box<dyn Error + Sync + Send>> {
+        raw.bytes()
+            .map(|b| match b {
+                b'0' => Ok(false),
+                b'1' => Ok(true),
+                _ => Err(format!("invalid bit string `{}`", raw).into()),
+            })
+            .collect()
+    }
+}
+
 impl ToSql for BitVec {
     fn to_sql(&self, _: &Type, out: &mut BytesMut) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
         types::varbit_to_sql(self.len(), self.to_bytes().into_iter(), out)?;
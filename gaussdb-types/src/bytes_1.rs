@@ -0,0 +1,55 @@
+use bytes::{Bytes, BytesMut};
+use gaussdb_protocol::types;
+use std::error::Error;
+
+use crate::text::bytea_from_text;
+use crate::{FromSql, FromSqlText, IsNull, ToSql, Type};
+
+/// The value is copied out of the row. `tokio_gaussdb::Row::get_bytes` returns it without copying.
+impl<'a> FromSql<'a> for Bytes {
+    fn from_sql(_: &Type, raw: &'a [u8]) -> Result<Bytes, Box<dyn Error + Sync + Send>> {
+        Ok(Bytes::copy_from_slice(types::bytea_from_sql(raw)))
+    }
+
+    accepts!(BYTEA, RAW, BLOB);
+}
+
+impl<'a> FromSqlText<'a> for Bytes {
+    fn from_sql_text(raw: &'a str) -> Result<Bytes, Box<dyn Error + Sync + Send>> {
+        bytea_from_text(raw).map(Bytes::from)
+    }
+}
+
+impl ToSql for Bytes {
+    fn to_sql(&self, _: &Type, w: &mut BytesMut) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+        types::bytea_to_sql(self, w);
+        Ok(IsNull::No)
+    }
+
+    accepts!(BYTEA, RAW, BLOB);
+    to_sql_checked!();
+}
+
+impl<'a> FromSql<'a> for BytesMut {
+    fn from_sql(_: &Type, raw: &'a [u8]) -> Result<BytesMut, Box<dyn Error + Sync + Send>> {
+        Ok(BytesMut::from(types::bytea_from_sql(raw)))
+    }
+
+    accepts!(BYTEA, RAW, BLOB);
+}
+
+impl<'a> FromSqlText<'a> for BytesMut {
+    fn from_sql_text(raw: &'a str) -> Result<BytesMut, Box<dyn Error + Sync + Send>> {
+        bytea_from_text(raw).map(|v| BytesMut::from(&*v))
+    }
+}
+
+impl ToSql for BytesMut {
+    fn to_sql(&self, _: &Type, w: &mut BytesMut) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+        types::bytea_to_sql(self, w);
+        Ok(IsNull::No)
+    }
+
+    accepts!(BYTEA, RAW, BLOB);
+    to_sql_checked!();
+}
@@ -3,9 +3,11 @@ use chrono_04::{
     DateTime, Duration, FixedOffset, Local, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc,
 };
 use gaussdb_protocol::types;
+use std::convert::TryFrom;
 use std::error::Error;
 
-use crate::{FromSql, IsNull, ToSql, Type};
+use crate::text;
+use crate::{FromSql, FromSqlText, Interval, IntervalConversionError, IsNull, ToSql, Type};
 
 fn base() -> NaiveDateTime {
     NaiveDate::from_ymd_opt(2000, 1, 1)
@@ -22,7 +24,16 @@ impl<'a> FromSql<'a> for NaiveDateTime {
             .ok_or_else(|| "value too large to decode".into())
     }
 
-    accepts!(TIMESTAMP);
+    accepts!(TIMESTAMP, SMALLDATETIME);
+}
+
+impl<'a> FromSqlText<'a> for NaiveDateTime {
+    fn from_sql_text(raw: &'a str) -> Result<NaiveDateTime, Box<dyn Error + Sync + Send>> {
+        let t = text::timestamp_from_text(raw)?;
+        base()
+            .checked_add_signed(Duration::microseconds(t))
+            .ok_or_else(|| "value too large to decode".into())
+    }
 }
 
 impl ToSql for NaiveDateTime {
@@ -35,7 +46,7 @@ impl ToSql for NaiveDateTime {
         Ok(IsNull::No)
     }
 
-    accepts!(TIMESTAMP);
+    accepts!(TIMESTAMP, SMALLDATETIME);
     to_sql_checked!();
 }
 
@@ -48,6 +59,13 @@ impl<'a> FromSql<'a> for DateTime<Utc> {
     accepts!(TIMESTAMPTZ);
 }
 
+impl<'a> FromSqlText<'a> for DateTime<Utc> {
+    fn from_sql_text(raw: &'a str) -> Result<DateTime<Utc>, Box<dyn Error + Sync + Send>> {
+        let naive = NaiveDateTime::from_sql_text(raw)?;
+        Ok(Utc.from_utc_datetime(&naive))
+    }
+}
+
 impl ToSql for DateTime<Utc> {
     fn to_sql(
         &self,
@@ -70,6 +88,13 @@ impl<'a> FromSql<'a> for DateTime<Local> {
     accepts!(TIMESTAMPTZ);
 }
 
+impl<'a> FromSqlText<'a> for DateTime<Local> {
+    fn from_sql_text(raw: &'a str) -> Result<DateTime<Local>, Box<dyn Error + Sync + Send>> {
+        let utc = DateTime::<Utc>::from_sql_text(raw)?;
+        Ok(utc.with_timezone(&Local))
+    }
+}
+
 impl ToSql for DateTime<Local> {
     fn to_sql(
         &self,
@@ -95,6 +120,13 @@ impl<'a> FromSql<'a> for DateTime<FixedOffset> {
     accepts!(TIMESTAMPTZ);
 }
 
+impl<'a> FromSqlText<'a> for DateTime<FixedOffset> {
+    fn from_sql_text(raw: &'a str) -> Result<DateTime<FixedOffset>, Box<dyn Error + Sync + Send>> {
+        let utc = DateTime::<Utc>::from_sql_text(raw)?;
+        Ok(utc.with_timezone(&FixedOffset::east_opt(0).unwrap()))
+    }
+}
+
 impl ToSql for DateTime<FixedOffset> {
     fn to_sql(
         &self,
@@ -120,6 +152,16 @@ impl<'a> FromSql<'a> for NaiveDate {
     accepts!(DATE);
 }
 
+impl<'a> FromSqlText<'a> for NaiveDate {
+    fn from_sql_text(raw: &'a str) -> Result<NaiveDate, Box<dyn Error + Sync + Send>> {
+        let jd = text::date_from_text(raw)?;
+        base()
+            .date()
+            .checked_add_signed(Duration::days(i64::from(jd)))
+            .ok_or_else(|| "value too large to decode".into())
+    }
+}
+
 impl ToSql for NaiveDate {
     fn to_sql(&self, _: &Type, w: &mut BytesMut) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
         let jd = self.signed_duration_since(base().date()).num_days();
@@ -144,6 +186,13 @@ impl<'a> FromSql<'a> for NaiveTime {
     accepts!(TIME);
 }
 
+impl<'a> FromSqlText<'a> for NaiveTime {
+    fn from_sql_text(raw: &'a str) -> Result<NaiveTime, Box<dyn Error + Sync + Send>> {
+        let usec = text::time_from_text(raw)?;
+        Ok(NaiveTime::from_hms_opt(0, 0, 0).unwrap() + Duration::microseconds(usec))
+    }
+}
+
 impl ToSql for NaiveTime {
     fn to_sql(&self, _: &Type, w: &mut BytesMut) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
         let delta = self.signed_duration_since(NaiveTime::from_hms_opt(0, 0, 0).unwrap());
@@ -158,3 +207,73 @@ impl ToSql for NaiveTime {
     accepts!(TIME);
     to_sql_checked!();
 }
+
+impl<'a> FromSql<'a> for (NaiveTime, FixedOffset) {
+    fn from_sql(
+        _: &Type,
+        raw: &[u8],
+    ) -> Result<(NaiveTime, FixedOffset), Box<dyn Error + Sync + Send>> {
+        let (usec, offset) = types::timetz_from_sql(raw)?;
+        timetz(usec, offset)
+    }
+
+    accepts!(TIMETZ);
+}
+
+impl<'a> FromSqlText<'a> for (NaiveTime, FixedOffset) {
+    fn from_sql_text(
+        raw: &'a str,
+    ) -> Result<(NaiveTime, FixedOffset), Box<dyn Error + Sync + Send>> {
+        let (usec, offset) = text::timetz_from_text(raw)?;
+        timetz(usec, offset)
+    }
+}
+
+fn timetz(
+    usec: i64,
+    offset: i32,
+) -> Result<(NaiveTime, FixedOffset), Box<dyn Error + Sync + Send>> {
+    let time = NaiveTime::from_hms_opt(0, 0, 0).unwrap() + Duration::microseconds(usec);
+    let offset = FixedOffset::east_opt(offset).ok_or("invalid timetz offset")?;
+    Ok((time, offset))
+}
+
+impl ToSql for (NaiveTime, FixedOffset) {
+    fn to_sql(&self, _: &Type, w: &mut BytesMut) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+        let delta = self
+            .0
+            .signed_duration_since(NaiveTime::from_hms_opt(0, 0, 0).unwrap());
+        let time = match delta.num_microseconds() {
+            Some(time) => time,
+            None => return Err("value too large to transmit".into()),
+        };
+        types::timetz_to_sql(time, self.1.local_minus_utc(), w);
+        Ok(IsNull::No)
+    }
+
+    accepts!(TIMETZ);
+    to_sql_checked!();
+}
+
+impl TryFrom<Interval> for Duration {
+    type Error = IntervalConversionError;
+
+    fn try_from(interval: Interval) -> Result<Duration, IntervalConversionError> {
+        interval
+            .total_microseconds()
+            .and_then(|usecs| i64::try_from(usecs).ok())
+            .map(Duration::microseconds)
+            .ok_or(IntervalConversionError(()))
+    }
+}
+
+impl TryFrom<Duration> for Interval {
+    type Error = IntervalConversionError;
+
+    fn try_from(duration: Duration) -> Result<Interval, IntervalConversionError> {
+        duration
+            .num_microseconds()
+            .map(|usecs| Interval::new(0, 0, usecs))
+            .ok_or(IntervalConversionError(()))
+    }
+}
@@ -0,0 +1,36 @@
+use bytes::BytesMut;
+use chrono_tz_010::Tz;
+use gaussdb_protocol::types;
+use std::error::Error;
+
+use crate::{FromSql, FromSqlText, IsNull, ToSql, Type};
+
+impl<'a> FromSql<'a> for Tz {
+    fn from_sql(ty: &Type, raw: &'a [u8]) -> Result<Tz, Box<dyn Error + Sync + Send>> {
+        Tz::from_sql_text(<&str as FromSql>::from_sql(ty, raw)?)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        <&str as FromSql>::accepts(ty)
+    }
+}
+
+impl<'a> FromSqlText<'a> for Tz {
+    fn from_sql_text(raw: &'a str) -> Result<Tz, Box<dyn Error + Sync + Send>> {
+        raw.parse()
+            .map_err(|_| format!("`{}` is not a known time zone", raw).into())
+    }
+}
+
+impl ToSql for Tz {
+    fn to_sql(&self, _: &Type, w: &mut BytesMut) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+        types::text_to_sql(self.name(), w);
+        Ok(IsNull::No)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        <&str as ToSql>::accepts(ty)
+    }
+
+    to_sql_checked!();
+}
@@ -2,8 +2,9 @@ use bytes::BytesMut;
 use cidr_03::{IpCidr, IpInet};
 use gaussdb_protocol::types;
 use std::error::Error;
+use std::net::IpAddr;
 
-use crate::{FromSql, IsNull, ToSql, Type};
+use crate::{FromSql, FromSqlText, IsNull, ToSql, Type};
 
 impl<'a> FromSql<'a> for IpCidr {
     fn from_sql(_: &Type, raw: &[u8]) -> Result<Self, Box<dyn Error + Sync + Send>> {
@@ -14,6 +15,12 @@ impl<'a> FromSql<'a> for IpCidr {
     accepts!(CIDR);
 }
 
+impl<'a> FromSqlText<'a> for IpCidr {
+    fn from_sql_text(raw: &'a str) -> Result<Self, Box<dyn Error + Sync + Send>> {
+        raw.parse().map_err(Into::into)
+    }
+}
+
 impl ToSql for IpCidr {
     fn to_sql(&self, _: &Type, w: &mut BytesMut) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
         types::inet_to_sql(self.first_address(), self.network_length(), w);
@@ -33,6 +40,18 @@ impl<'a> FromSql<'a> for IpInet {
     accepts!(INET);
 }
 
+impl<'a> FromSqlText<'a> for IpInet {
+    fn from_sql_text(raw: &'a str) -> Result<Self, Box<dyn Error + Sync + Send>> {
+        // the netmask is only printed if it does not cover the whole address
+        if raw.contains('/') {
+            raw.parse().map_err(Into::into)
+        } else {
+            let addr = raw.parse::<IpAddr>()?;
+            Ok(IpInet::new(addr, if addr.is_ipv4() { 32 } else { 128 })?)
+        }
+    }
+}
+
 impl ToSql for IpInet {
     fn to_sql(&self, _: &Type, w: &mut BytesMut) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
         types::inet_to_sql(self.address(), self.network_length(), w);
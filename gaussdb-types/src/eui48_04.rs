@@ -3,25 +3,51 @@ use eui48_04::MacAddress;
 use gaussdb_protocol::types;
 use std::error::Error;
 
-use crate::{FromSql, IsNull, ToSql, Type};
+use crate::text::macaddr8_from_text;
+use crate::{FromSql, FromSqlText, IsNull, ToSql, Type};
+
+// a `MACADDR8` holds an EUI-48 address when the bytes `FF:FE` are inserted after the first three
+fn from_eui64(bytes: [u8; 8]) -> Result<MacAddress, Box<dyn Error + Sync + Send>> {
+    match bytes {
+        [a, b, c, 0xff, 0xfe, d, e, f] => Ok(MacAddress::new([a, b, c, d, e, f])),
+        _ => Err("macaddr8 value is not an EUI-48 address".into()),
+    }
+}
 
 impl<'a> FromSql<'a> for MacAddress {
-    fn from_sql(_: &Type, raw: &[u8]) -> Result<MacAddress, Box<dyn Error + Sync + Send>> {
+    fn from_sql(ty: &Type, raw: &[u8]) -> Result<MacAddress, Box<dyn Error + Sync + Send>> {
+        if *ty == Type::MACADDR8 {
+            return from_eui64(types::macaddr8_from_sql(raw)?);
+        }
         let bytes = types::macaddr_from_sql(raw)?;
         Ok(MacAddress::new(bytes))
     }
 
-    accepts!(MACADDR);
+    accepts!(MACADDR, MACADDR8);
+}
+
+impl<'a> FromSqlText<'a> for MacAddress {
+    fn from_sql_text(raw: &'a str) -> Result<MacAddress, Box<dyn Error + Sync + Send>> {
+        if raw.len() == 23 {
+            return from_eui64(macaddr8_from_text(raw)?);
+        }
+        MacAddress::parse_str(raw).map_err(Into::into)
+    }
 }
 
 impl ToSql for MacAddress {
-    fn to_sql(&self, _: &Type, w: &mut BytesMut) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+    fn to_sql(&self, ty: &Type, w: &mut BytesMut) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
         let mut bytes = [0; 6];
         bytes.copy_from_slice(self.as_bytes());
-        types::macaddr_to_sql(bytes, w);
+        if *ty == Type::MACADDR8 {
+            let [a, b, c, d, e, f] = bytes;
+            types::macaddr8_to_sql([a, b, c, 0xff, 0xfe, d, e, f], w);
+        } else {
+            types::macaddr_to_sql(bytes, w);
+        }
         Ok(IsNull::No)
     }
 
-    accepts!(MACADDR);
+    accepts!(MACADDR, MACADDR8);
     to_sql_checked!();
 }
@@ -1,10 +1,11 @@
 use bytes::BytesMut;
 use fallible_iterator::FallibleIterator;
 use gaussdb_protocol::types;
-use geo_types_0_7::{Coord, LineString, Point, Rect};
+use geo_types_0_7::{Coord, Line, LineString, Point, Polygon, Rect};
 use std::error::Error;
 
-use crate::{FromSql, IsNull, ToSql, Type};
+use crate::text;
+use crate::{FromSql, FromSqlText, IsNull, ToSql, Type};
 
 impl<'a> FromSql<'a> for Point<f64> {
     fn from_sql(_: &Type, raw: &[u8]) -> Result<Self, Box<dyn Error + Sync + Send>> {
@@ -15,6 +16,15 @@ impl<'a> FromSql<'a> for Point<f64> {
     accepts!(POINT);
 }
 
+impl<'a> FromSqlText<'a> for Point<f64> {
+    fn from_sql_text(raw: &'a str) -> Result<Self, Box<dyn Error + Sync + Send>> {
+        match *text::points_from_text(raw)? {
+            [(x, y)] => Ok(Point::new(x, y)),
+            _ => Err(format!("invalid point `{}`", raw).into()),
+        }
+    }
+}
+
 impl ToSql for Point<f64> {
     fn to_sql(&self, _: &Type, out: &mut BytesMut) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
         types::point_to_sql(self.x(), self.y(), out);
@@ -37,6 +47,17 @@ impl<'a> FromSql<'a> for Rect<f64> {
     accepts!(BOX);
 }
 
+impl<'a> FromSqlText<'a> for Rect<f64> {
+    const ARRAY_DELIMITER: u8 = b';';
+
+    fn from_sql_text(raw: &'a str) -> Result<Self, Box<dyn Error + Sync + Send>> {
+        match *text::points_from_text(raw)? {
+            [upper_right, lower_left] => Ok(Rect::new(lower_left, upper_right)),
+            _ => Err(format!("invalid box `{}`", raw).into()),
+        }
+    }
+}
+
 impl ToSql for Rect<f64> {
     fn to_sql(&self, _: &Type, out: &mut BytesMut) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
         types::box_to_sql(self.min().x, self.min().y, self.max().x, self.max().y, out);
@@ -60,6 +81,16 @@ impl<'a> FromSql<'a> for LineString<f64> {
     accepts!(PATH);
 }
 
+impl<'a> FromSqlText<'a> for LineString<f64> {
+    fn from_sql_text(raw: &'a str) -> Result<Self, Box<dyn Error + Sync + Send>> {
+        let points = text::points_from_text(raw)?
+            .into_iter()
+            .map(|(x, y)| Coord { x, y })
+            .collect();
+        Ok(LineString(points))
+    }
+}
+
 impl ToSql for LineString<f64> {
     fn to_sql(&self, _: &Type, out: &mut BytesMut) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
         let closed = false; // always encode an open path from LineString
@@ -70,3 +101,78 @@ impl ToSql for LineString<f64> {
     accepts!(PATH);
     to_sql_checked!();
 }
+
+impl<'a> FromSql<'a> for Line<f64> {
+    fn from_sql(_: &Type, raw: &[u8]) -> Result<Self, Box<dyn Error + Sync + Send>> {
+        let lseg = types::lseg_from_sql(raw)?;
+        Ok(Line::new(
+            (lseg.start().x(), lseg.start().y()),
+            (lseg.end().x(), lseg.end().y()),
+        ))
+    }
+
+    accepts!(LSEG);
+}
+
+impl<'a> FromSqlText<'a> for Line<f64> {
+    fn from_sql_text(raw: &'a str) -> Result<Self, Box<dyn Error + Sync + Send>> {
+        match *text::points_from_text(raw)? {
+            [start, end] => Ok(Line::new(start, end)),
+            _ => Err(format!("invalid lseg `{}`", raw).into()),
+        }
+    }
+}
+
+impl ToSql for Line<f64> {
+    fn to_sql(&self, _: &Type, out: &mut BytesMut) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+        types::lseg_to_sql(self.start.x, self.start.y, self.end.x, self.end.y, out);
+        Ok(IsNull::No)
+    }
+
+    accepts!(LSEG);
+    to_sql_checked!();
+}
+
+impl<'a> FromSql<'a> for Polygon<f64> {
+    fn from_sql(_: &Type, raw: &[u8]) -> Result<Self, Box<dyn Error + Sync + Send>> {
+        let polygon = types::polygon_from_sql(raw)?;
+        let points = polygon
+            .points()
+            .map(|p| Ok(Coord { x: p.x(), y: p.y() }))
+            .collect()?;
+        Ok(Polygon::new(LineString(points), vec![]))
+    }
+
+    accepts!(POLYGON);
+}
+
+impl<'a> FromSqlText<'a> for Polygon<f64> {
+    fn from_sql_text(raw: &'a str) -> Result<Self, Box<dyn Error + Sync + Send>> {
+        let points = text::points_from_text(raw)?
+            .into_iter()
+            .map(|(x, y)| Coord { x, y })
+            .collect();
+        Ok(Polygon::new(LineString(points), vec![]))
+    }
+}
+
+impl ToSql for Polygon<f64> {
+    fn to_sql(&self, _: &Type, out: &mut BytesMut) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+        if !self.interiors().is_empty() {
+            return Err("polygons with interior rings are not supported".into());
+        }
+
+        // the exterior ring is closed by repeating its first point, which Postgres polygons leave implicit
+        let mut points = &self.exterior().0[..];
+        if let [first, .., last] = points {
+            if first == last {
+                points = &points[..points.len() - 1];
+            }
+        }
+        types::polygon_to_sql(points.iter().map(|p| (p.x, p.y)), out)?;
+        Ok(IsNull::No)
+    }
+
+    accepts!(POLYGON);
+    to_sql_checked!();
+}
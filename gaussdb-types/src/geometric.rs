@@ -0,0 +1,242 @@
+//! Geometric types.
+
+use bytes::BytesMut;
+use fallible_iterator::FallibleIterator;
+use gaussdb_protocol::types;
+use std::error::Error;
+
+use crate::text::{numbers_from_text, points_from_text};
+use crate::{FromSql, FromSqlText, IsNull, ToSql, Type};
+
+/// Postgres `POINT` type.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Point {
+    /// The x coordinate of the point.
+    pub x: f64,
+    /// The y coordinate of the point.
+    pub y: f64,
+}
+
+impl Point {
+    /// Creates a new point.
+    pub fn new(x: f64, y: f64) -> Point {
+        Point { x, y }
+    }
+}
+
+impl From<types::Point> for Point {
+    fn from(point: types::Point) -> Point {
+        Point::new(point.x(), point.y())
+    }
+}
+
+impl<'a> FromSql<'a> for Point {
+    fn from_sql(_: &Type, raw: &'a [u8]) -> Result<Point, Box<dyn Error + Sync + Send>> {
+        types::point_from_sql(raw).map(Point::from)
+    }
+
+    accepts!(POINT);
+}
+
+impl<'a> FromSqlText<'a> for Point {
+    fn from_sql_text(raw: &'a str) -> Result<Point, Box<dyn Error + Sync + Send>> {
+        match *points_from_text(raw)? {
+            [(x, y)] => Ok(Point::new(x, y)),
+            _ => Err(format!("invalid point `{}`", raw).into()),
+        }
+    }
+}
+
+impl ToSql for Point {
+    fn to_sql(&self, _: &Type, out: &mut BytesMut) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+        types::point_to_sql(self.x, self.y, out);
+        Ok(IsNull::No)
+    }
+
+    accepts!(POINT);
+    to_sql_checked!();
+}
+
+/// Postgres `LSEG` type, a finite line segment.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Lseg {
+    /// The start point of the segment.
+    pub start: Point,
+    /// The end point of the segment.
+    pub end: Point,
+}
+
+impl Lseg {
+    /// Creates a new line segment.
+    pub fn new(start: Point, end: Point) -> Lseg {
+        Lseg { start, end }
+    }
+}
+
+impl<'a> FromSql<'a> for Lseg {
+    fn from_sql(_: &Type, raw: &'a [u8]) -> Result<Lseg, Box<dyn Error + Sync + Send>> {
+        let lseg = types::lseg_from_sql(raw)?;
+        Ok(Lseg::new(lseg.start().into(), lseg.end().into()))
+    }
+
+    accepts!(LSEG);
+}
+
+impl<'a> FromSqlText<'a> for Lseg {
+    fn from_sql_text(raw: &'a str) -> Result<Lseg, Box<dyn Error + Sync + Send>> {
+        match *points_from_text(raw)? {
+            [(x1, y1), (x2, y2)] => Ok(Lseg::new(Point::new(x1, y1), Point::new(x2, y2))),
+            _ => Err(format!("invalid lseg `{}`", raw).into()),
+        }
+    }
+}
+
+impl ToSql for Lseg {
+    fn to_sql(&self, _: &Type, out: &mut BytesMut) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+        types::lseg_to_sql(self.start.x, self.start.y, self.end.x, self.end.y, out);
+        Ok(IsNull::No)
+    }
+
+    accepts!(LSEG);
+    to_sql_checked!();
+}
+
+/// Postgres `LINE` type, an infinite line represented by the equation `Ax + By + C = 0`.
+///
+/// `A` and `B` must not both be zero.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Line {
+    /// The `A` coefficient.
+    pub a: f64,
+    /// The `B` coefficient.
+    pub b: f64,
+    /// The `C` coefficient.
+    pub c: f64,
+}
+
+impl Line {
+    /// Creates a new line from the coefficients of its equation.
+    pub fn new(a: f64, b: f64, c: f64) -> Line {
+        Line { a, b, c }
+    }
+}
+
+impl<'a> FromSql<'a> for Line {
+    fn from_sql(_: &Type, raw: &'a [u8]) -> Result<Line, Box<dyn Error + Sync + Send>> {
+        let line = types::line_from_sql(raw)?;
+        Ok(Line::new(line.a(), line.b(), line.c()))
+    }
+
+    accepts!(LINE);
+}
+
+impl<'a> FromSqlText<'a> for Line {
+    fn from_sql_text(raw: &'a str) -> Result<Line, Box<dyn Error + Sync + Send>> {
+        match *numbers_from_text(raw)? {
+            [a, b, c] => Ok(Line::new(a, b, c)),
+            _ => Err(format!("invalid line `{}`", raw).into()),
+        }
+    }
+}
+
+impl ToSql for Line {
+    fn to_sql(&self, _: &Type, out: &mut BytesMut) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+        types::line_to_sql(self.a, self.b, self.c, out);
+        Ok(IsNull::No)
+    }
+
+    accepts!(LINE);
+    to_sql_checked!();
+}
+
+/// Postgres `POLYGON` type.
+///
+/// The polygon is implicitly closed, so its last vertex should not repeat the first one.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Polygon {
+    /// The vertices of the polygon.
+    pub points: Vec<Point>,
+}
+
+impl Polygon {
+    /// Creates a new polygon from its vertices.
+    pub fn new(points: Vec<Point>) -> Polygon {
+        Polygon { points }
+    }
+}
+
+impl<'a> FromSql<'a> for Polygon {
+    fn from_sql(_: &Type, raw: &'a [u8]) -> Result<Polygon, Box<dyn Error + Sync + Send>> {
+        let points = types::polygon_from_sql(raw)?
+            .points()
+            .map(|p| Ok(Point::from(p)))
+            .collect()?;
+        Ok(Polygon::new(points))
+    }
+
+    accepts!(POLYGON);
+}
+
+impl<'a> FromSqlText<'a> for Polygon {
+    fn from_sql_text(raw: &'a str) -> Result<Polygon, Box<dyn Error + Sync + Send>> {
+        let points = points_from_text(raw)?
+            .into_iter()
+            .map(|(x, y)| Point::new(x, y))
+            .collect();
+        Ok(Polygon::new(points))
+    }
+}
+
+impl ToSql for Polygon {
+    fn to_sql(&self, _: &Type, out: &mut BytesMut) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+        types::polygon_to_sql(self.points.iter().map(|p| (p.x, p.y)), out)?;
+        Ok(IsNull::No)
+    }
+
+    accepts!(POLYGON);
+    to_sql_checked!();
+}
+
+/// Postgres `CIRCLE` type.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Circle {
+    /// The center of the circle.
+    pub center: Point,
+    /// The radius of the circle.
+    pub radius: f64,
+}
+
+impl Circle {
+    /// Creates a new circle.
+    pub fn new(center: Point, radius: f64) -> Circle {
+        Circle { center, radius }
+    }
+}
+
+impl<'a> FromSql<'a> for Circle {
+    fn from_sql(_: &Type, raw: &'a [u8]) -> Result<Circle, Box<dyn Error + Sync + Send>> {
+        let circle = types::circle_from_sql(raw)?;
+        Ok(Circle::new(circle.center().into(), circle.radius()))
+    }
+
+    accepts!(CIRCLE);
+}
+
+impl<'a> FromSqlText<'a> for Circle {
+    fn from_sql_text(raw: &'a str) -> Result<Circle, Box<dyn Error + Sync + Send>> {
+        match *numbers_from_text(raw)? {
+            [x, y, radius] => Ok(Circle::new(Point::new(x, y), radius)),
+            _ => Err(format!("invalid circle `{}`", raw).into()),
+        }
+    }
+}
+
+impl ToSql for Circle {
+    fn to_sql(&self, _: &Type, out: &mut BytesMut) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+        types::circle_to_sql(self.center.x, self.center.y, self.radius, out);
+        Ok(IsNull::No)
+    }
+
+    accepts!(CIRCLE);
+    to_sql_checked!();
+}
@@ -0,0 +1,520 @@
+//! The `INTERVAL` type.
+
+use bytes::{BufMut, BytesMut};
+use std::convert::TryFrom;
+use std::error::Error;
+use std::fmt;
+use std::ops::{Add, Neg, Sub};
+use std::str::FromStr;
+use std::time::Duration;
+
+use crate::{FromSql, FromSqlText, IsNull, ToSql, Type};
+
+const USEC_PER_SEC: i64 = 1_000_000;
+const USEC_PER_MIN: i64 = 60 * USEC_PER_SEC;
+const USEC_PER_HOUR: i64 = 60 * USEC_PER_MIN;
+const USEC_PER_DAY: i64 = 24 * USEC_PER_HOUR;
+
+/// Postgres `INTERVAL` type.
+///
+/// An interval is made up of three independent fields, as months and days do not have a fixed length. Like the
+/// server, arithmetic is performed field by field and values are never normalized, so `1 mon` and `30 days` are
+/// distinct intervals.
+///
+/// `Display` produces the server's default `postgres` interval style, and `Interval::to_iso8601` the `iso_8601`
+/// style. `FromStr` accepts either of those styles as well as `postgres_verbose`.
+///
+/// Conversions to and from duration types treat a day as 24 hours, and fail for intervals with a nonzero number of
+/// months.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Interval {
+    /// The number of months.
+    pub months: i32,
+    /// The number of days.
+    pub days: i32,
+    /// The number of microseconds.
+    pub microseconds: i64,
+}
+
+/// Error parsing an `Interval`.
+#[derive(Debug)]
+pub struct ParseIntervalError(());
+
+impl fmt::Display for ParseIntervalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("invalid interval value")
+    }
+}
+
+impl Error for ParseIntervalError {}
+
+/// Error converting between an `Interval` and a duration type.
+#[derive(Debug)]
+pub struct IntervalConversionError(pub(crate) ());
+
+impl fmt::Display for IntervalConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("interval out of range for conversion")
+    }
+}
+
+impl Error for IntervalConversionError {}
+
+impl Interval {
+    /// The zero-length interval.
+    pub const ZERO: Interval = Interval::new(0, 0, 0);
+
+    /// Creates a new interval.
+    pub const fn new(months: i32, days: i32, microseconds: i64) -> Interval {
+        Interval {
+            months,
+            days,
+            microseconds,
+        }
+    }
+
+    /// Adds two intervals, returning `None` on overflow.
+    pub fn checked_add(self, other: Interval) -> Option<Interval> {
+        Some(Interval {
+            months: self.months.checked_add(other.months)?,
+            days: self.days.checked_add(other.days)?,
+            microseconds: self.microseconds.checked_add(other.microseconds)?,
+        })
+    }
+
+    /// Subtracts two intervals, returning `None` on overflow.
+    pub fn checked_sub(self, other: Interval) -> Option<Interval> {
+        Some(Interval {
+            months: self.months.checked_sub(other.months)?,
+            days: self.days.checked_sub(other.days)?,
+            microseconds: self.microseconds.checked_sub(other.microseconds)?,
+        })
+    }
+
+    /// Multiplies each field of the interval, returning `None` on overflow.
+    pub fn checked_mul(self, factor: i32) -> Option<Interval> {
+        Some(Interval {
+            months: self.months.checked_mul(factor)?,
+            days: self.days.checked_mul(factor)?,
+            microseconds: self.microseconds.checked_mul(i64::from(factor))?,
+        })
+    }
+
+    /// Negates the interval, returning `None` on overflow.
+    pub fn checked_neg(self) -> Option<Interval> {
+        Some(Interval {
+            months: self.months.checked_neg()?,
+            days: self.days.checked_neg()?,
+            microseconds: self.microseconds.checked_neg()?,
+        })
+    }
+
+    /// Returns the total length of the interval in microseconds, treating a day as 24 hours.
+    ///
+    /// Returns `None` if the interval has a nonzero number of months.
+    pub fn total_microseconds(&self) -> Option<i128> {
+        if self.months != 0 {
+            return None;
+        }
+        Some(i128::from(self.days) * i128::from(USEC_PER_DAY) + i128::from(self.microseconds))
+    }
+
+    /// Returns the interval formatted in the `iso_8601` interval style, e.g. `P1Y2M3DT4H5M6.5S`.
+    pub fn to_iso8601(&self) -> String {
+        let mut out = String::from("P");
+        let parts = [
+            (i64::from(self.months / 12), 'Y'),
+            (i64::from(self.months % 12), 'M'),
+            (i64::from(self.days), 'D'),
+        ];
+        for (value, unit) in parts.iter() {
+            if *value != 0 {
+                out.push_str(&format!("{}{}", value, unit));
+            }
+        }
+
+        let usecs = self.microseconds;
+        if usecs != 0 {
+            out.push('T');
+            let sign = if usecs < 0 { "-" } else { "" };
+            let usecs = usecs.unsigned_abs();
+            let hours = usecs / USEC_PER_HOUR as u64;
+            let minutes = usecs / USEC_PER_MIN as u64 % 60;
+            let seconds = usecs % USEC_PER_MIN as u64;
+            if hours != 0 {
+                out.push_str(&format!("{}{}H", sign, hours));
+            }
+            if minutes != 0 {
+                out.push_str(&format!("{}{}M", sign, minutes));
+            }
+            if seconds != 0 {
+                out.push_str(&format!("{}{}", sign, seconds / USEC_PER_SEC as u64));
+                push_fraction(&mut out, seconds % USEC_PER_SEC as u64);
+                out.push('S');
+            }
+        } else if out.len() == 1 {
+            out.push_str("T0S");
+        }
+
+        out
+    }
+}
+
+fn push_fraction(out: &mut String, usecs: u64) {
+    if usecs != 0 {
+        let fraction = format!("{:06}", usecs);
+        out.push('.');
+        out.push_str(fraction.trim_end_matches('0'));
+    }
+}
+
+impl Add for Interval {
+    type Output = Interval;
+
+    fn add(self, other: Interval) -> Interval {
+        self.checked_add(other)
+            .expect("overflow when adding intervals")
+    }
+}
+
+impl Sub for Interval {
+    type Output = Interval;
+
+    fn sub(self, other: Interval) -> Interval {
+        self.checked_sub(other)
+            .expect("overflow when subtracting intervals")
+    }
+}
+
+impl Neg for Interval {
+    type Output = Interval;
+
+    fn neg(self) -> Interval {
+        self.checked_neg().expect("overflow when negating interval")
+    }
+}
+
+// Formats the interval like the server's default `postgres` interval style.
+impl fmt::Display for Interval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut is_zero = true;
+        let mut is_before = false;
+
+        let parts = [
+            (i64::from(self.months / 12), "year"),
+            (i64::from(self.months % 12), "mon"),
+            (i64::from(self.days), "day"),
+        ];
+        for (value, unit) in parts.iter() {
+            if *value == 0 {
+                continue;
+            }
+            write!(
+                f,
+                "{}{}{} {}{}",
+                if is_zero { "" } else { " " },
+                if is_before && *value > 0 { "+" } else { "" },
+                value,
+                unit,
+                if *value == 1 { "" } else { "s" }
+            )?;
+            is_before = *value < 0;
+            is_zero = false;
+        }
+
+        let usecs = self.microseconds;
+        if is_zero || usecs != 0 {
+            let sign = if usecs < 0 {
+                "-"
+            } else if is_before {
+                "+"
+            } else {
+                ""
+            };
+            let usecs = usecs.unsigned_abs();
+            let secs = usecs / USEC_PER_SEC as u64;
+            let mut out = format!(
+                "{}{}{:02}:{:02}:{:02}",
+                if is_zero { "" } else { " " },
+                sign,
+                secs / 3600,
+                secs / 60 % 60,
+                secs % 60
+            );
+            push_fraction(&mut out, usecs % USEC_PER_SEC as u64);
+            f.write_str(&out)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for Interval {
+    type Err = ParseIntervalError;
+
+    fn from_str(s: &str) -> Result<Interval, ParseIntervalError> {
+        let s = s.trim();
+        let interval = match s.strip_prefix('P') {
+            Some(s) => parse_iso8601(s),
+            None => parse_postgres(s),
+        };
+        interval.ok_or(ParseIntervalError(()))
+    }
+}
+
+#[derive(Default)]
+struct Fields {
+    months: i64,
+    days: i64,
+    microseconds: i128,
+}
+
+impl Fields {
+    // Adds `value` of the named unit, returning `None` for unknown units and fractional months or days.
+    fn add(&mut self, value: &str, unit: &str) -> Option<()> {
+        let (months, days, usecs) = match unit.to_ascii_lowercase().as_str() {
+            "y" | "yr" | "yrs" | "year" | "years" => (12, 0, 0),
+            "mon" | "mons" | "month" | "months" => (1, 0, 0),
+            "w" | "week" | "weeks" => (0, 7, 0),
+            "d" | "day" | "days" => (0, 1, 0),
+            "h" | "hr" | "hrs" | "hour" | "hours" => (0, 0, USEC_PER_HOUR),
+            "m" | "min" | "mins" | "minute" | "minutes" => (0, 0, USEC_PER_MIN),
+            "s" | "sec" | "secs" | "second" | "seconds" => (0, 0, USEC_PER_SEC),
+            "ms" | "msec" | "msecs" | "millisecond" | "milliseconds" => (0, 0, 1000),
+            "us" | "usec" | "usecs" | "microsecond" | "microseconds" => (0, 0, 1),
+            _ => return None,
+        };
+
+        if usecs == 0 {
+            let value = value
+                .strip_prefix('+')
+                .unwrap_or(value)
+                .parse::<i64>()
+                .ok()?;
+            self.months = self.months.checked_add(value.checked_mul(months)?)?;
+            self.days = self.days.checked_add(value.checked_mul(days)?)?;
+        } else {
+            self.microseconds = self.microseconds.checked_add(parse_scaled(value, usecs)?)?;
+        }
+        Some(())
+    }
+
+    fn negate(&mut self) {
+        self.months = -self.months;
+        self.days = -self.days;
+        self.microseconds = -self.microseconds;
+    }
+
+    fn finish(self) -> Option<Interval> {
+        Some(Interval {
+            months: i32::try_from(self.months).ok()?,
+            days: i32::try_from(self.days).ok()?,
+            microseconds: i64::try_from(self.microseconds).ok()?,
+        })
+    }
+}
+
+// Parses a decimal number with an optional sign and fraction, multiplied by `scale` and rounded to an integer.
+fn parse_scaled(value: &str, scale: i64) -> Option<i128> {
+    let (negative, value) = match value.as_bytes().first()? {
+        b'-' => (true, &value[1..]),
+        b'+' => (false, &value[1..]),
+        _ => (false, value),
+    };
+    let (integer, fraction) = value.split_once('.').unwrap_or((value, ""));
+    if integer.is_empty() && fraction.is_empty()
+        || !integer
+            .bytes()
+            .chain(fraction.bytes())
+            .all(|b| b.is_ascii_digit())
+    {
+        return None;
+    }
+
+    let scale = i128::from(scale);
+    let mut result = if integer.is_empty() {
+        0
+    } else {
+        integer.parse::<i128>().ok()?.checked_mul(scale)?
+    };
+    // digits beyond the eighteenth can't affect a microsecond count
+    let fraction = &fraction[..fraction.len().min(18)];
+    if !fraction.is_empty() {
+        let denominator = 10i128.pow(fraction.len() as u32);
+        let numerator = fraction.parse::<i128>().ok()? * scale;
+        result = result.checked_add((numerator + denominator / 2) / denominator)?;
+    }
+
+    Some(if negative { -result } else { result })
+}
+
+// Parses the `postgres` and `postgres_verbose` interval styles, e.g. `1 year -2 mons +3 days 04:05:06.5` or
+// `@ 1 year 2 mons 3 days 4 hours 5 mins 6.5 secs ago`.
+fn parse_postgres(s: &str) -> Option<Interval> {
+    let s = s.strip_prefix('@').unwrap_or(s);
+    let mut tokens = s.split_whitespace().peekable();
+    let mut fields = Fields::default();
+    let mut empty = true;
+
+    while let Some(token) = tokens.next() {
+        empty = false;
+        if token.eq_ignore_ascii_case("ago") {
+            if tokens.peek().is_some() {
+                return None;
+            }
+            fields.negate();
+        } else if token.contains(':') {
+            fields.microseconds = fields.microseconds.checked_add(parse_time(token)?)?;
+        } else {
+            // a number without a unit is a number of seconds, e.g. `@ 0`
+            let unit = match tokens.peek() {
+                Some(unit) if !unit.eq_ignore_ascii_case("ago") => tokens.next()?,
+                _ => "secs",
+            };
+            fields.add(token, unit)?;
+        }
+    }
+
+    if empty {
+        return None;
+    }
+    fields.finish()
+}
+
+// Parses a `[+-]hh:mm[:ss[.ffffff]]` time field into microseconds.
+fn parse_time(s: &str) -> Option<i128> {
+    let (negative, s) = match s.as_bytes().first()? {
+        b'-' => (true, &s[1..]),
+        b'+' => (false, &s[1..]),
+        _ => (false, s),
+    };
+    let mut parts = s.split(':');
+    let hours = parts.next()?;
+    let minutes = parts.next()?;
+    let seconds = parts.next().unwrap_or("0");
+    if parts.next().is_some()
+        || !hours
+            .bytes()
+            .chain(minutes.bytes())
+            .all(|b| b.is_ascii_digit())
+        || seconds.starts_with(['+', '-'])
+    {
+        return None;
+    }
+
+    let usecs = parse_scaled(hours, USEC_PER_HOUR)?
+        .checked_add(parse_scaled(minutes, USEC_PER_MIN)?)?
+        .checked_add(parse_scaled(seconds, USEC_PER_SEC)?)?;
+    Some(if negative { -usecs } else { usecs })
+}
+
+// Parses the part of an ISO 8601 duration after the leading `P`, e.g. `1Y-2M3DT4H5M6.5S`.
+// `Option::is_none_or` requires Rust 1.82
+#[allow(clippy::unnecessary_map_or)]
+fn parse_iso8601(s: &str) -> Option<Interval> {
+    let (date, time) = match s.split_once('T') {
+        Some((date, time)) => (date, Some(time)),
+        None => (s, None),
+    };
+    if date.is_empty() && time.map_or(true, str::is_empty) {
+        return None;
+    }
+
+    let mut fields = Fields::default();
+    parse_iso8601_part(date, &mut fields, |c| match c {
+        'Y' => Some("years"),
+        'M' => Some("months"),
+        'W' => Some("weeks"),
+        'D' => Some("days"),
+        _ => None,
+    })?;
+    if let Some(time) = time {
+        if time.is_empty() {
+            return None;
+        }
+        parse_iso8601_part(time, &mut fields, |c| match c {
+            'H' => Some("hours"),
+            'M' => Some("minutes"),
+            'S' => Some("seconds"),
+            _ => None,
+        })?;
+    }
+    fields.finish()
+}
+
+fn parse_iso8601_part<F>(mut s: &str, fields: &mut Fields, unit: F) -> Option<()>
+where
+    F: Fn(char) -> Option<&'static str>,
+{
+    while !s.is_empty() {
+        let idx = s.find(|c: char| c.is_ascii_alphabetic())?;
+        let designator = s[idx..].chars().next()?;
+        fields.add(&s[..idx], unit(designator)?)?;
+        s = &s[idx + 1..];
+    }
+    Some(())
+}
+
+impl<'a> FromSql<'a> for Interval {
+    fn from_sql(_: &Type, raw: &'a [u8]) -> Result<Interval, Box<dyn Error + Sync + Send>> {
+        if raw.len() != 16 {
+            return Err("invalid message length: interval size mismatch".into());
+        }
+        let mut microseconds = [0; 8];
+        microseconds.copy_from_slice(&raw[..8]);
+        let mut days = [0; 4];
+        days.copy_from_slice(&raw[8..12]);
+        let mut months = [0; 4];
+        months.copy_from_slice(&raw[12..]);
+
+        Ok(Interval {
+            months: i32::from_be_bytes(months),
+            days: i32::from_be_bytes(days),
+            microseconds: i64::from_be_bytes(microseconds),
+        })
+    }
+
+    accepts!(INTERVAL);
+}
+
+impl<'a> FromSqlText<'a> for Interval {
+    fn from_sql_text(raw: &'a str) -> Result<Interval, Box<dyn Error + Sync + Send>> {
+        raw.parse()
+            .map_err(|_| format!("invalid interval `{}`", raw).into())
+    }
+}
+
+impl ToSql for Interval {
+    fn to_sql(&self, _: &Type, out: &mut BytesMut) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+        out.put_i64(self.microseconds);
+        out.put_i32(self.days);
+        out.put_i32(self.months);
+        Ok(IsNull::No)
+    }
+
+    accepts!(INTERVAL);
+
+    to_sql_checked!();
+}
+
+impl TryFrom<Interval> for Duration {
+    type Error = IntervalConversionError;
+
+    fn try_from(interval: Interval) -> Result<Duration, IntervalConversionError> {
+        let usecs = interval
+            .total_microseconds()
+            .and_then(|usecs| u64::try_from(usecs).ok())
+            .ok_or(IntervalConversionError(()))?;
+        Ok(Duration::from_micros(usecs))
+    }
+}
+
+// Durations are converted into a number of microseconds, truncating any sub-microsecond precision.
+impl TryFrom<Duration> for Interval {
+    type Error = IntervalConversionError;
+
+    fn try_from(duration: Duration) -> Result<Interval, IntervalConversionError> {
+        let usecs = i64::try_from(duration.as_micros()).map_err(|_| IntervalConversionError(()))?;
+        Ok(Interval::new(0, 0, usecs))
+    }
+}
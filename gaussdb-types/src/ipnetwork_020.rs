@@ -0,0 +1,32 @@
+use bytes::BytesMut;
+use gaussdb_protocol::types;
+use ipnetwork_020::IpNetwork;
+use std::error::Error;
+
+use crate::{FromSql, FromSqlText, IsNull, ToSql, Type};
+
+impl<'a> FromSql<'a> for IpNetwork {
+    fn from_sql(_: &Type, raw: &[u8]) -> Result<IpNetwork, Box<dyn Error + Sync + Send>> {
+        let inet = types::inet_from_sql(raw)?;
+        Ok(IpNetwork::new(inet.addr(), inet.netmask())?)
+    }
+
+    accepts!(INET, CIDR);
+}
+
+impl<'a> FromSqlText<'a> for IpNetwork {
+    fn from_sql_text(raw: &'a str) -> Result<IpNetwork, Box<dyn Error + Sync + Send>> {
+        // a bare address, as printed for an `INET` covering the whole address, parses with the full prefix length
+        raw.parse().map_err(Into::into)
+    }
+}
+
+impl ToSql for IpNetwork {
+    fn to_sql(&self, _: &Type, w: &mut BytesMut) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+        types::inet_to_sql(self.ip(), self.prefix(), w);
+        Ok(IsNull::No)
+    }
+
+    accepts!(INET, CIDR);
+    to_sql_checked!();
+}
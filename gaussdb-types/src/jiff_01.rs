@@ -4,9 +4,11 @@ use jiff_01::{
     civil::{Date, DateTime, Time},
     Span, SpanRound, Timestamp, Unit,
 };
+use std::convert::TryFrom;
 use std::error::Error;
 
-use crate::{FromSql, IsNull, ToSql, Type};
+use crate::text;
+use crate::{FromSql, FromSqlText, Interval, IntervalConversionError, IsNull, ToSql, Type};
 
 const fn base() -> DateTime {
     DateTime::constant(2000, 1, 1, 0, 0, 0, 0)
@@ -46,7 +48,17 @@ impl<'a> FromSql<'a> for DateTime {
             .map_err(decode_err)
     }
 
-    accepts!(TIMESTAMP);
+    accepts!(TIMESTAMP, SMALLDATETIME);
+}
+
+impl<'a> FromSqlText<'a> for DateTime {
+    fn from_sql_text(raw: &'a str) -> Result<DateTime, Box<dyn Error + Sync + Send>> {
+        let v = text::timestamp_from_text(raw)?;
+        Span::new()
+            .try_microseconds(v)
+            .and_then(|s| base().checked_add(s))
+            .map_err(decode_err)
+    }
 }
 
 impl ToSql for DateTime {
@@ -60,7 +72,7 @@ impl ToSql for DateTime {
         Ok(IsNull::No)
     }
 
-    accepts!(TIMESTAMP);
+    accepts!(TIMESTAMP, SMALLDATETIME);
     to_sql_checked!();
 }
 
@@ -76,6 +88,16 @@ impl<'a> FromSql<'a> for Timestamp {
     accepts!(TIMESTAMPTZ);
 }
 
+impl<'a> FromSqlText<'a> for Timestamp {
+    fn from_sql_text(raw: &'a str) -> Result<Timestamp, Box<dyn Error + Sync + Send>> {
+        let v = text::timestamp_from_text(raw)?;
+        Span::new()
+            .try_microseconds(v)
+            .and_then(|s| base_ts().checked_add(s))
+            .map_err(decode_err)
+    }
+}
+
 impl ToSql for Timestamp {
     fn to_sql(&self, _: &Type, w: &mut BytesMut) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
         let v = self
@@ -102,6 +124,16 @@ impl<'a> FromSql<'a> for Date {
     accepts!(DATE);
 }
 
+impl<'a> FromSqlText<'a> for Date {
+    fn from_sql_text(raw: &'a str) -> Result<Date, Box<dyn Error + Sync + Send>> {
+        let v = text::date_from_text(raw)?;
+        Span::new()
+            .try_days(v)
+            .and_then(|s| base().date().checked_add(s))
+            .map_err(decode_err)
+    }
+}
+
 impl ToSql for Date {
     fn to_sql(&self, _: &Type, w: &mut BytesMut) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
         let v = self.since(base().date()).map_err(transmit_err)?.get_days();
@@ -125,6 +157,16 @@ impl<'a> FromSql<'a> for Time {
     accepts!(TIME);
 }
 
+impl<'a> FromSqlText<'a> for Time {
+    fn from_sql_text(raw: &'a str) -> Result<Time, Box<dyn Error + Sync + Send>> {
+        let v = text::time_from_text(raw)?;
+        Span::new()
+            .try_microseconds(v)
+            .and_then(|s| Time::midnight().checked_add(s))
+            .map_err(decode_err)
+    }
+}
+
 impl ToSql for Time {
     fn to_sql(&self, _: &Type, w: &mut BytesMut) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
         let v = self
@@ -139,3 +181,50 @@ impl ToSql for Time {
     accepts!(TIME);
     to_sql_checked!();
 }
+
+impl TryFrom<Interval> for Span {
+    type Error = IntervalConversionError;
+
+    fn try_from(interval: Interval) -> Result<Span, IntervalConversionError> {
+        // all of the units of a span share a single sign
+        let fields = [
+            i64::from(interval.months),
+            i64::from(interval.days),
+            interval.microseconds,
+        ];
+        if fields.iter().any(|v| *v > 0) && fields.iter().any(|v| *v < 0) {
+            return Err(IntervalConversionError(()));
+        }
+
+        Span::new()
+            .try_months(interval.months)
+            .and_then(|s| s.try_days(interval.days))
+            .and_then(|s| s.try_microseconds(interval.microseconds))
+            .map_err(|_| IntervalConversionError(()))
+    }
+}
+
+// Sub-microsecond units are truncated.
+impl TryFrom<Span> for Interval {
+    type Error = IntervalConversionError;
+
+    fn try_from(span: Span) -> Result<Interval, IntervalConversionError> {
+        let months = i64::from(span.get_years()) * 12 + i64::from(span.get_months());
+        let days = i64::from(span.get_weeks()) * 7 + i64::from(span.get_days());
+        let usecs = i128::from(span.get_hours()) * 3_600_000_000
+            + i128::from(span.get_minutes()) * 60_000_000
+            + i128::from(span.get_seconds()) * 1_000_000
+            + i128::from(span.get_milliseconds()) * 1_000
+            + i128::from(span.get_microseconds())
+            + i128::from(span.get_nanoseconds()) / 1_000;
+
+        match (
+            i32::try_from(months),
+            i32::try_from(days),
+            i64::try_from(usecs),
+        ) {
+            (Ok(months), Ok(days), Ok(usecs)) => Ok(Interval::new(months, days, usecs)),
+            _ => Err(IntervalConversionError(())),
+        }
+    }
+}
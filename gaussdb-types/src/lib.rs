@@ -195,7 +195,7 @@ use std::sync::Arc;
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 #[cfg(feature = "derive")]
-pub use gaussdb_derive::{FromSql, ToSql};
+pub use gaussdb_derive::{FromSql, ToParams, ToSql};
 
 #[cfg(feature = "with-serde_json-1")]
 pub use crate::serde_json_1::Json;
@@ -207,7 +207,22 @@ pub use gaussdb_protocol::Oid;
 #[doc(inline)]
 pub use pg_lsn::PgLsn;
 
+pub use crate::array::{Array, ArrayShapeError, Dimension};
+pub use crate::geometric::{Circle, Line, Lseg, Point, Polygon};
+pub use crate::interval::{Interval, IntervalConversionError, ParseIntervalError};
+pub use crate::money::Money;
+pub use crate::numeric::{Numeric, ParseNumericError};
+pub use crate::range::Range;
+pub use crate::record::Record;
+
 pub use crate::special::{Date, Timestamp};
+pub use crate::text::{FromSqlText, FromSqlTextOwned};
+pub use crate::text_search::{
+    ParseTsQueryError, ParseTsVectorError, TsLexeme, TsPosition, TsQuery, TsVector, TsWeight,
+};
+pub use crate::tinyint::TinyInt;
+pub use crate::to_params::{NamedParams, ToParams};
+pub use crate::value::Value;
 use bytes::BytesMut;
 
 // Number of seconds from 1970-01-01 to 2000-01-01
@@ -262,10 +277,14 @@ where
     v.to_sql(ty, out)
 }
 
+#[cfg(feature = "with-bigdecimal-0_4")]
+mod bigdecimal_04;
 #[cfg(feature = "with-bit-vec-0_6")]
 mod bit_vec_06;
 #[cfg(feature = "with-chrono-0_4")]
 mod chrono_04;
+#[cfg(feature = "with-chrono-tz-0_10")]
+mod chrono_tz_010;
 #[cfg(feature = "with-cidr-0_2")]
 mod cidr_02;
 #[cfg(feature = "with-cidr-0_3")]
@@ -278,10 +297,18 @@ mod eui48_1;
 mod geo_types_06;
 #[cfg(feature = "with-geo-types-0_7")]
 mod geo_types_07;
+#[cfg(feature = "with-ipnetwork-0_20")]
+mod ipnetwork_020;
 #[cfg(feature = "with-jiff-0_1")]
 mod jiff_01;
 #[cfg(feature = "with-jiff-0_2")]
 mod jiff_02;
+#[cfg(feature = "with-ndarray-0_16")]
+mod ndarray_016;
+#[cfg(feature = "with-num-bigint-0_4")]
+mod num_bigint_04;
+#[cfg(feature = "with-rust_decimal-1")]
+mod rust_decimal_1;
 #[cfg(feature = "with-serde_json-1")]
 mod serde_json_1;
 #[cfg(feature = "with-smol_str-01")]
@@ -299,11 +326,24 @@ mod uuid_1;
 #[cfg(feature = "with-time-0_2")]
 extern crate time_02 as time;
 
+mod array;
+mod bytes_1;
+mod geometric;
+mod interval;
+mod money;
+mod numeric;
 mod pg_lsn;
 #[doc(hidden)]
 pub mod private;
+mod range;
+mod record;
 mod special;
+mod text;
+mod text_search;
+mod tinyint;
+mod to_params;
 mod type_gen;
+mod value;
 
 /// A Postgres type.
 #[derive(PartialEq, Eq, Clone, Hash)]
@@ -466,18 +506,37 @@ impl WrongType {
 /// |-----------------------------------|-----------------------------------------------|
 /// | `bool`                            | BOOL                                          |
 /// | `i8`                              | "char"                                        |
-/// | `i16`                             | SMALLINT, SMALLSERIAL                         |
+/// | `i16`                             | SMALLINT, SMALLSERIAL, YEAR                   |
 /// | `i32`                             | INT, SERIAL                                   |
 /// | `u32`                             | OID                                           |
 /// | `i64`                             | BIGINT, BIGSERIAL                             |
+/// | `i128`                            | INT16                                         |
+/// | `TinyInt`                         | TINYINT                                       |
 /// | `f32`                             | REAL                                          |
 /// | `f64`                             | DOUBLE PRECISION                              |
 /// | `&str`/`String`                   | VARCHAR, CHAR(n), TEXT, CITEXT, NAME, UNKNOWN |
-/// |                                   | LTREE, LQUERY, LTXTQUERY                      |
-/// | `&[u8]`/`Vec<u8>`                 | BYTEA                                         |
+/// |                                   | LTREE, LQUERY, LTXTQUERY, CLOB, NVARCHAR2     |
+/// | `&[u8]`/`Vec<u8>`                 | BYTEA, RAW, BLOB                              |
+/// | `bytes::Bytes`/`BytesMut`         | BYTEA, RAW, BLOB                              |
+/// | `[u8; 8]`                         | MACADDR8                                      |
 /// | `HashMap<String, Option<String>>` | HSTORE                                        |
-/// | `SystemTime`                      | TIMESTAMP, TIMESTAMP WITH TIME ZONE           |
+/// | `SystemTime`                      | TIMESTAMP, TIMESTAMP WITH TIME ZONE,          |
+/// |                                   | SMALLDATETIME                                 |
 /// | `IpAddr`                          | INET                                          |
+/// | `Numeric`                         | NUMERIC                                       |
+/// | `Money`                           | MONEY                                         |
+/// | `Interval`                        | INTERVAL                                      |
+/// | `Point`                           | POINT                                         |
+/// | `Lseg`                            | LSEG                                          |
+/// | `Line`                            | LINE                                          |
+/// | `Polygon`                         | POLYGON                                       |
+/// | `Circle`                          | CIRCLE                                        |
+/// | `TsVector`                        | TSVECTOR                                      |
+/// | `TsQuery`                         | TSQUERY                                       |
+/// | `Record`                          | RECORD, composite types                       |
+/// | `(A, B, ...)`                     | RECORD, composite types                       |
+/// | `Range<T>`                        | INT4RANGE, INT8RANGE, NUMRANGE, TSRANGE,      |
+/// |                                   | TSTZRANGE, DATERANGE and other range types    |
 ///
 /// In addition, some implementations are provided for types in third party
 /// crates. These are disabled by default; to opt into one of these
@@ -487,35 +546,63 @@ impl WrongType {
 ///
 /// | Rust type                       | Postgres type(s)                    |
 /// |---------------------------------|-------------------------------------|
-/// | `chrono::NaiveDateTime`         | TIMESTAMP                           |
+/// | `chrono::NaiveDateTime`         | TIMESTAMP, SMALLDATETIME            |
 /// | `chrono::DateTime<Utc>`         | TIMESTAMP WITH TIME ZONE            |
 /// | `chrono::DateTime<Local>`       | TIMESTAMP WITH TIME ZONE            |
 /// | `chrono::DateTime<FixedOffset>` | TIMESTAMP WITH TIME ZONE            |
 /// | `chrono::NaiveDate`             | DATE                                |
 /// | `chrono::NaiveTime`             | TIME                                |
+/// | `(NaiveTime, FixedOffset)`      | TIME WITH TIME ZONE                 |
 /// | `cidr::IpCidr`                  | CIDR                                |
 /// | `cidr::IpInet`                  | INET                                |
-/// | `time::PrimitiveDateTime`       | TIMESTAMP                           |
+/// | `time::PrimitiveDateTime`       | TIMESTAMP, SMALLDATETIME            |
 /// | `time::OffsetDateTime`          | TIMESTAMP WITH TIME ZONE            |
 /// | `time::Date`                    | DATE                                |
 /// | `time::Time`                    | TIME                                |
+/// | `(time::Time, UtcOffset)`       | TIME WITH TIME ZONE                 |
 /// | `jiff::civil::Date`             | DATE                                |
-/// | `jiff::civil::DateTime`         | TIMESTAMP                           |
+/// | `jiff::civil::DateTime`         | TIMESTAMP, SMALLDATETIME            |
 /// | `jiff::civil::Time`             | TIME                                |
 /// | `jiff::Timestamp`               | TIMESTAMP WITH TIME ZONE            |
-/// | `eui48::MacAddress`             | MACADDR                             |
+/// | `eui48::MacAddress`             | MACADDR, MACADDR8                   |
 /// | `geo_types::Point<f64>`         | POINT                               |
 /// | `geo_types::Rect<f64>`          | BOX                                 |
 /// | `geo_types::LineString<f64>`    | PATH                                |
+/// | `geo_types::Line<f64>`          | LSEG                                |
+/// | `geo_types::Polygon<f64>`       | POLYGON                             |
 /// | `serde_json::Value`             | JSON, JSONB                         |
 /// | `uuid::Uuid`                    | UUID                                |
 /// | `bit_vec::BitVec`               | BIT, VARBIT                         |
-/// | `eui48::MacAddress`             | MACADDR                             |
+/// | `eui48::MacAddress`             | MACADDR, MACADDR8                   |
 /// | `cidr::InetCidr`                | CIDR                                |
 /// | `cidr::InetAddr`                | INET                                |
 /// | `smol_str::SmolStr`             | VARCHAR, CHAR(n), TEXT, CITEXT,     |
 /// |                                 | NAME, UNKNOWN, LTREE, LQUERY,       |
 /// |                                 | LTXTQUERY                           |
+/// | `rust_decimal::Decimal`         | NUMERIC                             |
+/// | `bigdecimal::BigDecimal`        | NUMERIC                             |
+/// | `num_bigint::BigInt`            | NUMERIC                             |
+/// | `ipnetwork::IpNetwork`          | INET, CIDR                          |
+/// | `chrono_tz::Tz`                 | VARCHAR, CHAR(n), TEXT, NAME        |
+/// | `ndarray::ArrayD<T>`            | array types                         |
+///
+/// # openGauss types
+///
+/// TINYINT, INT16, RAW, BLOB, CLOB, NVARCHAR2 and SMALLDATETIME are built into
+/// openGauss and have fixed OIDs, so they have `Type` constants like the
+/// Postgres types. YEAR is provided by the dolphin extension and is matched by
+/// name instead.
+///
+/// RAW and BLOB values are output by the server as plain hexadecimal in the
+/// text format rather than in the `BYTEA` format, so they should be read with
+/// `FromSql`, not `FromSqlText`.
+///
+/// # Records
+///
+/// Anonymous `RECORD` values, produced by `ROW(...)` expressions and functions
+/// returning `RECORD`, can be decoded as a [`Record`], whose fields are read by
+/// index, or as a tuple of up to 12 elements. Tuples also accept composite
+/// types with matching fields, and check the number of fields of each value.
 ///
 /// # Nullability
 ///
@@ -527,6 +614,8 @@ impl WrongType {
 ///
 /// `FromSql` is implemented for `Vec<T>`, `Box<[T]>` and `[T; N]` where `T`
 /// implements `FromSql`, and corresponds to one-dimensional Postgres arrays.
+/// Arrays with more dimensions, or whose lower bound should be kept, can be
+/// decoded as an [`Array<T>`].
 ///
 /// **Note:** the impl for arrays only exist when the Cargo feature `array-impls`
 /// is enabled.
@@ -669,7 +758,7 @@ impl<'a> FromSql<'a> for Vec<u8> {
         Ok(types::bytea_from_sql(raw).to_owned())
     }
 
-    accepts!(BYTEA);
+    accepts!(BYTEA, RAW, BLOB);
 }
 
 impl<'a> FromSql<'a> for &'a [u8] {
@@ -677,7 +766,15 @@ impl<'a> FromSql<'a> for &'a [u8] {
         Ok(types::bytea_from_sql(raw))
     }
 
-    accepts!(BYTEA);
+    accepts!(BYTEA, RAW, BLOB);
+}
+
+impl<'a> FromSql<'a> for [u8; 8] {
+    fn from_sql(_: &Type, raw: &'a [u8]) -> Result<[u8; 8], Box<dyn Error + Sync + Send>> {
+        types::macaddr8_from_sql(raw)
+    }
+
+    accepts!(MACADDR8);
 }
 
 impl<'a> FromSql<'a> for String {
@@ -714,7 +811,13 @@ impl<'a> FromSql<'a> for &'a str {
 
     fn accepts(ty: &Type) -> bool {
         match *ty {
-            Type::VARCHAR | Type::TEXT | Type::BPCHAR | Type::NAME | Type::UNKNOWN => true,
+            Type::VARCHAR
+            | Type::TEXT
+            | Type::BPCHAR
+            | Type::NAME
+            | Type::UNKNOWN
+            | Type::CLOB
+            | Type::NVARCHAR2 => true,
             ref ty
                 if (ty.name() == "citext"
                     || ty.name() == "ltree"
@@ -742,13 +845,28 @@ macro_rules! simple_from {
 
 simple_from!(bool, bool_from_sql, BOOL);
 simple_from!(i8, char_from_sql, CHAR);
-simple_from!(i16, int2_from_sql, INT2);
 simple_from!(i32, int4_from_sql, INT4);
 simple_from!(u32, oid_from_sql, OID);
 simple_from!(i64, int8_from_sql, INT8);
+simple_from!(i128, int16_from_sql, INT16);
 simple_from!(f32, float4_from_sql, FLOAT4);
 simple_from!(f64, float8_from_sql, FLOAT8);
 
+// openGauss's `YEAR` type (from the dolphin extension) has no fixed OID, but uses the `INT2` binary format
+fn is_year(ty: &Type) -> bool {
+    ty.name() == "year" && ty.schema() == "pg_catalog" && *ty.kind() == Kind::Simple
+}
+
+impl<'a> FromSql<'a> for i16 {
+    fn from_sql(_: &Type, raw: &'a [u8]) -> Result<i16, Box<dyn Error + Sync + Send>> {
+        types::int2_from_sql(raw)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        *ty == Type::INT2 || is_year(ty)
+    }
+}
+
 impl<'a, S> FromSql<'a> for HashMap<String, Option<String>, S>
 where
     S: Default + BuildHasher,
@@ -788,7 +906,7 @@ impl<'a> FromSql<'a> for SystemTime {
         Ok(time)
     }
 
-    accepts!(TIMESTAMP, TIMESTAMPTZ);
+    accepts!(TIMESTAMP, TIMESTAMPTZ, SMALLDATETIME);
 }
 
 impl<'a> FromSql<'a> for IpAddr {
@@ -819,18 +937,37 @@ pub enum IsNull {
 /// |-----------------------------------|--------------------------------------|
 /// | `bool`                            | BOOL                                 |
 /// | `i8`                              | "char"                               |
-/// | `i16`                             | SMALLINT, SMALLSERIAL                |
+/// | `i16`                             | SMALLINT, SMALLSERIAL, YEAR          |
 /// | `i32`                             | INT, SERIAL                          |
 /// | `u32`                             | OID                                  |
 /// | `i64`                             | BIGINT, BIGSERIAL                    |
+/// | `i128`                            | INT16                                |
+/// | `TinyInt`                         | TINYINT                              |
 /// | `f32`                             | REAL                                 |
 /// | `f64`                             | DOUBLE PRECISION                     |
 /// | `&str`/`String`                   | VARCHAR, CHAR(n), TEXT, CITEXT, NAME |
-/// |                                   | LTREE, LQUERY, LTXTQUERY             |
-/// | `&[u8]`/`Vec<u8>`/`[u8; N]`       | BYTEA                                |
+/// |                                   | LTREE, LQUERY, LTXTQUERY, CLOB,      |
+/// |                                   | NVARCHAR2                            |
+/// | `&[u8]`/`Vec<u8>`/`[u8; N]`       | BYTEA, RAW, BLOB                     |
+/// | `bytes::Bytes`/`BytesMut`         | BYTEA, RAW, BLOB                     |
+/// | `[u8; 8]`                         | MACADDR8                             |
 /// | `HashMap<String, Option<String>>` | HSTORE                               |
-/// | `SystemTime`                      | TIMESTAMP, TIMESTAMP WITH TIME ZONE  |
+/// | `SystemTime`                      | TIMESTAMP, TIMESTAMP WITH TIME ZONE, |
+/// |                                   | SMALLDATETIME                        |
 /// | `IpAddr`                          | INET                                 |
+/// | `Numeric`                         | NUMERIC                              |
+/// | `Money`                           | MONEY                                |
+/// | `Interval`                        | INTERVAL                             |
+/// | `Point`                           | POINT                                |
+/// | `Lseg`                            | LSEG                                 |
+/// | `Line`                            | LINE                                 |
+/// | `Polygon`                         | POLYGON                              |
+/// | `Circle`                          | CIRCLE                               |
+/// | `TsVector`                        | TSVECTOR                             |
+/// | `TsQuery`                         | TSQUERY                              |
+/// | `Range<T>`                        | INT4RANGE, INT8RANGE, NUMRANGE,      |
+/// |                                   | TSRANGE, TSTZRANGE, DATERANGE and    |
+/// |                                   | other range types                    |
 ///
 /// In addition, some implementations are provided for types in third party
 /// crates. These are disabled by default; to opt into one of these
@@ -840,26 +977,36 @@ pub enum IsNull {
 ///
 /// | Rust type                       | Postgres type(s)                    |
 /// |---------------------------------|-------------------------------------|
-/// | `chrono::NaiveDateTime`         | TIMESTAMP                           |
+/// | `chrono::NaiveDateTime`         | TIMESTAMP, SMALLDATETIME            |
 /// | `chrono::DateTime<Utc>`         | TIMESTAMP WITH TIME ZONE            |
 /// | `chrono::DateTime<Local>`       | TIMESTAMP WITH TIME ZONE            |
 /// | `chrono::DateTime<FixedOffset>` | TIMESTAMP WITH TIME ZONE            |
 /// | `chrono::NaiveDate`             | DATE                                |
 /// | `chrono::NaiveTime`             | TIME                                |
+/// | `(NaiveTime, FixedOffset)`      | TIME WITH TIME ZONE                 |
 /// | `cidr::IpCidr`                  | CIDR                                |
 /// | `cidr::IpInet`                  | INET                                |
-/// | `time::PrimitiveDateTime`       | TIMESTAMP                           |
+/// | `time::PrimitiveDateTime`       | TIMESTAMP, SMALLDATETIME            |
 /// | `time::OffsetDateTime`          | TIMESTAMP WITH TIME ZONE            |
 /// | `time::Date`                    | DATE                                |
 /// | `time::Time`                    | TIME                                |
-/// | `eui48::MacAddress`             | MACADDR                             |
+/// | `(time::Time, UtcOffset)`       | TIME WITH TIME ZONE                 |
+/// | `eui48::MacAddress`             | MACADDR, MACADDR8                   |
 /// | `geo_types::Point<f64>`         | POINT                               |
 /// | `geo_types::Rect<f64>`          | BOX                                 |
 /// | `geo_types::LineString<f64>`    | PATH                                |
+/// | `geo_types::Line<f64>`          | LSEG                                |
+/// | `geo_types::Polygon<f64>`       | POLYGON                             |
 /// | `serde_json::Value`             | JSON, JSONB                         |
 /// | `uuid::Uuid`                    | UUID                                |
 /// | `bit_vec::BitVec`               | BIT, VARBIT                         |
-/// | `eui48::MacAddress`             | MACADDR                             |
+/// | `eui48::MacAddress`             | MACADDR, MACADDR8                   |
+/// | `rust_decimal::Decimal`         | NUMERIC                             |
+/// | `bigdecimal::BigDecimal`        | NUMERIC                             |
+/// | `num_bigint::BigInt`            | NUMERIC                             |
+/// | `ipnetwork::IpNetwork`          | INET, CIDR                          |
+/// | `chrono_tz::Tz`                 | VARCHAR, CHAR(n), TEXT, NAME        |
+/// | `ndarray::ArrayD<T>`            | array types                         |
 ///
 /// # Nullability
 ///
@@ -871,7 +1018,8 @@ pub enum IsNull {
 ///
 /// `ToSql` is implemented for `[u8; N]`, `Vec<T>`, `&[T]`, `Box<[T]>` and `[T; N]`
 /// where `T` implements `ToSql` and `N` is const usize, and corresponds to one-dimensional
-/// Postgres arrays with an index offset of 1.
+/// Postgres arrays with an index offset of 1. [`Array<T>`] corresponds to arrays
+/// of any number of dimensions and lower bounds.
 ///
 /// **Note:** the impl for arrays only exist when the Cargo feature `array-impls`
 /// is enabled.
@@ -1018,19 +1166,38 @@ impl ToSql for &[u8] {
         Ok(IsNull::No)
     }
 
-    accepts!(BYTEA);
+    accepts!(BYTEA, RAW, BLOB);
+
+    to_sql_checked!();
+}
+
+#[cfg(not(feature = "array-impls"))]
+impl ToSql for [u8; 8] {
+    fn to_sql(&self, _: &Type, w: &mut BytesMut) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+        types::macaddr8_to_sql(*self, w);
+        Ok(IsNull::No)
+    }
+
+    accepts!(MACADDR8);
 
     to_sql_checked!();
 }
 
 #[cfg(feature = "array-impls")]
 impl<const N: usize> ToSql for [u8; N] {
-    fn to_sql(&self, _: &Type, w: &mut BytesMut) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
-        types::bytea_to_sql(&self[..], w);
+    fn to_sql(&self, ty: &Type, w: &mut BytesMut) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+        // `[u8; 8]` doubles as a `MACADDR8`, whose binary format is just its bytes
+        if *ty == Type::MACADDR8 {
+            w.extend_from_slice(self);
+        } else {
+            types::bytea_to_sql(&self[..], w);
+        }
         Ok(IsNull::No)
     }
 
-    accepts!(BYTEA);
+    fn accepts(ty: &Type) -> bool {
+        matches!(*ty, Type::BYTEA | Type::RAW | Type::BLOB) || (N == 8 && *ty == Type::MACADDR8)
+    }
 
     to_sql_checked!();
 }
@@ -1110,7 +1277,13 @@ impl ToSql for &str {
     fn accepts(ty: &Type) -> bool {
         matches!(
             *ty,
-            Type::VARCHAR | Type::TEXT | Type::BPCHAR | Type::NAME | Type::UNKNOWN
+            Type::VARCHAR
+                | Type::TEXT
+                | Type::BPCHAR
+                | Type::NAME
+                | Type::UNKNOWN
+                | Type::CLOB
+                | Type::NVARCHAR2
         ) || matches!(ty.name(), "citext" | "ltree" | "lquery" | "ltxtquery")
     }
 
@@ -1173,13 +1346,26 @@ macro_rules! simple_to {
 
 simple_to!(bool, bool_to_sql, BOOL);
 simple_to!(i8, char_to_sql, CHAR);
-simple_to!(i16, int2_to_sql, INT2);
 simple_to!(i32, int4_to_sql, INT4);
 simple_to!(u32, oid_to_sql, OID);
 simple_to!(i64, int8_to_sql, INT8);
+simple_to!(i128, int16_to_sql, INT16);
 simple_to!(f32, float4_to_sql, FLOAT4);
 simple_to!(f64, float8_to_sql, FLOAT8);
 
+impl ToSql for i16 {
+    fn to_sql(&self, _: &Type, w: &mut BytesMut) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+        types::int2_to_sql(*self, w);
+        Ok(IsNull::No)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        *ty == Type::INT2 || is_year(ty)
+    }
+
+    to_sql_checked!();
+}
+
 impl<H> ToSql for HashMap<String, Option<String>, H>
 where
     H: BuildHasher,
@@ -1215,7 +1401,7 @@ impl ToSql for SystemTime {
         Ok(IsNull::No)
     }
 
-    accepts!(TIMESTAMP, TIMESTAMPTZ);
+    accepts!(TIMESTAMP, TIMESTAMPTZ, SMALLDATETIME);
 
     to_sql_checked!();
 }
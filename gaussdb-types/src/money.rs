@@ -0,0 +1,74 @@
+//! The Postgres `MONEY` type.
+
+use bytes::BytesMut;
+use gaussdb_protocol::types;
+use std::error::Error;
+
+use crate::{FromSql, FromSqlText, IsNull, ToSql, Type};
+
+/// Postgres `MONEY` type, a currency amount with a fixed fractional precision.
+///
+/// The amount is stored in the currency's minor unit, so with the usual two fractional digits `Money(12345)`
+/// represents `$123.45`. The number of fractional digits, like the currency symbol, is determined by the server's
+/// `lc_monetary` setting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Money(pub i64);
+
+impl From<i64> for Money {
+    fn from(v: i64) -> Money {
+        Money(v)
+    }
+}
+
+impl From<Money> for i64 {
+    fn from(v: Money) -> i64 {
+        v.0
+    }
+}
+
+impl<'a> FromSql<'a> for Money {
+    fn from_sql(_: &Type, raw: &'a [u8]) -> Result<Money, Box<dyn Error + Sync + Send>> {
+        types::money_from_sql(raw).map(Money)
+    }
+
+    accepts!(MONEY);
+}
+
+impl<'a> FromSqlText<'a> for Money {
+    fn from_sql_text(raw: &'a str) -> Result<Money, Box<dyn Error + Sync + Send>> {
+        // the text format is locale dependent, but always consists of the digits of the amount in its minor unit,
+        // interspersed with separators and the currency symbol, and marked negative by a sign or parentheses
+        let negative = raw.contains(['-', '(']);
+        let mut amount = 0i64;
+        let mut digits = 0;
+        for digit in raw.chars().filter_map(|c| c.to_digit(10)) {
+            let digit = i64::from(digit);
+            amount = amount
+                .checked_mul(10)
+                .and_then(|amount| {
+                    if negative {
+                        amount.checked_sub(digit)
+                    } else {
+                        amount.checked_add(digit)
+                    }
+                })
+                .ok_or("value too large to decode")?;
+            digits += 1;
+        }
+        if digits == 0 {
+            return Err(format!("invalid money `{}`", raw).into());
+        }
+        Ok(Money(amount))
+    }
+}
+
+impl ToSql for Money {
+    fn to_sql(&self, _: &Type, out: &mut BytesMut) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+        types::money_to_sql(self.0, out);
+        Ok(IsNull::No)
+    }
+
+    accepts!(MONEY);
+
+    to_sql_checked!();
+}
@@ -0,0 +1,83 @@
+use bytes::BytesMut;
+use gaussdb_protocol::types::{self, ArrayDimension};
+use ndarray_016::{ArrayD, IxDyn};
+use std::convert::TryFrom;
+use std::error::Error;
+
+use crate::{Array, FromSql, FromSqlText, FromSqlTextOwned, IsNull, Kind, ToSql, Type};
+
+// Lower bounds are not kept, and arrays without elements are decoded as one-dimensional.
+fn array_to_ndarray<T>(array: Array<T>) -> Result<ArrayD<T>, Box<dyn Error + Sync + Send>> {
+    let mut shape = array
+        .dimensions()
+        .iter()
+        .map(|d| d.len as usize)
+        .collect::<Vec<_>>();
+    if shape.is_empty() {
+        shape.push(0);
+    }
+
+    ArrayD::from_shape_vec(IxDyn(&shape), array.into_inner()).map_err(Into::into)
+}
+
+impl<'a, T: FromSql<'a>> FromSql<'a> for ArrayD<T> {
+    fn from_sql(ty: &Type, raw: &'a [u8]) -> Result<ArrayD<T>, Box<dyn Error + Sync + Send>> {
+        array_to_ndarray(Array::from_sql(ty, raw)?)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        <Array<T> as FromSql>::accepts(ty)
+    }
+}
+
+impl<'a, T: FromSqlTextOwned> FromSqlText<'a> for ArrayD<T> {
+    fn from_sql_text(raw: &'a str) -> Result<ArrayD<T>, Box<dyn Error + Sync + Send>> {
+        array_to_ndarray(Array::from_sql_text(raw)?)
+    }
+}
+
+impl<T: ToSql> ToSql for ArrayD<T> {
+    fn to_sql(&self, ty: &Type, w: &mut BytesMut) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+        let member_type = match *ty.kind() {
+            Kind::Array(ref member) => member,
+            _ => panic!("expected array type"),
+        };
+
+        if self.ndim() == 0 {
+            return Err("cannot convert a zero-dimensional array to a Postgres array".into());
+        }
+        // empty arrays have no dimensions on the server
+        let shape = if self.is_empty() {
+            &[][..]
+        } else {
+            self.shape()
+        };
+        let dimensions = shape
+            .iter()
+            .map(|&len| {
+                Ok(ArrayDimension {
+                    len: i32::try_from(len).map_err(|_| "array too large")?,
+                    lower_bound: 1,
+                })
+            })
+            .collect::<Result<Vec<_>, Box<dyn Error + Sync + Send>>>()?;
+
+        types::array_to_sql(
+            dimensions,
+            member_type.oid(),
+            self.iter(),
+            |e, w| match e.to_sql(member_type, w)? {
+                IsNull::No => Ok(gaussdb_protocol::IsNull::No),
+                IsNull::Yes => Ok(gaussdb_protocol::IsNull::Yes),
+            },
+            w,
+        )?;
+        Ok(IsNull::No)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        <Array<T> as ToSql>::accepts(ty)
+    }
+
+    to_sql_checked!();
+}
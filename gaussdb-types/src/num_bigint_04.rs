@@ -0,0 +1,38 @@
+use bytes::BytesMut;
+use num_bigint_04::BigInt;
+use std::error::Error;
+
+use crate::{FromSql, FromSqlText, IsNull, Numeric, ToSql, Type};
+
+impl<'a> FromSql<'a> for BigInt {
+    fn from_sql(ty: &Type, raw: &[u8]) -> Result<BigInt, Box<dyn Error + Sync + Send>> {
+        let numeric = Numeric::from_sql(ty, raw)?;
+        BigInt::from_sql_text(&numeric.to_string())
+    }
+
+    accepts!(NUMERIC);
+}
+
+impl<'a> FromSqlText<'a> for BigInt {
+    fn from_sql_text(raw: &'a str) -> Result<BigInt, Box<dyn Error + Sync + Send>> {
+        // values with a scale are accepted as long as they have no fractional part
+        let integer = match raw.split_once('.') {
+            Some((integer, fraction)) if fraction.bytes().all(|b| b == b'0') => integer,
+            Some(_) => return Err(format!("numeric `{}` is not an integer", raw).into()),
+            None => raw,
+        };
+        integer
+            .parse()
+            .map_err(|_| format!("numeric `{}` cannot be represented as a BigInt", raw).into())
+    }
+}
+
+impl ToSql for BigInt {
+    fn to_sql(&self, ty: &Type, w: &mut BytesMut) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+        let numeric = self.to_string().parse::<Numeric>()?;
+        numeric.to_sql(ty, w)
+    }
+
+    accepts!(NUMERIC);
+    to_sql_checked!();
+}
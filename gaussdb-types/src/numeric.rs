@@ -0,0 +1,339 @@
+//! Arbitrary precision `NUMERIC` values.
+
+use bytes::{BufMut, BytesMut};
+use std::convert::TryFrom;
+use std::error::Error;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::str::FromStr;
+
+use crate::{FromSql, FromSqlText, IsNull, ToSql, Type};
+
+const NUMERIC_POS: u16 = 0x0000;
+const NUMERIC_NEG: u16 = 0x4000;
+const NUMERIC_NAN: u16 = 0xC000;
+const NUMERIC_PINF: u16 = 0xD000;
+const NUMERIC_NINF: u16 = 0xF000;
+
+/// Postgres `NUMERIC` type.
+///
+/// Values are stored in the same base 10000 digit format used by the server, so they round trip without any loss
+/// of precision, including the special values `NaN`, `Infinity` and `-Infinity`. The display scale of a value (the
+/// number of digits shown after the decimal point) is preserved as well, but is not taken into account when comparing
+/// values for equality, so `1.0` and `1.00` are equal.
+///
+/// `Numeric` implements `FromStr` and `Display` using the server's text format, and can be used to convert between
+/// other decimal types.
+#[derive(Clone)]
+pub struct Numeric {
+    sign: u16,
+    weight: i16,
+    scale: u16,
+    digits: Vec<i16>,
+}
+
+/// Error parsing a `Numeric`.
+#[derive(Debug)]
+pub struct ParseNumericError(());
+
+impl fmt::Display for ParseNumericError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("invalid numeric value")
+    }
+}
+
+impl Error for ParseNumericError {}
+
+impl Numeric {
+    /// Returns the `NaN` value.
+    pub fn nan() -> Numeric {
+        Numeric::special(NUMERIC_NAN)
+    }
+
+    /// Returns positive infinity.
+    pub fn infinity() -> Numeric {
+        Numeric::special(NUMERIC_PINF)
+    }
+
+    /// Returns negative infinity.
+    pub fn neg_infinity() -> Numeric {
+        Numeric::special(NUMERIC_NINF)
+    }
+
+    fn special(sign: u16) -> Numeric {
+        Numeric {
+            sign,
+            weight: 0,
+            scale: 0,
+            digits: vec![],
+        }
+    }
+
+    /// Determines if the value is `NaN`.
+    pub fn is_nan(&self) -> bool {
+        self.sign == NUMERIC_NAN
+    }
+
+    /// Determines if the value is positive or negative infinity.
+    pub fn is_infinite(&self) -> bool {
+        self.sign == NUMERIC_PINF || self.sign == NUMERIC_NINF
+    }
+
+    /// Determines if the value is neither `NaN` nor infinite.
+    pub fn is_finite(&self) -> bool {
+        self.sign == NUMERIC_POS || self.sign == NUMERIC_NEG
+    }
+
+    /// Determines if the value is negative, including negative infinity.
+    pub fn is_sign_negative(&self) -> bool {
+        self.sign == NUMERIC_NEG || self.sign == NUMERIC_NINF
+    }
+
+    /// Returns the display scale of the value: the number of digits shown after the decimal point.
+    pub fn scale(&self) -> u16 {
+        self.scale
+    }
+
+    fn digit(&self, i: i32) -> i16 {
+        usize::try_from(i)
+            .ok()
+            .and_then(|i| self.digits.get(i))
+            .copied()
+            .unwrap_or(0)
+    }
+}
+
+impl FromStr for Numeric {
+    type Err = ParseNumericError;
+
+    fn from_str(s: &str) -> Result<Numeric, ParseNumericError> {
+        let s = s.trim();
+        if s.eq_ignore_ascii_case("nan") {
+            return Ok(Numeric::nan());
+        }
+
+        let (negative, unsigned) = match s.as_bytes().first() {
+            Some(b'-') => (true, &s[1..]),
+            Some(b'+') => (false, &s[1..]),
+            _ => (false, s),
+        };
+        if unsigned.eq_ignore_ascii_case("infinity") || unsigned.eq_ignore_ascii_case("inf") {
+            return Ok(if negative {
+                Numeric::neg_infinity()
+            } else {
+                Numeric::infinity()
+            });
+        }
+
+        let (mantissa, exponent) = match unsigned.find(['e', 'E']) {
+            Some(idx) => {
+                let exponent = unsigned[idx + 1..]
+                    .parse::<i32>()
+                    .map_err(|_| ParseNumericError(()))?;
+                (&unsigned[..idx], exponent)
+            }
+            None => (unsigned, 0),
+        };
+        let (integer, fraction) = match mantissa.split_once('.') {
+            Some((integer, fraction)) => (integer, fraction),
+            None => (mantissa, ""),
+        };
+        if integer.is_empty() && fraction.is_empty()
+            || !integer
+                .bytes()
+                .chain(fraction.bytes())
+                .all(|b| b.is_ascii_digit())
+        {
+            return Err(ParseNumericError(()));
+        }
+
+        // the decimal digits of the value, and the number of them before the decimal point
+        let decimal = integer.bytes().chain(fraction.bytes()).map(|b| b - b'0');
+        let point = i32::try_from(integer.len())
+            .ok()
+            .and_then(|len| len.checked_add(exponent))
+            .ok_or(ParseNumericError(()))?;
+        let scale = i32::try_from(fraction.len())
+            .ok()
+            .and_then(|len| len.checked_sub(exponent))
+            .ok_or(ParseNumericError(()))?
+            .max(0);
+        let scale = u16::try_from(scale).map_err(|_| ParseNumericError(()))?;
+
+        // pad the digits on the left so the decimal point falls on a base 10000 digit boundary
+        let pad = (4 - point.rem_euclid(4)) % 4;
+        let mut digits = vec![];
+        let mut digit = 0;
+        let mut len = 0;
+        // `repeat_n` requires Rust 1.82
+        #[allow(clippy::manual_repeat_n)]
+        for d in std::iter::repeat(0).take(pad as usize).chain(decimal) {
+            digit = digit * 10 + i16::from(d);
+            len += 1;
+            if len == 4 {
+                digits.push(digit);
+                digit = 0;
+                len = 0;
+            }
+        }
+        if len > 0 {
+            digits.push(digit * 10i16.pow(4 - len));
+        }
+        let weight = (point + pad) / 4 - 1;
+
+        let leading = digits.iter().take_while(|d| **d == 0).count();
+        digits.drain(..leading);
+        while digits.last() == Some(&0) {
+            digits.pop();
+        }
+        let weight = if digits.is_empty() {
+            0
+        } else {
+            i16::try_from(weight - leading as i32).map_err(|_| ParseNumericError(()))?
+        };
+
+        Ok(Numeric {
+            sign: if negative && !digits.is_empty() {
+                NUMERIC_NEG
+            } else {
+                NUMERIC_POS
+            },
+            weight,
+            scale,
+            digits,
+        })
+    }
+}
+
+impl fmt::Display for Numeric {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.sign {
+            NUMERIC_NAN => return f.write_str("NaN"),
+            NUMERIC_PINF => return f.write_str("Infinity"),
+            NUMERIC_NINF => return f.write_str("-Infinity"),
+            NUMERIC_NEG => f.write_str("-")?,
+            _ => {}
+        }
+
+        let weight = i32::from(self.weight);
+        if weight < 0 {
+            f.write_str("0")?;
+        } else {
+            write!(f, "{}", self.digit(0))?;
+            for i in 1..=weight {
+                write!(f, "{:04}", self.digit(i))?;
+            }
+        }
+
+        if self.scale > 0 {
+            f.write_str(".")?;
+            let mut remaining = usize::from(self.scale);
+            let mut i = weight + 1;
+            while remaining > 0 {
+                let digits = format!("{:04}", self.digit(i));
+                let len = remaining.min(4);
+                f.write_str(&digits[..len])?;
+                remaining -= len;
+                i += 1;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Debug for Numeric {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl PartialEq for Numeric {
+    fn eq(&self, other: &Numeric) -> bool {
+        self.sign == other.sign && self.weight == other.weight && self.digits == other.digits
+    }
+}
+
+impl Eq for Numeric {}
+
+impl Hash for Numeric {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.sign.hash(state);
+        self.weight.hash(state);
+        self.digits.hash(state);
+    }
+}
+
+impl<'a> FromSql<'a> for Numeric {
+    fn from_sql(_: &Type, mut raw: &'a [u8]) -> Result<Numeric, Box<dyn Error + Sync + Send>> {
+        let ndigits = read_i16(&mut raw)?;
+        let weight = read_i16(&mut raw)?;
+        let sign = read_i16(&mut raw)? as u16;
+        let scale = read_i16(&mut raw)?;
+
+        match sign {
+            NUMERIC_POS | NUMERIC_NEG => {}
+            NUMERIC_NAN | NUMERIC_PINF | NUMERIC_NINF => return Ok(Numeric::special(sign)),
+            _ => return Err("invalid numeric sign".into()),
+        }
+        if ndigits < 0 || scale < 0 {
+            return Err("invalid numeric header".into());
+        }
+
+        let mut digits = Vec::with_capacity(ndigits as usize);
+        for _ in 0..ndigits {
+            let digit = read_i16(&mut raw)?;
+            if !(0..10000).contains(&digit) {
+                return Err("invalid numeric digit".into());
+            }
+            digits.push(digit);
+        }
+        if !raw.is_empty() {
+            return Err("invalid message length: numeric not drained".into());
+        }
+
+        Ok(Numeric {
+            sign,
+            weight,
+            scale: scale as u16,
+            digits,
+        })
+    }
+
+    accepts!(NUMERIC);
+}
+
+impl<'a> FromSqlText<'a> for Numeric {
+    fn from_sql_text(raw: &'a str) -> Result<Numeric, Box<dyn Error + Sync + Send>> {
+        raw.parse()
+            .map_err(|_| format!("invalid numeric `{}`", raw).into())
+    }
+}
+
+impl ToSql for Numeric {
+    fn to_sql(&self, _: &Type, out: &mut BytesMut) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+        let ndigits =
+            i16::try_from(self.digits.len()).map_err(|_| "value too large to transmit")?;
+        out.put_i16(ndigits);
+        out.put_i16(self.weight);
+        out.put_u16(self.sign);
+        out.put_u16(self.scale);
+        for digit in &self.digits {
+            out.put_i16(*digit);
+        }
+        Ok(IsNull::No)
+    }
+
+    accepts!(NUMERIC);
+
+    to_sql_checked!();
+}
+
+fn read_i16(buf: &mut &[u8]) -> Result<i16, Box<dyn Error + Sync + Send>> {
+    if buf.len() < 2 {
+        return Err("invalid buffer size".into());
+    }
+    let v = i16::from_be_bytes([buf[0], buf[1]]);
+    *buf = &buf[2..];
+    Ok(v)
+}
@@ -7,7 +7,7 @@ use std::error::Error;
 use std::fmt;
 use std::str::FromStr;
 
-use crate::{FromSql, IsNull, ToSql, Type};
+use crate::{FromSql, FromSqlText, IsNull, ToSql, Type};
 
 /// Postgres `PG_LSN` type.
 #[derive(Clone, Copy, Eq, Ord, PartialEq, PartialOrd)]
@@ -65,6 +65,13 @@ impl<'a> FromSql<'a> for PgLsn {
     accepts!(PG_LSN);
 }
 
+impl<'a> FromSqlText<'a> for PgLsn {
+    fn from_sql_text(raw: &'a str) -> Result<Self, Box<dyn Error + Sync + Send>> {
+        raw.parse()
+            .map_err(|_| format!("invalid LSN `{}`", raw).into())
+    }
+}
+
 impl ToSql for PgLsn {
     fn to_sql(&self, _: &Type, out: &mut BytesMut) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
         types::lsn_to_sql((*self).into(), out);
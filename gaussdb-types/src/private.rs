@@ -19,16 +19,19 @@ pub fn read_value<'a, T>(
 where
     T: FromSql<'a>,
 {
-    let len = read_be_i32(buf)?;
-    let value = if len < 0 {
-        None
-    } else {
-        if len as usize > buf.len() {
-            return Err("invalid buffer size".into());
-        }
-        let (head, tail) = buf.split_at(len as usize);
-        *buf = tail;
-        Some(head)
-    };
+    let value = read_raw(buf)?;
     T::from_sql_nullable(type_, value)
 }
+
+pub fn read_raw<'a>(buf: &mut &'a [u8]) -> Result<Option<&'a [u8]>, Box<dyn Error + Sync + Send>> {
+    let len = read_be_i32(buf)?;
+    if len < 0 {
+        return Ok(None);
+    }
+    if len as usize > buf.len() {
+        return Err("invalid buffer size".into());
+    }
+    let (head, tail) = buf.split_at(len as usize);
+    *buf = tail;
+    Ok(Some(head))
+}
@@ -0,0 +1,317 @@
+//! Range types.
+
+use bytes::BytesMut;
+use gaussdb_protocol::types;
+use std::error::Error;
+use std::fmt;
+use std::ops::{self, Bound};
+
+use crate::text::range_from_text;
+use crate::{FromSql, FromSqlText, FromSqlTextOwned, IsNull, Kind, ToSql, Type};
+
+/// A Postgres range, such as `INT4RANGE`, `TSTZRANGE` or `DATERANGE`, or a user-defined range type.
+///
+/// `Range<T>` can be used with any range type whose element type `T` supports, and with arrays of ranges as
+/// `Vec<Range<T>>`. Ranges of discrete types like `INT4RANGE` are normalized by the server into the canonical
+/// `[lower,upper)` form, so a range sent with inclusive upper bound may come back with an exclusive one.
+///
+/// Ranges can also be created from the standard library's range types, so `(1..5).into()` is `[1,5)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Range<T> {
+    /// An empty range.
+    Empty,
+    /// A nonempty range with a lower and upper bound.
+    Nonempty(Bound<T>, Bound<T>),
+}
+
+impl<T> Range<T> {
+    /// Creates a new range from a lower and upper bound.
+    pub fn new(lower: Bound<T>, upper: Bound<T>) -> Range<T> {
+        Range::Nonempty(lower, upper)
+    }
+
+    /// Determines if the range is empty.
+    ///
+    /// Only the `Empty` variant is considered to be empty; the bounds of a `Nonempty` range are not inspected.
+    pub fn is_empty(&self) -> bool {
+        matches!(self, Range::Empty)
+    }
+
+    /// Returns the lower bound of the range, or `None` if it is empty.
+    pub fn lower(&self) -> Option<Bound<&T>> {
+        match self {
+            Range::Empty => None,
+            Range::Nonempty(lower, _) => Some(lower.as_ref()),
+        }
+    }
+
+    /// Returns the upper bound of the range, or `None` if it is empty.
+    pub fn upper(&self) -> Option<Bound<&T>> {
+        match self {
+            Range::Empty => None,
+            Range::Nonempty(_, upper) => Some(upper.as_ref()),
+        }
+    }
+}
+
+impl<T: PartialOrd> Range<T> {
+    /// Determines if the range contains a value.
+    pub fn contains(&self, value: &T) -> bool {
+        match self {
+            Range::Empty => false,
+            Range::Nonempty(lower, upper) => {
+                let above = match lower {
+                    Bound::Included(lower) => lower <= value,
+                    Bound::Excluded(lower) => lower < value,
+                    Bound::Unbounded => true,
+                };
+                let below = match upper {
+                    Bound::Included(upper) => value <= upper,
+                    Bound::Excluded(upper) => value < upper,
+                    Bound::Unbounded => true,
+                };
+                above && below
+            }
+        }
+    }
+
+    /// Determines if the range contains every value of another range.
+    ///
+    /// Every range contains the empty range.
+    pub fn contains_range(&self, other: &Range<T>) -> bool {
+        match (self, other) {
+            (_, Range::Empty) => true,
+            (Range::Empty, _) => false,
+            (Range::Nonempty(lower, upper), Range::Nonempty(other_lower, other_upper)) => {
+                starts_before(lower, other_lower) && ends_after(upper, other_upper)
+            }
+        }
+    }
+
+    /// Determines if the range has any values in common with another range.
+    pub fn overlaps(&self, other: &Range<T>) -> bool {
+        match (self, other) {
+            (Range::Nonempty(lower, upper), Range::Nonempty(other_lower, other_upper)) => {
+                precedes(lower, other_upper) && precedes(other_lower, upper)
+            }
+            _ => false,
+        }
+    }
+}
+
+// Determines if the lower bound `a` is at or before the lower bound `b`.
+fn starts_before<T: PartialOrd>(a: &Bound<T>, b: &Bound<T>) -> bool {
+    match (a, b) {
+        (Bound::Unbounded, _) => true,
+        (_, Bound::Unbounded) => false,
+        (Bound::Excluded(a), Bound::Included(b)) => a < b,
+        (Bound::Included(a), Bound::Included(b))
+        | (Bound::Included(a), Bound::Excluded(b))
+        | (Bound::Excluded(a), Bound::Excluded(b)) => a <= b,
+    }
+}
+
+// Determines if the upper bound `a` is at or after the upper bound `b`.
+fn ends_after<T: PartialOrd>(a: &Bound<T>, b: &Bound<T>) -> bool {
+    match (a, b) {
+        (Bound::Unbounded, _) => true,
+        (_, Bound::Unbounded) => false,
+        (Bound::Excluded(a), Bound::Included(b)) => a > b,
+        (Bound::Included(a), Bound::Included(b))
+        | (Bound::Included(a), Bound::Excluded(b))
+        | (Bound::Excluded(a), Bound::Excluded(b)) => a >= b,
+    }
+}
+
+// Determines if there is a value at or above the lower bound `lower` and at or below the upper bound `upper`.
+fn precedes<T: PartialOrd>(lower: &Bound<T>, upper: &Bound<T>) -> bool {
+    match (lower, upper) {
+        (Bound::Unbounded, _) | (_, Bound::Unbounded) => true,
+        (Bound::Included(lower), Bound::Included(upper)) => lower <= upper,
+        (Bound::Included(lower), Bound::Excluded(upper))
+        | (Bound::Excluded(lower), Bound::Included(upper))
+        | (Bound::Excluded(lower), Bound::Excluded(upper)) => lower < upper,
+    }
+}
+
+impl<T> From<ops::Range<T>> for Range<T> {
+    fn from(range: ops::Range<T>) -> Range<T> {
+        Range::Nonempty(Bound::Included(range.start), Bound::Excluded(range.end))
+    }
+}
+
+impl<T> From<ops::RangeInclusive<T>> for Range<T> {
+    fn from(range: ops::RangeInclusive<T>) -> Range<T> {
+        let (start, end) = range.into_inner();
+        Range::Nonempty(Bound::Included(start), Bound::Included(end))
+    }
+}
+
+impl<T> From<ops::RangeFrom<T>> for Range<T> {
+    fn from(range: ops::RangeFrom<T>) -> Range<T> {
+        Range::Nonempty(Bound::Included(range.start), Bound::Unbounded)
+    }
+}
+
+impl<T> From<ops::RangeTo<T>> for Range<T> {
+    fn from(range: ops::RangeTo<T>) -> Range<T> {
+        Range::Nonempty(Bound::Unbounded, Bound::Excluded(range.end))
+    }
+}
+
+impl<T> From<ops::RangeToInclusive<T>> for Range<T> {
+    fn from(range: ops::RangeToInclusive<T>) -> Range<T> {
+        Range::Nonempty(Bound::Unbounded, Bound::Included(range.end))
+    }
+}
+
+impl<T> From<ops::RangeFull> for Range<T> {
+    fn from(_: ops::RangeFull) -> Range<T> {
+        Range::Nonempty(Bound::Unbounded, Bound::Unbounded)
+    }
+}
+
+// Formats the range like the server, e.g. `[1,5)` or `empty`.
+impl<T: fmt::Display> fmt::Display for Range<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (lower, upper) = match self {
+            Range::Empty => return f.write_str("empty"),
+            Range::Nonempty(lower, upper) => (lower, upper),
+        };
+
+        match lower {
+            Bound::Included(v) => {
+                f.write_str("[")?;
+                fmt_bound(f, v)?;
+            }
+            Bound::Excluded(v) => {
+                f.write_str("(")?;
+                fmt_bound(f, v)?;
+            }
+            Bound::Unbounded => f.write_str("(")?,
+        }
+        f.write_str(",")?;
+        match upper {
+            Bound::Included(v) => {
+                fmt_bound(f, v)?;
+                f.write_str("]")
+            }
+            Bound::Excluded(v) => {
+                fmt_bound(f, v)?;
+                f.write_str(")")
+            }
+            Bound::Unbounded => f.write_str(")"),
+        }
+    }
+}
+
+// Writes a bound value, quoting it if required.
+fn fmt_bound<T: fmt::Display>(f: &mut fmt::Formatter<'_>, value: &T) -> fmt::Result {
+    let s = value.to_string();
+    let quote = s.is_empty()
+        || s.bytes()
+            .any(|b| b"\"\\,()[]".contains(&b) || b.is_ascii_whitespace());
+    if !quote {
+        return f.write_str(&s);
+    }
+
+    f.write_str("\"")?;
+    for c in s.chars() {
+        if c == '"' || c == '\\' {
+            f.write_str("\\")?;
+        }
+        write!(f, "{}", c)?;
+    }
+    f.write_str("\"")
+}
+
+impl<'a, T: FromSql<'a>> FromSql<'a> for Range<T> {
+    fn from_sql(ty: &Type, raw: &'a [u8]) -> Result<Range<T>, Box<dyn Error + Sync + Send>> {
+        let member = match *ty.kind() {
+            Kind::Range(ref member) => member,
+            _ => panic!("expected range type"),
+        };
+
+        let bound = |bound| -> Result<Bound<T>, Box<dyn Error + Sync + Send>> {
+            match bound {
+                types::RangeBound::Inclusive(v) => {
+                    Ok(Bound::Included(T::from_sql_nullable(member, v)?))
+                }
+                types::RangeBound::Exclusive(v) => {
+                    Ok(Bound::Excluded(T::from_sql_nullable(member, v)?))
+                }
+                types::RangeBound::Unbounded => Ok(Bound::Unbounded),
+            }
+        };
+
+        match types::range_from_sql(raw)? {
+            types::Range::Empty => Ok(Range::Empty),
+            types::Range::Nonempty(lower, upper) => {
+                Ok(Range::Nonempty(bound(lower)?, bound(upper)?))
+            }
+        }
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        match *ty.kind() {
+            Kind::Range(ref member) => T::accepts(member),
+            _ => false,
+        }
+    }
+}
+
+impl<'a, T: FromSqlTextOwned> FromSqlText<'a> for Range<T> {
+    fn from_sql_text(raw: &'a str) -> Result<Range<T>, Box<dyn Error + Sync + Send>> {
+        let bound = |bound: Bound<String>| -> Result<Bound<T>, Box<dyn Error + Sync + Send>> {
+            match bound {
+                Bound::Included(v) => Ok(Bound::Included(T::from_sql_text(&v)?)),
+                Bound::Excluded(v) => Ok(Bound::Excluded(T::from_sql_text(&v)?)),
+                Bound::Unbounded => Ok(Bound::Unbounded),
+            }
+        };
+
+        match range_from_text(raw)? {
+            Range::Empty => Ok(Range::Empty),
+            Range::Nonempty(lower, upper) => Ok(Range::Nonempty(bound(lower)?, bound(upper)?)),
+        }
+    }
+}
+
+impl<T: ToSql> ToSql for Range<T> {
+    fn to_sql(&self, ty: &Type, w: &mut BytesMut) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+        let member = match *ty.kind() {
+            Kind::Range(ref member) => member,
+            _ => panic!("expected range type"),
+        };
+
+        let (lower, upper) = match self {
+            Range::Empty => {
+                types::empty_range_to_sql(w);
+                return Ok(IsNull::No);
+            }
+            Range::Nonempty(lower, upper) => (lower, upper),
+        };
+
+        let is_null = |v: &T, w: &mut BytesMut| match v.to_sql(member, w)? {
+            IsNull::No => Ok(gaussdb_protocol::IsNull::No),
+            IsNull::Yes => Ok(gaussdb_protocol::IsNull::Yes),
+        };
+        let bound = |bound: &Bound<T>, w: &mut BytesMut| match bound {
+            Bound::Included(v) => is_null(v, w).map(types::RangeBound::Inclusive),
+            Bound::Excluded(v) => is_null(v, w).map(types::RangeBound::Exclusive),
+            Bound::Unbounded => Ok(types::RangeBound::Unbounded),
+        };
+        types::range_to_sql(|w| bound(lower, w), |w| bound(upper, w), w)?;
+
+        Ok(IsNull::No)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        match *ty.kind() {
+            Kind::Range(ref member) => T::accepts(member),
+            _ => false,
+        }
+    }
+
+    to_sql_checked!();
+}
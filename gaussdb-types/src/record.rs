@@ -0,0 +1,190 @@
+//! Anonymous records.
+
+use std::error::Error;
+
+use crate::private::{read_be_i32, read_raw, read_value};
+use crate::{FromSql, Kind, Type, WrongType};
+
+/// An anonymous Postgres `RECORD`, such as the result of a `ROW(...)` expression or of a function returning `RECORD`.
+///
+/// Unlike named composite types, the fields of an anonymous record are only known once a value is decoded, since its
+/// binary representation carries the type of each field. Fields are accessed by index, either as a [`Value`] or as
+/// any other type implementing `FromSql`:
+///
+/// ```rust
+/// # use gaussdb_types::{Record, Value};
+/// # fn f(record: Record) {
+/// let id: i32 = record.get(0);
+/// let name: Option<&str> = record.get(1);
+/// let any: Value = record.get(2);
+/// # }
+/// ```
+///
+/// Fields of built-in types are decoded with their full type information. The types of other fields, such as enums or
+/// composites, cannot be looked up without a query, so they can only be read as [`Value::Unknown`].
+///
+/// Values of named composite types can be decoded as a `Record` as well, in which case the field types come from the
+/// composite type.
+///
+/// [`Value`]: crate::Value
+/// [`Value::Unknown`]: crate::Value::Unknown
+#[derive(Debug, Clone, PartialEq)]
+pub struct Record {
+    types: Vec<Type>,
+    values: Vec<Option<Vec<u8>>>,
+}
+
+impl Record {
+    /// Returns the number of fields in the record.
+    pub fn len(&self) -> usize {
+        self.types.len()
+    }
+
+    /// Determines if the record has no fields.
+    pub fn is_empty(&self) -> bool {
+        self.types.is_empty()
+    }
+
+    /// Returns the types of the fields of the record.
+    pub fn types(&self) -> &[Type] {
+        &self.types
+    }
+
+    /// Deserializes a field of the record.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is out of bounds or if the value cannot be converted to the specified type.
+    #[track_caller]
+    pub fn get<'a, T>(&'a self, idx: usize) -> T
+    where
+        T: FromSql<'a>,
+    {
+        match self.try_get(idx) {
+            Ok(ok) => ok,
+            Err(err) => panic!("error retrieving record field {}: {}", idx, err),
+        }
+    }
+
+    /// Like `Record::get`, but returns a `Result` rather than panicking.
+    pub fn try_get<'a, T>(&'a self, idx: usize) -> Result<T, Box<dyn Error + Sync + Send>>
+    where
+        T: FromSql<'a>,
+    {
+        let ty = match self.types.get(idx) {
+            Some(ty) => ty,
+            None => return Err(format!("record field index {} out of bounds", idx).into()),
+        };
+        if !T::accepts(ty) {
+            return Err(Box::new(WrongType::new::<T>(ty.clone())));
+        }
+
+        T::from_sql_nullable(ty, self.values[idx].as_deref())
+    }
+}
+
+impl<'a> FromSql<'a> for Record {
+    fn from_sql(ty: &Type, mut raw: &'a [u8]) -> Result<Record, Box<dyn Error + Sync + Send>> {
+        let num_fields = read_be_i32(&mut raw)?;
+
+        let mut record = Record {
+            types: Vec::with_capacity(num_fields.max(0) as usize),
+            values: Vec::with_capacity(num_fields.max(0) as usize),
+        };
+        for i in 0..num_fields as usize {
+            let type_ = field_type(ty, i, &mut raw)?;
+            let value = read_raw(&mut raw)?;
+            record.types.push(type_);
+            record.values.push(value.map(<[u8]>::to_vec));
+        }
+        if !raw.is_empty() {
+            return Err("invalid message length: record not drained".into());
+        }
+
+        Ok(record)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        matches!(*ty.kind(), Kind::Composite(_)) || *ty == Type::RECORD
+    }
+}
+
+// Named composites know the types of their fields up front, while anonymous records only carry their OIDs.
+fn field_type(
+    ty: &Type,
+    idx: usize,
+    raw: &mut &[u8],
+) -> Result<Type, Box<dyn Error + Sync + Send>> {
+    let oid = read_be_i32(raw)? as u32;
+    match *ty.kind() {
+        Kind::Composite(ref fields) => {
+            let field = fields
+                .get(idx)
+                .ok_or_else(|| format!("invalid field count: more than {}", fields.len()))?;
+            if field.type_().oid() != oid {
+                return Err(format!("field {} has an unexpected type", field.name()).into());
+            }
+            Ok(field.type_().clone())
+        }
+        _ => Ok(Type::from_oid(oid)
+            .unwrap_or_else(|| Type::new(oid.to_string(), oid, Kind::Simple, String::new()))),
+    }
+}
+
+fn read_field<'a, T>(
+    ty: &Type,
+    idx: usize,
+    raw: &mut &'a [u8],
+) -> Result<T, Box<dyn Error + Sync + Send>>
+where
+    T: FromSql<'a>,
+{
+    let type_ = field_type(ty, idx, raw)?;
+    if !T::accepts(&type_) {
+        return Err(Box::new(WrongType::new::<T>(type_)));
+    }
+    read_value(&type_, raw)
+}
+
+macro_rules! tuple_from_sql {
+    ($len:expr; $($t:ident $idx:tt),+) => {
+        impl<'a, $($t),+> FromSql<'a> for ($($t,)+)
+        where
+            $($t: FromSql<'a>,)+
+        {
+            fn from_sql(ty: &Type, mut raw: &'a [u8]) -> Result<Self, Box<dyn Error + Sync + Send>> {
+                let num_fields = read_be_i32(&mut raw)?;
+                if num_fields != $len {
+                    return Err(format!("invalid field count: {} vs {}", num_fields, $len).into());
+                }
+                let value = ($(read_field::<$t>(ty, $idx, &mut raw)?,)+);
+                if !raw.is_empty() {
+                    return Err("invalid message length: record not drained".into());
+                }
+                Ok(value)
+            }
+
+            fn accepts(ty: &Type) -> bool {
+                match *ty.kind() {
+                    Kind::Composite(ref fields) => {
+                        fields.len() == $len $(&& $t::accepts(fields[$idx].type_()))+
+                    }
+                    _ => *ty == Type::RECORD,
+                }
+            }
+        }
+    };
+}
+
+tuple_from_sql!(1; A 0);
+tuple_from_sql!(2; A 0, B 1);
+tuple_from_sql!(3; A 0, B 1, C 2);
+tuple_from_sql!(4; A 0, B 1, C 2, D 3);
+tuple_from_sql!(5; A 0, B 1, C 2, D 3, E 4);
+tuple_from_sql!(6; A 0, B 1, C 2, D 3, E 4, F 5);
+tuple_from_sql!(7; A 0, B 1, C 2, D 3, E 4, F 5, G 6);
+tuple_from_sql!(8; A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7);
+tuple_from_sql!(9; A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8);
+tuple_from_sql!(10; A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9);
+tuple_from_sql!(11; A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10);
+tuple_from_sql!(12; A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10, L 11);
@@ -0,0 +1,31 @@
+use bytes::BytesMut;
+use rust_decimal_1::Decimal;
+use std::error::Error;
+
+use crate::{FromSql, FromSqlText, IsNull, Numeric, ToSql, Type};
+
+impl<'a> FromSql<'a> for Decimal {
+    fn from_sql(ty: &Type, raw: &[u8]) -> Result<Decimal, Box<dyn Error + Sync + Send>> {
+        let numeric = Numeric::from_sql(ty, raw)?;
+        Decimal::from_sql_text(&numeric.to_string())
+    }
+
+    accepts!(NUMERIC);
+}
+
+impl<'a> FromSqlText<'a> for Decimal {
+    fn from_sql_text(raw: &'a str) -> Result<Decimal, Box<dyn Error + Sync + Send>> {
+        Decimal::from_str_exact(raw)
+            .map_err(|e| format!("numeric `{}` out of range for Decimal: {}", raw, e).into())
+    }
+}
+
+impl ToSql for Decimal {
+    fn to_sql(&self, ty: &Type, w: &mut BytesMut) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+        let numeric = self.to_string().parse::<Numeric>()?;
+        numeric.to_sql(ty, w)
+    }
+
+    accepts!(NUMERIC);
+    to_sql_checked!();
+}
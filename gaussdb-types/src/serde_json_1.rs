@@ -1,4 +1,4 @@
-use crate::{FromSql, IsNull, ToSql, Type};
+use crate::{FromSql, FromSqlText, IsNull, ToSql, Type};
 use bytes::{BufMut, BytesMut};
 use serde_1::{Deserialize, Serialize};
 use serde_json_1::Value;
@@ -31,6 +31,17 @@ where
     accepts!(JSON, JSONB);
 }
 
+impl<'a, T> FromSqlText<'a> for Json<T>
+where
+    T: Deserialize<'a>,
+{
+    fn from_sql_text(raw: &'a str) -> Result<Json<T>, Box<dyn Error + Sync + Send>> {
+        serde_json_1::de::from_str(raw)
+            .map(Json)
+            .map_err(Into::into)
+    }
+}
+
 impl<T> ToSql for Json<T>
 where
     T: Serialize + Debug,
@@ -59,6 +70,12 @@ impl<'a> FromSql<'a> for Value {
     accepts!(JSON, JSONB);
 }
 
+impl<'a> FromSqlText<'a> for Value {
+    fn from_sql_text(raw: &'a str) -> Result<Value, Box<dyn Error + Sync + Send>> {
+        Json::<Value>::from_sql_text(raw).map(|json| json.0)
+    }
+}
+
 impl ToSql for Value {
     fn to_sql(
         &self,
@@ -2,7 +2,7 @@ use bytes::BytesMut;
 use smol_str_01::SmolStr;
 use std::error::Error;
 
-use crate::{FromSql, IsNull, ToSql, Type};
+use crate::{FromSql, FromSqlText, IsNull, ToSql, Type};
 
 impl<'a> FromSql<'a> for SmolStr {
     fn from_sql(ty: &Type, raw: &'a [u8]) -> Result<SmolStr, Box<dyn Error + Sync + Send>> {
@@ -14,6 +14,12 @@ impl<'a> FromSql<'a> for SmolStr {
     }
 }
 
+impl<'a> FromSqlText<'a> for SmolStr {
+    fn from_sql_text(raw: &'a str) -> Result<SmolStr, Box<dyn Error + Sync + Send>> {
+        Ok(SmolStr::from(raw))
+    }
+}
+
 impl ToSql for SmolStr {
     fn to_sql(&self, ty: &Type, w: &mut BytesMut) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
         <&str as ToSql>::to_sql(&&**self, ty, w)
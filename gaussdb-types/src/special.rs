@@ -2,7 +2,7 @@ use bytes::BytesMut;
 use gaussdb_protocol::types;
 use std::error::Error;
 
-use crate::{FromSql, IsNull, ToSql, Type};
+use crate::{FromSql, FromSqlText, IsNull, ToSql, Type};
 
 /// A wrapper that can be used to represent infinity with `Type::Date` types.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -29,6 +29,16 @@ impl<'a, T: FromSql<'a>> FromSql<'a> for Date<T> {
     }
 }
 
+impl<'a, T: FromSqlText<'a>> FromSqlText<'a> for Date<T> {
+    fn from_sql_text(raw: &'a str) -> Result<Self, Box<dyn Error + Sync + Send>> {
+        match raw {
+            "infinity" => Ok(Date::PosInfinity),
+            "-infinity" => Ok(Date::NegInfinity),
+            _ => T::from_sql_text(raw).map(Date::Value),
+        }
+    }
+}
+
 impl<T: ToSql> ToSql for Date<T> {
     fn to_sql(
         &self,
@@ -74,7 +84,17 @@ impl<'a, T: FromSql<'a>> FromSql<'a> for Timestamp<T> {
     }
 
     fn accepts(ty: &Type) -> bool {
-        matches!(*ty, Type::TIMESTAMP | Type::TIMESTAMPTZ if T::accepts(ty))
+        matches!(*ty, Type::TIMESTAMP | Type::TIMESTAMPTZ | Type::SMALLDATETIME if T::accepts(ty))
+    }
+}
+
+impl<'a, T: FromSqlText<'a>> FromSqlText<'a> for Timestamp<T> {
+    fn from_sql_text(raw: &'a str) -> Result<Self, Box<dyn Error + Sync + Send>> {
+        match raw {
+            "infinity" => Ok(Timestamp::PosInfinity),
+            "-infinity" => Ok(Timestamp::NegInfinity),
+            _ => T::from_sql_text(raw).map(Timestamp::Value),
+        }
     }
 }
 
@@ -95,7 +115,7 @@ impl<T: ToSql> ToSql for Timestamp<T> {
     }
 
     fn accepts(ty: &Type) -> bool {
-        matches!(*ty, Type::TIMESTAMP | Type::TIMESTAMPTZ if T::accepts(ty))
+        matches!(*ty, Type::TIMESTAMP | Type::TIMESTAMPTZ | Type::SMALLDATETIME if T::accepts(ty))
     }
 
     to_sql_checked!();
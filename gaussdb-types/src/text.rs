@@ -0,0 +1,835 @@
+//! Decoding of values from their text representation.
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::error::Error;
+use std::hash::BuildHasher;
+use std::net::IpAddr;
+use std::ops::Bound;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::{Dimension, Range, WasNull, NSEC_PER_USEC, TIME_SEC_CONVERSION, USEC_PER_SEC};
+
+/// A trait for types that can be created from the text representation of a Postgres value.
+///
+/// This is the representation returned by `simple_query`, and by queries which request their results in the text
+/// format. Unlike [`FromSql`](crate::FromSql), the Postgres type of the value is not needed to decode it, which
+/// makes this usable for values whose binary format is not supported, such as those of types specific to GaussDB.
+///
+/// # Types
+///
+/// `FromSqlText` is implemented for all types listed in the documentation of [`FromSql`](crate::FromSql), including
+/// those behind the `with-*` cargo features, as well as for `Option<T>`, `Vec<T>` and `Box<[T]>` (one-dimensional
+/// arrays), [`Array<T>`](crate::Array) (arrays of any number of dimensions) and `[T; N]` when the `array-impls`
+/// feature is enabled.
+///
+/// Dates and times are expected in the `ISO` `DateStyle`, which is the server default. Values with a time zone are
+/// converted to UTC.
+pub trait FromSqlText<'a>: Sized {
+    /// The delimiter between values of this type in the text representation of an array.
+    const ARRAY_DELIMITER: u8 = b',';
+
+    /// Creates a new value of this type from its text representation.
+    fn from_sql_text(raw: &'a str) -> Result<Self, Box<dyn Error + Sync + Send>>;
+
+    /// Creates a new value of this type from a `NULL` SQL value.
+    ///
+    /// The default implementation returns `Err(Box::new(WasNull))`.
+    fn from_sql_text_null() -> Result<Self, Box<dyn Error + Sync + Send>> {
+        Err(Box::new(WasNull))
+    }
+
+    /// A convenience function that delegates to `from_sql_text` and `from_sql_text_null` depending on the value of
+    /// `raw`.
+    fn from_sql_text_nullable(raw: Option<&'a str>) -> Result<Self, Box<dyn Error + Sync + Send>> {
+        match raw {
+            Some(raw) => Self::from_sql_text(raw),
+            None => Self::from_sql_text_null(),
+        }
+    }
+}
+
+/// A trait for types which can be created from the text representation of a Postgres value without borrowing any
+/// data.
+///
+/// This is primarily useful for trait bounds on functions.
+pub trait FromSqlTextOwned: for<'a> FromSqlText<'a> {}
+
+impl<T> FromSqlTextOwned for T where T: for<'a> FromSqlText<'a> {}
+
+impl<'a, T: FromSqlText<'a>> FromSqlText<'a> for Option<T> {
+    const ARRAY_DELIMITER: u8 = T::ARRAY_DELIMITER;
+
+    fn from_sql_text(raw: &'a str) -> Result<Option<T>, Box<dyn Error + Sync + Send>> {
+        T::from_sql_text(raw).map(Some)
+    }
+
+    fn from_sql_text_null() -> Result<Option<T>, Box<dyn Error + Sync + Send>> {
+        Ok(None)
+    }
+}
+
+impl<'a, T: FromSqlTextOwned> FromSqlText<'a> for Vec<T> {
+    fn from_sql_text(raw: &'a str) -> Result<Vec<T>, Box<dyn Error + Sync + Send>> {
+        array_from_text(raw, T::ARRAY_DELIMITER)?
+            .iter()
+            .map(|v| T::from_sql_text_nullable(v.as_deref()))
+            .collect()
+    }
+}
+
+#[cfg(feature = "array-impls")]
+impl<'a, T: FromSqlTextOwned, const N: usize> FromSqlText<'a> for [T; N] {
+    fn from_sql_text(raw: &'a str) -> Result<Self, Box<dyn Error + Sync + Send>> {
+        use std::convert::TryInto;
+
+        let values = Vec::<T>::from_sql_text(raw)?;
+        let len = values.len();
+        values.try_into().map_err(|_| {
+            format!(
+                "wrong number of elements in array (expected {}, got {})",
+                N, len
+            )
+            .into()
+        })
+    }
+}
+
+impl<'a, T: FromSqlTextOwned> FromSqlText<'a> for Box<[T]> {
+    fn from_sql_text(raw: &'a str) -> Result<Self, Box<dyn Error + Sync + Send>> {
+        Vec::<T>::from_sql_text(raw).map(Vec::into_boxed_slice)
+    }
+}
+
+impl<'a> FromSqlText<'a> for Vec<u8> {
+    fn from_sql_text(raw: &'a str) -> Result<Vec<u8>, Box<dyn Error + Sync + Send>> {
+        bytea_from_text(raw)
+    }
+}
+
+impl<'a> FromSqlText<'a> for [u8; 8] {
+    fn from_sql_text(raw: &'a str) -> Result<[u8; 8], Box<dyn Error + Sync + Send>> {
+        macaddr8_from_text(raw)
+    }
+}
+
+impl<'a> FromSqlText<'a> for &'a str {
+    fn from_sql_text(raw: &'a str) -> Result<&'a str, Box<dyn Error + Sync + Send>> {
+        Ok(raw)
+    }
+}
+
+impl<'a> FromSqlText<'a> for String {
+    fn from_sql_text(raw: &'a str) -> Result<String, Box<dyn Error + Sync + Send>> {
+        Ok(raw.to_string())
+    }
+}
+
+impl<'a> FromSqlText<'a> for Box<str> {
+    fn from_sql_text(raw: &'a str) -> Result<Box<str>, Box<dyn Error + Sync + Send>> {
+        Ok(raw.into())
+    }
+}
+
+impl<'a> FromSqlText<'a> for bool {
+    fn from_sql_text(raw: &'a str) -> Result<bool, Box<dyn Error + Sync + Send>> {
+        match raw {
+            "t" | "true" => Ok(true),
+            "f" | "false" => Ok(false),
+            _ => Err(format!("invalid boolean `{}`", raw).into()),
+        }
+    }
+}
+
+impl<'a> FromSqlText<'a> for i8 {
+    fn from_sql_text(raw: &'a str) -> Result<i8, Box<dyn Error + Sync + Send>> {
+        // "char" values are output as the raw byte, or as an octal escape if it is not ASCII
+        match raw.as_bytes() {
+            [] => Ok(0),
+            [b] => Ok(*b as i8),
+            [b'\\', octal @ ..] if octal.len() == 3 => u8::from_str_radix(&raw[1..], 8)
+                .map(|b| b as i8)
+                .map_err(Into::into),
+            _ => Err(format!("invalid \"char\" `{}`", raw).into()),
+        }
+    }
+}
+
+macro_rules! from_str_text {
+    ($($t:ty),+) => {
+        $(
+            impl<'a> FromSqlText<'a> for $t {
+                fn from_sql_text(raw: &'a str) -> Result<$t, Box<dyn Error + Sync + Send>> {
+                    raw.parse().map_err(Into::into)
+                }
+            }
+        )+
+    }
+}
+
+// floats parse `NaN`, `Infinity` and `-Infinity` as Postgres prints them
+from_str_text!(i16, i32, u32, i64, i128, f32, f64);
+
+impl<'a, S> FromSqlText<'a> for HashMap<String, Option<String>, S>
+where
+    S: Default + BuildHasher,
+{
+    fn from_sql_text(
+        raw: &'a str,
+    ) -> Result<HashMap<String, Option<String>, S>, Box<dyn Error + Sync + Send>> {
+        hstore_from_text(raw)
+    }
+}
+
+impl<'a> FromSqlText<'a> for SystemTime {
+    fn from_sql_text(raw: &'a str) -> Result<SystemTime, Box<dyn Error + Sync + Send>> {
+        let time = timestamp_from_text(raw)?;
+        let epoch = UNIX_EPOCH + Duration::from_secs(TIME_SEC_CONVERSION);
+
+        let negative = time < 0;
+        let time = time.unsigned_abs();
+
+        let secs = time / USEC_PER_SEC;
+        let nsec = (time % USEC_PER_SEC) * NSEC_PER_USEC;
+        let offset = Duration::new(secs, nsec as u32);
+
+        let time = if negative {
+            epoch - offset
+        } else {
+            epoch + offset
+        };
+
+        Ok(time)
+    }
+}
+
+impl<'a> FromSqlText<'a> for IpAddr {
+    fn from_sql_text(raw: &'a str) -> Result<IpAddr, Box<dyn Error + Sync + Send>> {
+        // the netmask is only printed if it does not cover the whole address
+        let addr = raw.split_once('/').map_or(raw, |(addr, _)| addr);
+        addr.parse().map_err(Into::into)
+    }
+}
+
+/// Parses the text representation of a one-dimensional array.
+pub(crate) fn array_from_text(
+    raw: &str,
+    delimiter: u8,
+) -> Result<Vec<Option<Cow<'_, str>>>, Box<dyn Error + Sync + Send>> {
+    // arrays with non-default lower bounds are prefixed with their dimensions, like `[0:1]={1,2}`
+    let body = match raw.strip_prefix('[') {
+        Some(_) => match raw.split_once('=') {
+            Some((dims, body)) if dims.matches('[').count() == 1 => body,
+            Some(_) => return Err("array contains too many dimensions".into()),
+            None => return Err(format!("invalid array `{}`", raw).into()),
+        },
+        None => raw,
+    };
+    // `int2vector` and `oidvector` are written as space separated values without braces
+    if !body.starts_with('{')
+        && body
+            .bytes()
+            .all(|b| b.is_ascii_digit() || b == b'-' || b == b' ')
+    {
+        return Ok(body
+            .split_ascii_whitespace()
+            .map(|v| Some(Cow::Borrowed(v)))
+            .collect());
+    }
+    let inner = body
+        .strip_prefix('{')
+        .and_then(|b| b.strip_suffix('}'))
+        .ok_or_else(|| format!("invalid array `{}`", raw))?;
+
+    let mut values = vec![];
+    if inner.is_empty() {
+        return Ok(values);
+    }
+
+    let bytes = inner.as_bytes();
+    let mut i = 0;
+    loop {
+        while i < bytes.len() && bytes[i].is_ascii_whitespace() {
+            i += 1;
+        }
+
+        let value = match bytes.get(i) {
+            Some(b'{') => return Err("array contains too many dimensions".into()),
+            Some(b'"') => {
+                let (value, end) = unquote(inner, i)?;
+                i = end;
+                Some(value)
+            }
+            _ => {
+                let start = i;
+                while i < bytes.len() && bytes[i] != delimiter {
+                    i += 1;
+                }
+                let value = inner[start..i].trim_end();
+                if value.eq_ignore_ascii_case("NULL") {
+                    None
+                } else if value.contains('\\') {
+                    Some(Cow::Owned(unescape(value)))
+                } else {
+                    Some(Cow::Borrowed(value))
+                }
+            }
+        };
+        values.push(value);
+
+        while i < bytes.len() && bytes[i].is_ascii_whitespace() {
+            i += 1;
+        }
+        match bytes.get(i) {
+            Some(b) if *b == delimiter => i += 1,
+            Some(_) => return Err(format!("invalid array `{}`", raw).into()),
+            None => break,
+        }
+    }
+
+    Ok(values)
+}
+
+/// Parses the text representation of an array with any number of dimensions, returning its dimensions and its values
+/// in row-major order.
+#[allow(clippy::type_complexity)]
+pub(crate) fn md_array_from_text(
+    raw: &str,
+    delimiter: u8,
+) -> Result<(Vec<Dimension>, Vec<Option<Cow<'_, str>>>), Box<dyn Error + Sync + Send>> {
+    let invalid = || -> Box<dyn Error + Sync + Send> { format!("invalid array `{}`", raw).into() };
+    let non_rectangular =
+        || -> Box<dyn Error + Sync + Send> { format!("array `{}` is not rectangular", raw).into() };
+
+    // arrays with non-default lower bounds are prefixed with their bounds, like `[0:1][1:2]={{1,2},{3,4}}`
+    let (bounds, body) = match raw.strip_prefix('[') {
+        Some(_) => {
+            let (bounds, body) = raw.split_once('=').ok_or_else(invalid)?;
+            let bounds = bounds
+                .strip_prefix('[')
+                .and_then(|b| b.strip_suffix(']'))
+                .ok_or_else(invalid)?
+                .split("][")
+                .map(|bound| {
+                    let (lower, upper) = bound.split_once(':')?;
+                    Some((lower.parse::<i32>().ok()?, upper.parse::<i32>().ok()?))
+                })
+                .collect::<Option<Vec<_>>>()
+                .ok_or_else(invalid)?;
+            (Some(bounds), body)
+        }
+        None => (None, raw),
+    };
+
+    let bytes = body.as_bytes();
+    let mut i = 0;
+    // the number of elements seen so far in each open level of braces
+    let mut counts: Vec<usize> = vec![];
+    // the length of each dimension, known once the first sub-array at that depth is closed
+    let mut lens: Vec<Option<usize>> = vec![];
+    // the depth at which values appear, which must be the same throughout the array
+    let mut leaf_depth = None;
+    let mut values = vec![];
+
+    loop {
+        while i < bytes.len() && bytes[i].is_ascii_whitespace() {
+            i += 1;
+        }
+
+        match bytes.get(i) {
+            Some(b'{') => {
+                if let Some(count) = counts.last_mut() {
+                    *count += 1;
+                }
+                counts.push(0);
+                if lens.len() < counts.len() {
+                    lens.push(None);
+                }
+                i += 1;
+                continue;
+            }
+            Some(b'}') if !counts.is_empty() => {
+                let depth = counts.len() - 1;
+                let count = counts.pop().unwrap();
+                match lens[depth] {
+                    Some(len) if len != count => return Err(non_rectangular()),
+                    _ => lens[depth] = Some(count),
+                }
+                i += 1;
+                if counts.is_empty() {
+                    break;
+                }
+            }
+            Some(_) if !counts.is_empty() => {
+                match leaf_depth {
+                    Some(depth) if depth != counts.len() => return Err(non_rectangular()),
+                    _ => leaf_depth = Some(counts.len()),
+                }
+                *counts.last_mut().unwrap() += 1;
+
+                let value = if bytes[i] == b'"' {
+                    let (value, end) = unquote(body, i)?;
+                    i = end;
+                    Some(value)
+                } else {
+                    let start = i;
+                    while i < bytes.len() && bytes[i] != delimiter && bytes[i] != b'}' {
+                        if bytes[i] == b'\\' {
+                            i += 1;
+                        }
+                        i += 1;
+                    }
+                    i = i.min(bytes.len());
+                    let value = body[start..i].trim_end();
+                    if value.eq_ignore_ascii_case("NULL") {
+                        None
+                    } else if value.contains('\\') {
+                        Some(Cow::Owned(unescape(value)))
+                    } else {
+                        Some(Cow::Borrowed(value))
+                    }
+                };
+                values.push(value);
+            }
+            _ => return Err(invalid()),
+        }
+
+        while i < bytes.len() && bytes[i].is_ascii_whitespace() {
+            i += 1;
+        }
+        match bytes.get(i) {
+            Some(b) if *b == delimiter => i += 1,
+            Some(b'}') => {}
+            _ => return Err(invalid()),
+        }
+    }
+
+    if !body[i..].trim().is_empty() {
+        return Err(invalid());
+    }
+
+    let lens = lens.into_iter().flatten().collect::<Vec<_>>();
+    let dimensions = match leaf_depth {
+        Some(depth) if depth != lens.len() => return Err(non_rectangular()),
+        Some(_) => lens
+            .iter()
+            .enumerate()
+            .map(|(i, &len)| {
+                let len: i32 = std::convert::TryFrom::try_from(len)
+                    .map_err(|_| "array too large".to_string())?;
+                let lower_bound = match &bounds {
+                    Some(bounds) => match bounds.get(i) {
+                        Some(&(lower, upper)) if upper.checked_sub(lower) == Some(len - 1) => lower,
+                        _ => return Err(format!("array `{}` does not match its bounds", raw)),
+                    },
+                    None => 1,
+                };
+                Ok(Dimension::new(len, lower_bound))
+            })
+            .collect::<Result<Vec<_>, _>>()?,
+        None => vec![],
+    };
+    if matches!(&bounds, Some(bounds) if bounds.len() != dimensions.len()) {
+        return Err(format!("array `{}` does not match its bounds", raw).into());
+    }
+
+    Ok((dimensions, values))
+}
+
+fn hstore_from_text<S>(
+    raw: &str,
+) -> Result<HashMap<String, Option<String>, S>, Box<dyn Error + Sync + Send>>
+where
+    S: Default + BuildHasher,
+{
+    let mut map = HashMap::default();
+    let bytes = raw.as_bytes();
+    let mut i = 0;
+
+    let skip_whitespace = |i: &mut usize| {
+        while *i < bytes.len() && bytes[*i].is_ascii_whitespace() {
+            *i += 1;
+        }
+    };
+
+    skip_whitespace(&mut i);
+    while i < bytes.len() {
+        if bytes[i] != b'"' {
+            return Err(format!("invalid hstore `{}`", raw).into());
+        }
+        let (key, end) = unquote(raw, i)?;
+        i = end;
+
+        skip_whitespace(&mut i);
+        if !raw[i..].starts_with("=>") {
+            return Err(format!("invalid hstore `{}`", raw).into());
+        }
+        i += 2;
+        skip_whitespace(&mut i);
+
+        let value = if raw[i..].starts_with("NULL") {
+            i += 4;
+            None
+        } else if bytes.get(i) == Some(&b'"') {
+            let (value, end) = unquote(raw, i)?;
+            i = end;
+            Some(value.into_owned())
+        } else {
+            return Err(format!("invalid hstore `{}`", raw).into());
+        };
+        map.insert(key.into_owned(), value);
+
+        skip_whitespace(&mut i);
+        match bytes.get(i) {
+            Some(b',') => {
+                i += 1;
+                skip_whitespace(&mut i);
+            }
+            Some(_) => return Err(format!("invalid hstore `{}`", raw).into()),
+            None => {}
+        }
+    }
+
+    Ok(map)
+}
+
+/// Reads the double quoted string starting at `start`, returning its contents and the index just past it.
+fn unquote(raw: &str, start: usize) -> Result<(Cow<'_, str>, usize), Box<dyn Error + Sync + Send>> {
+    let bytes = raw.as_bytes();
+    let mut escaped = false;
+    let mut i = start + 1;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\\' => {
+                escaped = true;
+                i += 2;
+            }
+            b'"' => {
+                let value = &raw[start + 1..i];
+                let value = if escaped {
+                    Cow::Owned(unescape(value))
+                } else {
+                    Cow::Borrowed(value)
+                };
+                return Ok((value, i + 1));
+            }
+            _ => i += 1,
+        }
+    }
+
+    Err(format!("unterminated quoted string in `{}`", raw).into())
+}
+
+fn unescape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => out.extend(chars.next()),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+/// Parses the text representation of a range such as `[1,5)` or `empty`.
+pub(crate) fn range_from_text(raw: &str) -> Result<Range<String>, Box<dyn Error + Sync + Send>> {
+    let raw = raw.trim();
+    if raw.eq_ignore_ascii_case("empty") {
+        return Ok(Range::Empty);
+    }
+
+    let invalid = || format!("invalid range `{}`", raw);
+    let lower_inclusive = match raw.as_bytes().first() {
+        Some(b'[') => true,
+        Some(b'(') => false,
+        _ => return Err(invalid().into()),
+    };
+    let (lower, end) = range_bound_from_text(raw, 1, b',').ok_or_else(invalid)?;
+    let (upper, end) = range_bound_from_text(raw, end + 1, b')').ok_or_else(invalid)?;
+    let upper_inclusive = match raw.as_bytes()[end..] {
+        [b']'] => true,
+        [b')'] => false,
+        _ => return Err(invalid().into()),
+    };
+
+    let bound = |value: Option<String>, inclusive| match value {
+        Some(value) if inclusive => Bound::Included(value),
+        Some(value) => Bound::Excluded(value),
+        None => Bound::Unbounded,
+    };
+    Ok(Range::Nonempty(
+        bound(lower, lower_inclusive),
+        bound(upper, upper_inclusive),
+    ))
+}
+
+// Reads a range bound starting at `start` up to the first unquoted `terminator` (or `]` for the upper bound),
+// returning the unescaped bound, or `None` if it is empty, and the index of the terminator.
+fn range_bound_from_text(
+    raw: &str,
+    start: usize,
+    terminator: u8,
+) -> Option<(Option<String>, usize)> {
+    let bytes = raw.as_bytes();
+    let mut value = vec![];
+    let mut quoted = false;
+    let mut was_quoted = false;
+    let mut i = start;
+    loop {
+        let b = *bytes.get(i)?;
+        match b {
+            b'\\' => {
+                value.push(*bytes.get(i + 1)?);
+                i += 2;
+                continue;
+            }
+            b'"' if quoted && bytes.get(i + 1) == Some(&b'"') => {
+                value.push(b'"');
+                i += 2;
+                continue;
+            }
+            b'"' => {
+                quoted = !quoted;
+                was_quoted = true;
+            }
+            _ if quoted => value.push(b),
+            b if b == terminator || terminator == b')' && b == b']' => break,
+            b => value.push(b),
+        }
+        i += 1;
+    }
+
+    let value = String::from_utf8(value).ok()?;
+    if value.is_empty() && !was_quoted {
+        Some((None, i))
+    } else {
+        Some((Some(value), i))
+    }
+}
+
+/// Parses the points of a geometric value such as `(1,2)`, `(3,4),(1,2)` or `[(1,2),(3,4)]`.
+pub(crate) fn points_from_text(raw: &str) -> Result<Vec<(f64, f64)>, Box<dyn Error + Sync + Send>> {
+    let coordinates = numbers_from_text(raw)?;
+    if coordinates.len() % 2 != 0 {
+        return Err(format!("invalid geometric value `{}`", raw).into());
+    }
+    Ok(coordinates.chunks(2).map(|c| (c[0], c[1])).collect())
+}
+
+/// Parses the numbers of a geometric value, ignoring its delimiters, such as `{1,-1,0}` or `<(1,2),3>`.
+pub(crate) fn numbers_from_text(raw: &str) -> Result<Vec<f64>, Box<dyn Error + Sync + Send>> {
+    raw.split(',')
+        .map(|c| {
+            c.trim_matches(|c: char| "()[]{}<> ".contains(c))
+                .parse::<f64>()
+        })
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|_| format!("invalid geometric value `{}`", raw).into())
+}
+
+/// Parses a `MACADDR8` such as `08:00:2b:01:02:03:04:05`.
+pub(crate) fn macaddr8_from_text(raw: &str) -> Result<[u8; 8], Box<dyn Error + Sync + Send>> {
+    let mut out = [0; 8];
+    let mut parts = raw.split(':');
+    for byte in &mut out {
+        *byte = parts
+            .next()
+            .filter(|part| part.len() == 2)
+            .and_then(|part| u8::from_str_radix(part, 16).ok())
+            .ok_or_else(|| format!("invalid macaddr8 `{}`", raw))?;
+    }
+    if parts.next().is_some() {
+        return Err(format!("invalid macaddr8 `{}`", raw).into());
+    }
+    Ok(out)
+}
+
+pub(crate) fn bytea_from_text(raw: &str) -> Result<Vec<u8>, Box<dyn Error + Sync + Send>> {
+    if let Some(hex) = raw.strip_prefix("\\x") {
+        if hex.len() % 2 != 0 {
+            return Err("invalid hexadecimal bytea".into());
+        }
+        return (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).map_err(Into::into))
+            .collect();
+    }
+
+    // the legacy escape format, with non-printable bytes as octal escapes
+    let bytes = raw.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\\' if bytes.get(i + 1) == Some(&b'\\') => {
+                out.push(b'\\');
+                i += 2;
+            }
+            b'\\' => {
+                let octal = raw.get(i + 1..i + 4).ok_or("invalid escaped bytea")?;
+                out.push(u8::from_str_radix(octal, 8)?);
+                i += 4;
+            }
+            b => {
+                out.push(b);
+                i += 1;
+            }
+        }
+    }
+    Ok(out)
+}
+
+#[cfg(any(
+    feature = "with-chrono-0_4",
+    feature = "with-jiff-0_1",
+    feature = "with-jiff-0_2",
+    feature = "with-time-0_2",
+    feature = "with-time-0_3"
+))]
+/// Parses a `DATE`, returning the number of days since 2000-01-01.
+pub(crate) fn date_from_text(raw: &str) -> Result<i32, Box<dyn Error + Sync + Send>> {
+    let (raw, bc) = match raw.strip_suffix(" BC") {
+        Some(raw) => (raw, true),
+        None => (raw, false),
+    };
+    let days = parse_date(raw, bc).ok_or_else(|| format!("invalid date `{}`", raw))?;
+    std::convert::TryFrom::try_from(days).map_err(|_| "value too large to decode".into())
+}
+
+#[cfg(any(
+    feature = "with-chrono-0_4",
+    feature = "with-jiff-0_1",
+    feature = "with-jiff-0_2",
+    feature = "with-time-0_2",
+    feature = "with-time-0_3"
+))]
+/// Parses a `TIME`, returning the number of microseconds since midnight.
+pub(crate) fn time_from_text(raw: &str) -> Result<i64, Box<dyn Error + Sync + Send>> {
+    parse_time(raw).ok_or_else(|| format!("invalid time `{}`", raw).into())
+}
+
+#[cfg(any(
+    feature = "with-chrono-0_4",
+    feature = "with-time-0_2",
+    feature = "with-time-0_3"
+))]
+/// Parses a `TIMETZ`, returning the number of microseconds since midnight and the offset in seconds east of UTC.
+pub(crate) fn timetz_from_text(raw: &str) -> Result<(i64, i32), Box<dyn Error + Sync + Send>> {
+    let parse = || {
+        let idx = raw.rfind(['+', '-'])?;
+        let time = parse_time(&raw[..idx])?;
+        let offset = parse_offset(&raw[idx..])?;
+        Some((time, std::convert::TryFrom::try_from(offset).ok()?))
+    };
+    parse().ok_or_else(|| format!("invalid timetz `{}`", raw).into())
+}
+
+/// Parses a `TIMESTAMP` or `TIMESTAMPTZ`, returning the number of microseconds since 2000-01-01 00:00:00 UTC.
+pub(crate) fn timestamp_from_text(raw: &str) -> Result<i64, Box<dyn Error + Sync + Send>> {
+    parse_timestamp(raw).ok_or_else(|| format!("invalid timestamp `{}`", raw).into())
+}
+
+fn parse_timestamp(raw: &str) -> Option<i64> {
+    let (raw, bc) = match raw.strip_suffix(" BC") {
+        Some(raw) => (raw, true),
+        None => (raw, false),
+    };
+    let (date, time) = raw.split_once(' ')?;
+    let (time, offset) = match time.find(['+', '-']) {
+        Some(idx) => (&time[..idx], parse_offset(&time[idx..])?),
+        None => (time, 0),
+    };
+
+    let days = parse_date(date, bc)?;
+    let micros = days
+        .checked_mul(86_400 * USEC_PER_SEC as i64)?
+        .checked_add(parse_time(time)?)?;
+    micros.checked_sub(offset * USEC_PER_SEC as i64)
+}
+
+fn parse_date(raw: &str, bc: bool) -> Option<i64> {
+    let mut parts = raw.splitn(3, '-');
+    let year = parse_digits(parts.next()?)?;
+    let month = parse_digits(parts.next()?)?;
+    let day = parse_digits(parts.next()?)?;
+
+    // there is no year 0, so 1 BC is year 0 of the proleptic Gregorian calendar
+    let year = if bc { 1 - year } else { year };
+    if !(1..=12).contains(&month) || day < 1 || day > days_in_month(year, month) {
+        return None;
+    }
+
+    Some(days_from_civil(year, month, day) - days_from_civil(2000, 1, 1))
+}
+
+fn parse_time(raw: &str) -> Option<i64> {
+    let mut parts = raw.splitn(3, ':');
+    let hour = parse_digits(parts.next()?)?;
+    let minute = parse_digits(parts.next()?)?;
+    let second = parts.next()?;
+    let (second, fraction) = second.split_once('.').unwrap_or((second, ""));
+    let second = parse_digits(second)?;
+
+    if fraction.len() > 6 || !fraction.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let micros = format!("{:0<6}", fraction).parse::<i64>().ok()?;
+
+    if hour > 24
+        || minute > 59
+        || second > 60
+        || (hour == 24 && (minute, second, micros) != (0, 0, 0))
+    {
+        return None;
+    }
+
+    Some(((hour * 60 + minute) * 60 + second) * USEC_PER_SEC as i64 + micros)
+}
+
+/// Parses a UTC offset such as `+05`, `-03:30` or `+00:19:32`, returning it in seconds.
+pub(crate) fn parse_offset(raw: &str) -> Option<i64> {
+    let (sign, raw) = match raw.as_bytes().first()? {
+        b'+' => (1, &raw[1..]),
+        b'-' => (-1, &raw[1..]),
+        _ => return None,
+    };
+
+    let mut seconds = 0;
+    let mut unit = 3600;
+    for part in raw.split(':') {
+        if unit == 0 || part.len() != 2 {
+            return None;
+        }
+        seconds += parse_digits(part)? * unit;
+        unit /= 60;
+    }
+
+    Some(sign * seconds)
+}
+
+fn parse_digits(raw: &str) -> Option<i64> {
+    if raw.is_empty() || !raw.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    raw.parse().ok()
+}
+
+fn days_in_month(year: i64, month: i64) -> i64 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+// The number of days since 1970-01-01 in the proleptic Gregorian calendar.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
@@ -0,0 +1,676 @@
+//! Full text search types.
+
+use bytes::BytesMut;
+use fallible_iterator::FallibleIterator;
+use gaussdb_protocol::types::{self, TsQueryItem};
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::{FromSql, FromSqlText, IsNull, ToSql, Type};
+
+const MAX_POSITION: u16 = (1 << 14) - 1;
+
+/// The weight of a lexeme position in a `TsVector`, or of a lexeme in a `TsQuery`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum TsWeight {
+    /// The `A` weight, the highest.
+    A,
+    /// The `B` weight.
+    B,
+    /// The `C` weight.
+    C,
+    /// The `D` weight, the lowest. This is the default, and is not shown in the text format of a `TsVector`.
+    #[default]
+    D,
+}
+
+impl TsWeight {
+    const ALL: [TsWeight; 4] = [TsWeight::A, TsWeight::B, TsWeight::C, TsWeight::D];
+
+    // The weight as represented by the server, from 3 for `A` to 0 for `D`.
+    fn code(self) -> u8 {
+        match self {
+            TsWeight::A => 3,
+            TsWeight::B => 2,
+            TsWeight::C => 1,
+            TsWeight::D => 0,
+        }
+    }
+
+    fn from_code(code: u8) -> TsWeight {
+        match code {
+            3 => TsWeight::A,
+            2 => TsWeight::B,
+            1 => TsWeight::C,
+            _ => TsWeight::D,
+        }
+    }
+
+    fn letter(self) -> char {
+        match self {
+            TsWeight::A => 'A',
+            TsWeight::B => 'B',
+            TsWeight::C => 'C',
+            TsWeight::D => 'D',
+        }
+    }
+
+    fn from_letter(c: char) -> Option<TsWeight> {
+        match c.to_ascii_uppercase() {
+            'A' => Some(TsWeight::A),
+            'B' => Some(TsWeight::B),
+            'C' => Some(TsWeight::C),
+            'D' => Some(TsWeight::D),
+            _ => None,
+        }
+    }
+}
+
+/// A position of a lexeme in a `TsVector`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TsPosition {
+    /// The position of the lexeme in the document, from 1 to 16383.
+    pub position: u16,
+    /// The weight of the position.
+    pub weight: TsWeight,
+}
+
+impl TsPosition {
+    /// Creates a new position.
+    pub fn new(position: u16, weight: TsWeight) -> TsPosition {
+        TsPosition { position, weight }
+    }
+}
+
+/// A lexeme of a `TsVector`, along with its positions.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TsLexeme {
+    /// The lexeme.
+    pub lexeme: String,
+    /// The positions of the lexeme, in increasing order.
+    pub positions: Vec<TsPosition>,
+}
+
+impl TsLexeme {
+    /// Creates a new lexeme.
+    pub fn new<S>(lexeme: S, positions: Vec<TsPosition>) -> TsLexeme
+    where
+        S: Into<String>,
+    {
+        TsLexeme {
+            lexeme: lexeme.into(),
+            positions,
+        }
+    }
+}
+
+/// Postgres `TSVECTOR` type.
+///
+/// The server stores the lexemes of a `TSVECTOR` in sorted order without duplicates, so values read from the database
+/// are always in that order. The positions of each lexeme must be in increasing order when a value is sent to the
+/// server.
+///
+/// `TsVector` implements `FromStr` and `Display` using the server's text format, e.g. `'a':1A 'cat':5 'fat':2B,4`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct TsVector {
+    /// The lexemes of the vector.
+    pub lexemes: Vec<TsLexeme>,
+}
+
+impl TsVector {
+    /// Creates a new vector from its lexemes.
+    pub fn new(lexemes: Vec<TsLexeme>) -> TsVector {
+        TsVector { lexemes }
+    }
+}
+
+/// Error parsing a `TsVector`.
+#[derive(Debug)]
+pub struct ParseTsVectorError(());
+
+impl fmt::Display for ParseTsVectorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("invalid tsvector value")
+    }
+}
+
+impl Error for ParseTsVectorError {}
+
+impl FromStr for TsVector {
+    type Err = ParseTsVectorError;
+
+    fn from_str(s: &str) -> Result<TsVector, ParseTsVectorError> {
+        let mut parser = Parser::new(s);
+        let mut lexemes = vec![];
+        loop {
+            parser.skip_whitespace();
+            if parser.is_done() {
+                break;
+            }
+
+            let lexeme = parser.lexeme(":").ok_or(ParseTsVectorError(()))?;
+            let mut positions = vec![];
+            if parser.eat(':') {
+                loop {
+                    let position = parser.number().ok_or(ParseTsVectorError(()))?;
+                    if position == 0 || position > MAX_POSITION {
+                        return Err(ParseTsVectorError(()));
+                    }
+                    let weight = match parser.peek().and_then(TsWeight::from_letter) {
+                        Some(weight) => {
+                            parser.next();
+                            weight
+                        }
+                        None => TsWeight::D,
+                    };
+                    positions.push(TsPosition::new(position, weight));
+                    if !parser.eat(',') {
+                        break;
+                    }
+                }
+            }
+            lexemes.push(TsLexeme::new(lexeme, positions));
+
+            match parser.peek() {
+                Some(c) if !c.is_whitespace() => return Err(ParseTsVectorError(())),
+                _ => {}
+            }
+        }
+
+        Ok(TsVector::new(lexemes))
+    }
+}
+
+impl fmt::Display for TsVector {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, lexeme) in self.lexemes.iter().enumerate() {
+            if i > 0 {
+                f.write_str(" ")?;
+            }
+            fmt_lexeme(f, &lexeme.lexeme)?;
+            for (j, position) in lexeme.positions.iter().enumerate() {
+                f.write_str(if j == 0 { ":" } else { "," })?;
+                write!(f, "{}", position.position)?;
+                if position.weight != TsWeight::D {
+                    write!(f, "{}", position.weight.letter())?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> FromSql<'a> for TsVector {
+    fn from_sql(_: &Type, raw: &'a [u8]) -> Result<TsVector, Box<dyn Error + Sync + Send>> {
+        let lexemes = types::tsvector_from_sql(raw)?
+            .map(|lexeme| {
+                let positions = lexeme
+                    .positions()
+                    .map(|p| TsPosition::new(p.position(), TsWeight::from_code(p.weight())))
+                    .collect();
+                Ok(TsLexeme::new(lexeme.lexeme(), positions))
+            })
+            .collect()?;
+        Ok(TsVector::new(lexemes))
+    }
+
+    accepts!(TS_VECTOR);
+}
+
+impl<'a> FromSqlText<'a> for TsVector {
+    fn from_sql_text(raw: &'a str) -> Result<TsVector, Box<dyn Error + Sync + Send>> {
+        raw.parse()
+            .map_err(|_| format!("invalid tsvector `{}`", raw).into())
+    }
+}
+
+impl ToSql for TsVector {
+    fn to_sql(&self, _: &Type, out: &mut BytesMut) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+        types::tsvector_to_sql(
+            self.lexemes.iter().map(|lexeme| {
+                let positions = lexeme
+                    .positions
+                    .iter()
+                    .map(|p| (p.position, p.weight.code()));
+                (&*lexeme.lexeme, positions)
+            }),
+            out,
+        )?;
+        Ok(IsNull::No)
+    }
+
+    accepts!(TS_VECTOR);
+
+    to_sql_checked!();
+}
+
+/// Postgres `TSQUERY` type.
+///
+/// A query is a tree of lexemes combined with the `!`, `<N>`, `&` and `|` operators, which bind in that order from
+/// tightest to loosest. The binary operators are left associative.
+///
+/// `TsQuery` implements `FromStr` and `Display` using the server's text format, e.g. `'fat':*A & !( 'rat' | 'cat' )`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum TsQuery {
+    /// An empty query, which matches nothing. It can only appear at the top level of a query.
+    ///
+    /// The server can return empty queries, but rejects them as parameters.
+    Empty,
+    /// A lexeme.
+    Lexeme {
+        /// The lexeme.
+        lexeme: String,
+        /// The weights the lexeme must have in a matching document, in order from `A` to `D`. An empty list matches
+        /// any weight.
+        weights: Vec<TsWeight>,
+        /// Whether the lexeme matches as a prefix.
+        prefix: bool,
+    },
+    /// The `!` operator.
+    Not(Box<TsQuery>),
+    /// The `&` operator.
+    And(Box<TsQuery>, Box<TsQuery>),
+    /// The `|` operator.
+    Or(Box<TsQuery>, Box<TsQuery>),
+    /// The `<N>` phrase operator, with the distance between its operands. `<->` is the same as `<1>`.
+    Phrase(Box<TsQuery>, Box<TsQuery>, u16),
+}
+
+impl TsQuery {
+    /// Creates a query matching a lexeme with any weight.
+    pub fn lexeme<S>(lexeme: S) -> TsQuery
+    where
+        S: Into<String>,
+    {
+        TsQuery::Lexeme {
+            lexeme: lexeme.into(),
+            weights: vec![],
+            prefix: false,
+        }
+    }
+
+    // Flattens the query into the server's prefix order.
+    fn items<'a>(
+        &'a self,
+        items: &mut Vec<TsQueryItem<'a>>,
+    ) -> Result<(), Box<dyn Error + Sync + Send>> {
+        let (item, right, left) = match self {
+            TsQuery::Empty => return Err("empty tsquery operand".into()),
+            TsQuery::Lexeme {
+                lexeme,
+                weights,
+                prefix,
+            } => {
+                items.push(TsQueryItem::Operand {
+                    lexeme,
+                    weights: weights.iter().fold(0, |acc, w| acc | 1 << w.code()),
+                    prefix: *prefix,
+                });
+                return Ok(());
+            }
+            TsQuery::Not(operand) => (TsQueryItem::Not, operand, None),
+            TsQuery::And(left, right) => (TsQueryItem::And, right, Some(left)),
+            TsQuery::Or(left, right) => (TsQueryItem::Or, right, Some(left)),
+            TsQuery::Phrase(left, right, distance) => {
+                (TsQueryItem::Phrase(*distance), right, Some(left))
+            }
+        };
+
+        items.push(item);
+        right.items(items)?;
+        if let Some(left) = left {
+            left.items(items)?;
+        }
+        Ok(())
+    }
+
+    fn from_items<'a, I>(items: &mut I) -> Result<TsQuery, Box<dyn Error + Sync + Send>>
+    where
+        I: Iterator<Item = TsQueryItem<'a>>,
+    {
+        let query = match items.next().ok_or("invalid tsquery: missing operand")? {
+            TsQueryItem::Operand {
+                lexeme,
+                weights,
+                prefix,
+            } => TsQuery::Lexeme {
+                lexeme: lexeme.to_string(),
+                weights: TsWeight::ALL
+                    .iter()
+                    .copied()
+                    .filter(|w| weights & (1 << w.code()) != 0)
+                    .collect(),
+                prefix,
+            },
+            TsQueryItem::Not => TsQuery::Not(Box::new(TsQuery::from_items(items)?)),
+            item => {
+                let right = Box::new(TsQuery::from_items(items)?);
+                let left = Box::new(TsQuery::from_items(items)?);
+                match item {
+                    TsQueryItem::And => TsQuery::And(left, right),
+                    TsQueryItem::Or => TsQuery::Or(left, right),
+                    TsQueryItem::Phrase(distance) => TsQuery::Phrase(left, right, distance),
+                    _ => unreachable!(),
+                }
+            }
+        };
+        Ok(query)
+    }
+
+    // The binding strength of the query's operator, as used by the server when printing queries.
+    fn priority(&self) -> u8 {
+        match self {
+            TsQuery::Empty | TsQuery::Lexeme { .. } => u8::MAX,
+            TsQuery::Not(_) => 4,
+            TsQuery::Phrase(..) => 3,
+            TsQuery::And(..) => 2,
+            TsQuery::Or(..) => 1,
+        }
+    }
+
+    fn fmt_infix(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        parent_priority: u8,
+        right_phrase: bool,
+    ) -> fmt::Result {
+        let priority = self.priority();
+        let parenthesize =
+            priority < parent_priority || (matches!(self, TsQuery::Phrase(..)) && right_phrase);
+
+        if parenthesize {
+            f.write_str("( ")?;
+        }
+        match self {
+            TsQuery::Empty => {}
+            TsQuery::Lexeme {
+                lexeme,
+                weights,
+                prefix,
+            } => {
+                fmt_lexeme(f, lexeme)?;
+                if *prefix || !weights.is_empty() {
+                    f.write_str(":")?;
+                    if *prefix {
+                        f.write_str("*")?;
+                    }
+                    for weight in TsWeight::ALL.iter().filter(|w| weights.contains(w)) {
+                        write!(f, "{}", weight.letter())?;
+                    }
+                }
+            }
+            TsQuery::Not(operand) => {
+                f.write_str("!")?;
+                operand.fmt_infix(f, priority, false)?;
+            }
+            TsQuery::And(left, right) => {
+                left.fmt_infix(f, priority, false)?;
+                f.write_str(" & ")?;
+                right.fmt_infix(f, priority, false)?;
+            }
+            TsQuery::Or(left, right) => {
+                left.fmt_infix(f, priority, false)?;
+                f.write_str(" | ")?;
+                right.fmt_infix(f, priority, false)?;
+            }
+            TsQuery::Phrase(left, right, distance) => {
+                left.fmt_infix(f, priority, false)?;
+                if *distance == 1 {
+                    f.write_str(" <-> ")?;
+                } else {
+                    write!(f, " <{}> ", distance)?;
+                }
+                right.fmt_infix(f, priority, true)?;
+            }
+        }
+        if parenthesize {
+            f.write_str(" )")?;
+        }
+        Ok(())
+    }
+}
+
+/// Error parsing a `TsQuery`.
+#[derive(Debug)]
+pub struct ParseTsQueryError(());
+
+impl fmt::Display for ParseTsQueryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("invalid tsquery value")
+    }
+}
+
+impl Error for ParseTsQueryError {}
+
+impl FromStr for TsQuery {
+    type Err = ParseTsQueryError;
+
+    fn from_str(s: &str) -> Result<TsQuery, ParseTsQueryError> {
+        let mut parser = Parser::new(s);
+        parser.skip_whitespace();
+        if parser.is_done() {
+            return Ok(TsQuery::Empty);
+        }
+
+        let query = parse_or(&mut parser).ok_or(ParseTsQueryError(()))?;
+        parser.skip_whitespace();
+        if !parser.is_done() {
+            return Err(ParseTsQueryError(()));
+        }
+        Ok(query)
+    }
+}
+
+fn parse_or(parser: &mut Parser<'_>) -> Option<TsQuery> {
+    let mut query = parse_and(parser)?;
+    while parser.eat_operator('|') {
+        query = TsQuery::Or(Box::new(query), Box::new(parse_and(parser)?));
+    }
+    Some(query)
+}
+
+fn parse_and(parser: &mut Parser<'_>) -> Option<TsQuery> {
+    let mut query = parse_phrase(parser)?;
+    while parser.eat_operator('&') {
+        query = TsQuery::And(Box::new(query), Box::new(parse_phrase(parser)?));
+    }
+    Some(query)
+}
+
+fn parse_phrase(parser: &mut Parser<'_>) -> Option<TsQuery> {
+    let mut query = parse_not(parser)?;
+    loop {
+        parser.skip_whitespace();
+        if !parser.eat('<') {
+            break;
+        }
+        let distance = if parser.eat('-') {
+            1
+        } else {
+            parser.number().filter(|d| *d <= MAX_POSITION + 1)?
+        };
+        if !parser.eat('>') {
+            return None;
+        }
+        query = TsQuery::Phrase(Box::new(query), Box::new(parse_not(parser)?), distance);
+    }
+    Some(query)
+}
+
+fn parse_not(parser: &mut Parser<'_>) -> Option<TsQuery> {
+    if parser.eat_operator('!') {
+        return Some(TsQuery::Not(Box::new(parse_not(parser)?)));
+    }
+    if parser.eat_operator('(') {
+        let query = parse_or(parser)?;
+        return if parser.eat_operator(')') {
+            Some(query)
+        } else {
+            None
+        };
+    }
+
+    parser.skip_whitespace();
+    let lexeme = parser.lexeme(":&|!()<")?;
+    let mut weights = vec![];
+    let mut prefix = false;
+    if parser.eat(':') {
+        while let Some(c) = parser.peek() {
+            if c == '*' {
+                prefix = true;
+            } else if let Some(weight) = TsWeight::from_letter(c) {
+                weights.push(weight);
+            } else {
+                break;
+            }
+            parser.next();
+        }
+        weights.sort();
+        weights.dedup();
+    }
+    Some(TsQuery::Lexeme {
+        lexeme,
+        weights,
+        prefix,
+    })
+}
+
+impl fmt::Display for TsQuery {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_infix(f, 0, false)
+    }
+}
+
+impl<'a> FromSql<'a> for TsQuery {
+    fn from_sql(_: &Type, raw: &'a [u8]) -> Result<TsQuery, Box<dyn Error + Sync + Send>> {
+        let items = types::tsquery_from_sql(raw)?.collect::<Vec<_>>()?;
+        if items.is_empty() {
+            return Ok(TsQuery::Empty);
+        }
+
+        let mut items = items.into_iter();
+        let query = TsQuery::from_items(&mut items)?;
+        if items.next().is_some() {
+            return Err("invalid tsquery: unused operands".into());
+        }
+        Ok(query)
+    }
+
+    accepts!(TSQUERY);
+}
+
+impl<'a> FromSqlText<'a> for TsQuery {
+    fn from_sql_text(raw: &'a str) -> Result<TsQuery, Box<dyn Error + Sync + Send>> {
+        raw.parse()
+            .map_err(|_| format!("invalid tsquery `{}`", raw).into())
+    }
+}
+
+impl ToSql for TsQuery {
+    fn to_sql(&self, _: &Type, out: &mut BytesMut) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+        let mut items = vec![];
+        if *self != TsQuery::Empty {
+            self.items(&mut items)?;
+        }
+        types::tsquery_to_sql(items, out)?;
+        Ok(IsNull::No)
+    }
+
+    accepts!(TSQUERY);
+
+    to_sql_checked!();
+}
+
+// Writes a lexeme in quotes, escaping quotes and backslashes.
+fn fmt_lexeme(f: &mut fmt::Formatter<'_>, lexeme: &str) -> fmt::Result {
+    f.write_str("'")?;
+    for c in lexeme.chars() {
+        match c {
+            '\'' => f.write_str("''")?,
+            '\\' => f.write_str("\\\\")?,
+            c => write!(f, "{}", c)?,
+        }
+    }
+    f.write_str("'")
+}
+
+struct Parser<'a> {
+    s: &'a str,
+    idx: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(s: &'a str) -> Parser<'a> {
+        Parser { s, idx: 0 }
+    }
+
+    fn is_done(&self) -> bool {
+        self.idx == self.s.len()
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.s[self.idx..].chars().next()
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.idx += c.len_utf8();
+        Some(c)
+    }
+
+    fn eat(&mut self, target: char) -> bool {
+        if self.peek() == Some(target) {
+            self.next();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn eat_operator(&mut self, target: char) -> bool {
+        self.skip_whitespace();
+        self.eat(target)
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.next();
+        }
+    }
+
+    fn number(&mut self) -> Option<u16> {
+        let start = self.idx;
+        while self.peek().is_some_and(|c| c.is_ascii_digit()) {
+            self.next();
+        }
+        self.s[start..self.idx].parse().ok()
+    }
+
+    // Parses a quoted lexeme, or an unquoted one ending at whitespace or one of `delimiters`.
+    fn lexeme(&mut self, delimiters: &str) -> Option<String> {
+        let mut lexeme = String::new();
+        if self.eat('\'') {
+            loop {
+                match self.next()? {
+                    '\'' if self.eat('\'') => lexeme.push('\''),
+                    '\'' => break,
+                    '\\' => lexeme.push(self.next()?),
+                    c => lexeme.push(c),
+                }
+            }
+        } else {
+            while let Some(c) = self.peek() {
+                if c.is_whitespace() || delimiters.contains(c) {
+                    break;
+                }
+                self.next();
+                lexeme.push(if c == '\\' { self.next()? } else { c });
+            }
+            if lexeme.is_empty() {
+                return None;
+            }
+        }
+        Some(lexeme)
+    }
+}
@@ -4,7 +4,8 @@ use std::convert::TryFrom;
 use std::error::Error;
 use time_02::{date, time, Date, Duration, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
 
-use crate::{FromSql, IsNull, ToSql, Type};
+use crate::text;
+use crate::{FromSql, FromSqlText, Interval, IntervalConversionError, IsNull, ToSql, Type};
 
 #[rustfmt::skip]
 const fn base() -> PrimitiveDateTime {
@@ -17,7 +18,14 @@ impl<'a> FromSql<'a> for PrimitiveDateTime {
         Ok(base() + Duration::microseconds(t))
     }
 
-    accepts!(TIMESTAMP);
+    accepts!(TIMESTAMP, SMALLDATETIME);
+}
+
+impl<'a> FromSqlText<'a> for PrimitiveDateTime {
+    fn from_sql_text(raw: &'a str) -> Result<PrimitiveDateTime, Box<dyn Error + Sync + Send>> {
+        let t = text::timestamp_from_text(raw)?;
+        Ok(base() + Duration::microseconds(t))
+    }
 }
 
 impl ToSql for PrimitiveDateTime {
@@ -30,7 +38,7 @@ impl ToSql for PrimitiveDateTime {
         Ok(IsNull::No)
     }
 
-    accepts!(TIMESTAMP);
+    accepts!(TIMESTAMP, SMALLDATETIME);
     to_sql_checked!();
 }
 
@@ -43,6 +51,13 @@ impl<'a> FromSql<'a> for OffsetDateTime {
     accepts!(TIMESTAMPTZ);
 }
 
+impl<'a> FromSqlText<'a> for OffsetDateTime {
+    fn from_sql_text(raw: &'a str) -> Result<OffsetDateTime, Box<dyn Error + Sync + Send>> {
+        let primitive = PrimitiveDateTime::from_sql_text(raw)?;
+        Ok(primitive.assume_utc())
+    }
+}
+
 impl ToSql for OffsetDateTime {
     fn to_sql(
         &self,
@@ -69,6 +84,13 @@ impl<'a> FromSql<'a> for Date {
     accepts!(DATE);
 }
 
+impl<'a> FromSqlText<'a> for Date {
+    fn from_sql_text(raw: &'a str) -> Result<Date, Box<dyn Error + Sync + Send>> {
+        let jd = text::date_from_text(raw)?;
+        Ok(base().date() + Duration::days(i64::from(jd)))
+    }
+}
+
 impl ToSql for Date {
     fn to_sql(&self, _: &Type, w: &mut BytesMut) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
         let jd = (*self - base().date()).whole_days();
@@ -93,6 +115,13 @@ impl<'a> FromSql<'a> for Time {
     accepts!(TIME);
 }
 
+impl<'a> FromSqlText<'a> for Time {
+    fn from_sql_text(raw: &'a str) -> Result<Time, Box<dyn Error + Sync + Send>> {
+        let usec = text::time_from_text(raw)?;
+        Ok(time!(00:00:00) + Duration::microseconds(usec))
+    }
+}
+
 impl ToSql for Time {
     fn to_sql(&self, _: &Type, w: &mut BytesMut) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
         let delta = *self - time!(00:00:00);
@@ -107,3 +136,63 @@ impl ToSql for Time {
     accepts!(TIME);
     to_sql_checked!();
 }
+
+impl<'a> FromSql<'a> for (Time, UtcOffset) {
+    fn from_sql(_: &Type, raw: &[u8]) -> Result<(Time, UtcOffset), Box<dyn Error + Sync + Send>> {
+        let (usec, offset) = types::timetz_from_sql(raw)?;
+        Ok(timetz(usec, offset))
+    }
+
+    accepts!(TIMETZ);
+}
+
+impl<'a> FromSqlText<'a> for (Time, UtcOffset) {
+    fn from_sql_text(raw: &'a str) -> Result<(Time, UtcOffset), Box<dyn Error + Sync + Send>> {
+        let (usec, offset) = text::timetz_from_text(raw)?;
+        Ok(timetz(usec, offset))
+    }
+}
+
+fn timetz(usec: i64, offset: i32) -> (Time, UtcOffset) {
+    (
+        time!(00:00:00) + Duration::microseconds(usec),
+        UtcOffset::seconds(offset),
+    )
+}
+
+impl ToSql for (Time, UtcOffset) {
+    fn to_sql(&self, _: &Type, w: &mut BytesMut) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+        let delta = self.0 - time!(00:00:00);
+        let time = match i64::try_from(delta.whole_microseconds()) {
+            Ok(time) => time,
+            Err(_) => return Err("value too large to transmit".into()),
+        };
+        types::timetz_to_sql(time, self.1.as_seconds(), w);
+        Ok(IsNull::No)
+    }
+
+    accepts!(TIMETZ);
+    to_sql_checked!();
+}
+
+impl TryFrom<Interval> for Duration {
+    type Error = IntervalConversionError;
+
+    fn try_from(interval: Interval) -> Result<Duration, IntervalConversionError> {
+        interval
+            .total_microseconds()
+            .and_then(|usecs| i64::try_from(usecs).ok())
+            .map(Duration::microseconds)
+            .ok_or(IntervalConversionError(()))
+    }
+}
+
+impl TryFrom<Duration> for Interval {
+    type Error = IntervalConversionError;
+
+    fn try_from(duration: Duration) -> Result<Interval, IntervalConversionError> {
+        i64::try_from(duration.whole_microseconds())
+            .map(|usecs| Interval::new(0, 0, usecs))
+            .map_err(|_| IntervalConversionError(()))
+    }
+}
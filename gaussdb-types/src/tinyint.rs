@@ -0,0 +1,58 @@
+//! The openGauss `TINYINT` type.
+
+use bytes::BytesMut;
+use gaussdb_protocol::types;
+use std::error::Error;
+use std::fmt;
+
+use crate::{FromSql, FromSqlText, IsNull, ToSql, Type};
+
+/// openGauss `TINYINT` (`INT1`) type, an unsigned 1-byte integer.
+///
+/// `u8` cannot implement `FromSql` and `ToSql` itself, since `Vec<u8>` and `&[u8]` already map to `BYTEA`, so this
+/// wrapper is used instead. It converts to and from `u8` with `From`, and `Vec<TinyInt>` maps to `TINYINT[]`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct TinyInt(pub u8);
+
+impl From<u8> for TinyInt {
+    fn from(v: u8) -> TinyInt {
+        TinyInt(v)
+    }
+}
+
+impl From<TinyInt> for u8 {
+    fn from(v: TinyInt) -> u8 {
+        v.0
+    }
+}
+
+impl fmt::Display for TinyInt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl<'a> FromSql<'a> for TinyInt {
+    fn from_sql(_: &Type, raw: &'a [u8]) -> Result<TinyInt, Box<dyn Error + Sync + Send>> {
+        types::int1_from_sql(raw).map(TinyInt)
+    }
+
+    accepts!(INT1);
+}
+
+impl<'a> FromSqlText<'a> for TinyInt {
+    fn from_sql_text(raw: &'a str) -> Result<TinyInt, Box<dyn Error + Sync + Send>> {
+        raw.parse().map(TinyInt).map_err(Into::into)
+    }
+}
+
+impl ToSql for TinyInt {
+    fn to_sql(&self, _: &Type, out: &mut BytesMut) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+        types::int1_to_sql(self.0, out);
+        Ok(IsNull::No)
+    }
+
+    accepts!(INT1);
+
+    to_sql_checked!();
+}
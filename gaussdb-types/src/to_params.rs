@@ -0,0 +1,181 @@
+//! Binding the fields of a struct as query parameters.
+
+use crate::ToSql;
+use std::collections::{BTreeMap, HashMap};
+use std::hash::BuildHasher;
+
+/// A trait for types whose fields can be bound as the parameters of a query.
+///
+/// The parameters are returned in a fixed order, matching [`param_names`](ToParams::param_names). This makes a value
+/// usable with positional queries, named queries and binary `COPY` alike.
+///
+/// # Derive
+///
+/// If the `derive` cargo feature is enabled, `ToParams` can be derived for structs with named fields. Each field is
+/// bound in declaration order under its name, which can be changed with `#[gaussdb(rename = "...")]` on the field or
+/// `#[gaussdb(rename_all = "...")]` on the struct. Fields marked `#[gaussdb(skip)]` are not bound. The generated code
+/// refers to this crate as `gaussdb_types`, which can be changed with `#[gaussdb(crate = "...")]` on the struct, for
+/// example to `gaussdb::types` when depending on `gaussdb` alone.
+///
+/// ```rust
+/// # #[cfg(feature = "derive")]
+/// use gaussdb_types::ToParams;
+///
+/// # #[cfg(feature = "derive")]
+/// #[derive(ToParams)]
+/// struct NewUser {
+///     name: String,
+///     #[gaussdb(rename = "mail")]
+///     email: Option<String>,
+///     #[gaussdb(skip)]
+///     password: String,
+/// }
+///
+/// # #[cfg(feature = "derive")]
+/// # fn main() {
+/// assert_eq!(NewUser::param_names(), &["name", "mail"]);
+/// assert_eq!(
+///     NewUser::insert_query("users"),
+///     "INSERT INTO users (name, mail) VALUES ($1, $2)",
+/// );
+/// # }
+/// # #[cfg(not(feature = "derive"))]
+/// # fn main() {}
+/// ```
+pub trait ToParams {
+    /// Returns the names of the parameters, in the order they are bound.
+    fn param_names() -> &'static [&'static str]
+    where
+        Self: Sized;
+
+    /// Returns the values of the parameters, in the order they are bound.
+    fn to_params(&self) -> Vec<&(dyn ToSql + Sync)>;
+
+    /// Returns the values of the parameters paired with their names.
+    ///
+    /// The result can be passed to the `*_named` query methods of a client.
+    fn to_named_params(&self) -> Vec<(&'static str, &(dyn ToSql + Sync))>
+    where
+        Self: Sized,
+    {
+        Self::param_names()
+            .iter()
+            .copied()
+            .zip(self.to_params())
+            .collect()
+    }
+
+    /// Returns the parameter names as a comma separated column list, quoting names where required.
+    fn columns() -> String
+    where
+        Self: Sized,
+    {
+        Self::param_names()
+            .iter()
+            .map(|name| quote_ident(name))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    /// Returns a comma separated list of positional placeholders, one for each parameter.
+    fn placeholders() -> String
+    where
+        Self: Sized,
+    {
+        (1..=Self::param_names().len())
+            .map(|i| format!("${}", i))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    /// Returns an `INSERT` statement binding the parameters into their columns of `table`.
+    ///
+    /// `table` is inserted into the statement verbatim.
+    fn insert_query(table: &str) -> String
+    where
+        Self: Sized,
+    {
+        format!(
+            "INSERT INTO {} ({}) VALUES ({})",
+            table,
+            Self::columns(),
+            Self::placeholders()
+        )
+    }
+
+    /// Returns a binary `COPY ... FROM STDIN` statement for the parameter columns of `table`.
+    ///
+    /// `table` is inserted into the statement verbatim.
+    fn copy_in_query(table: &str) -> String
+    where
+        Self: Sized,
+    {
+        format!("COPY {} ({}) FROM STDIN BINARY", table, Self::columns())
+    }
+}
+
+/// A trait for collections of parameter values keyed by name, such as maps.
+///
+/// [`named_params`](NamedParams::named_params) returns the values in a form which can be passed to the `*_named` query
+/// methods of a client. Structs deriving [`ToParams`] can use [`ToParams::to_named_params`] instead.
+///
+/// ```rust
+/// use gaussdb_types::NamedParams;
+/// use std::collections::HashMap;
+///
+/// let mut params = HashMap::new();
+/// params.insert("id", 1i32);
+/// params.insert("limit", 10i32);
+///
+/// let mut named = params.named_params();
+/// named.sort_by_key(|(name, _)| *name);
+/// assert_eq!(
+///     named.iter().map(|(name, _)| *name).collect::<Vec<_>>(),
+///     &["id", "limit"],
+/// );
+/// ```
+pub trait NamedParams {
+    /// Returns the values of the parameters paired with their names.
+    fn named_params(&self) -> Vec<(&str, &(dyn ToSql + Sync))>;
+}
+
+impl<K, V, S> NamedParams for HashMap<K, V, S>
+where
+    K: AsRef<str>,
+    V: ToSql + Sync,
+    S: BuildHasher,
+{
+    fn named_params(&self) -> Vec<(&str, &(dyn ToSql + Sync))> {
+        self.iter()
+            .map(|(name, value)| (name.as_ref(), value as &(dyn ToSql + Sync)))
+            .collect()
+    }
+}
+
+impl<K, V> NamedParams for BTreeMap<K, V>
+where
+    K: AsRef<str>,
+    V: ToSql + Sync,
+{
+    fn named_params(&self) -> Vec<(&str, &(dyn ToSql + Sync))> {
+        self.iter()
+            .map(|(name, value)| (name.as_ref(), value as &(dyn ToSql + Sync)))
+            .collect()
+    }
+}
+
+fn quote_ident(name: &str) -> String {
+    let plain = name
+        .bytes()
+        .next()
+        .is_some_and(|b| b.is_ascii_lowercase() || b == b'_')
+        && name
+            .bytes()
+            .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'_');
+
+    if plain {
+        name.to_string()
+    } else {
+        format!("\"{}\"", name.replace('"', "\"\""))
+    }
+}
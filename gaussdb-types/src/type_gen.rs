@@ -29,6 +29,11 @@ pub enum Inner {
     Cid,
     OidVector,
     PgDdlCommand,
+    Int16,
+    Raw,
+    RawArray,
+    Blob,
+    Clob,
     Json,
     Xml,
     XmlArray,
@@ -98,6 +103,7 @@ pub enum Inner {
     Interval,
     IntervalArray,
     NumericArray,
+    Int16Array,
     CstringArray,
     Timetz,
     TimetzArray,
@@ -134,6 +140,8 @@ pub enum Inner {
     UuidArray,
     TxidSnapshot,
     FdwHandler,
+    BlobArray,
+    ClobArray,
     PgLsn,
     PgLsnArray,
     TsmHandler,
@@ -166,6 +174,8 @@ pub enum Inner {
     DateRangeArray,
     Int8Range,
     Int8RangeArray,
+    Nvarchar2Array,
+    Nvarchar2,
     Jsonpath,
     JsonpathArray,
     Regnamespace,
@@ -192,12 +202,16 @@ pub enum Inner {
     Anycompatiblearray,
     Anycompatiblenonarray,
     AnycompatibleRange,
+    Int1,
+    Int1Array,
     Int4multiRangeArray,
     NummultiRangeArray,
     TsmultiRangeArray,
     TstzmultiRangeArray,
     DatemultiRangeArray,
     Int8multiRangeArray,
+    Smalldatetime,
+    SmalldatetimeArray,
     Other(Arc<Other>),
 }
 
@@ -220,6 +234,11 @@ impl Inner {
             29 => Some(Inner::Cid),
             30 => Some(Inner::OidVector),
             32 => Some(Inner::PgDdlCommand),
+            34 => Some(Inner::Int16),
+            86 => Some(Inner::Raw),
+            87 => Some(Inner::RawArray),
+            88 => Some(Inner::Blob),
+            90 => Some(Inner::Clob),
             114 => Some(Inner::Json),
             142 => Some(Inner::Xml),
             143 => Some(Inner::XmlArray),
@@ -289,6 +308,7 @@ impl Inner {
             1186 => Some(Inner::Interval),
             1187 => Some(Inner::IntervalArray),
             1231 => Some(Inner::NumericArray),
+            1234 => Some(Inner::Int16Array),
             1263 => Some(Inner::CstringArray),
             1266 => Some(Inner::Timetz),
             1270 => Some(Inner::TimetzArray),
@@ -325,6 +345,8 @@ impl Inner {
             2951 => Some(Inner::UuidArray),
             2970 => Some(Inner::TxidSnapshot),
             3115 => Some(Inner::FdwHandler),
+            3201 => Some(Inner::BlobArray),
+            3202 => Some(Inner::ClobArray),
             3220 => Some(Inner::PgLsn),
             3221 => Some(Inner::PgLsnArray),
             3310 => Some(Inner::TsmHandler),
@@ -357,6 +379,8 @@ impl Inner {
             3913 => Some(Inner::DateRangeArray),
             3926 => Some(Inner::Int8Range),
             3927 => Some(Inner::Int8RangeArray),
+            3968 => Some(Inner::Nvarchar2Array),
+            3969 => Some(Inner::Nvarchar2),
             4072 => Some(Inner::Jsonpath),
             4073 => Some(Inner::JsonpathArray),
             4089 => Some(Inner::Regnamespace),
@@ -383,12 +407,16 @@ impl Inner {
             5078 => Some(Inner::Anycompatiblearray),
             5079 => Some(Inner::Anycompatiblenonarray),
             5080 => Some(Inner::AnycompatibleRange),
+            5545 => Some(Inner::Int1),
+            5546 => Some(Inner::Int1Array),
             6150 => Some(Inner::Int4multiRangeArray),
             6151 => Some(Inner::NummultiRangeArray),
             6152 => Some(Inner::TsmultiRangeArray),
             6153 => Some(Inner::TstzmultiRangeArray),
             6155 => Some(Inner::DatemultiRangeArray),
             6157 => Some(Inner::Int8multiRangeArray),
+            9003 => Some(Inner::Smalldatetime),
+            9005 => Some(Inner::SmalldatetimeArray),
             _ => None,
         }
     }
@@ -411,6 +439,11 @@ impl Inner {
             Inner::Cid => 29,
             Inner::OidVector => 30,
             Inner::PgDdlCommand => 32,
+            Inner::Int16 => 34,
+            Inner::Raw => 86,
+            Inner::RawArray => 87,
+            Inner::Blob => 88,
+            Inner::Clob => 90,
             Inner::Json => 114,
             Inner::Xml => 142,
             Inner::XmlArray => 143,
@@ -480,6 +513,7 @@ impl Inner {
             Inner::Interval => 1186,
             Inner::IntervalArray => 1187,
             Inner::NumericArray => 1231,
+            Inner::Int16Array => 1234,
             Inner::CstringArray => 1263,
             Inner::Timetz => 1266,
             Inner::TimetzArray => 1270,
@@ -516,6 +550,8 @@ impl Inner {
             Inner::UuidArray => 2951,
             Inner::TxidSnapshot => 2970,
             Inner::FdwHandler => 3115,
+            Inner::BlobArray => 3201,
+            Inner::ClobArray => 3202,
             Inner::PgLsn => 3220,
             Inner::PgLsnArray => 3221,
             Inner::TsmHandler => 3310,
@@ -548,6 +584,8 @@ impl Inner {
             Inner::DateRangeArray => 3913,
             Inner::Int8Range => 3926,
             Inner::Int8RangeArray => 3927,
+            Inner::Nvarchar2Array => 3968,
+            Inner::Nvarchar2 => 3969,
             Inner::Jsonpath => 4072,
             Inner::JsonpathArray => 4073,
             Inner::Regnamespace => 4089,
@@ -574,12 +612,16 @@ impl Inner {
             Inner::Anycompatiblearray => 5078,
             Inner::Anycompatiblenonarray => 5079,
             Inner::AnycompatibleRange => 5080,
+            Inner::Int1 => 5545,
+            Inner::Int1Array => 5546,
             Inner::Int4multiRangeArray => 6150,
             Inner::NummultiRangeArray => 6151,
             Inner::TsmultiRangeArray => 6152,
             Inner::TstzmultiRangeArray => 6153,
             Inner::DatemultiRangeArray => 6155,
             Inner::Int8multiRangeArray => 6157,
+            Inner::Smalldatetime => 9003,
+            Inner::SmalldatetimeArray => 9005,
             Inner::Other(ref u) => u.oid,
         }
     }
@@ -602,6 +644,11 @@ impl Inner {
             Inner::Cid => &Kind::Simple,
             Inner::OidVector => &Kind::Array(Type(Inner::Oid)),
             Inner::PgDdlCommand => &Kind::Pseudo,
+            Inner::Int16 => &Kind::Simple,
+            Inner::Raw => &Kind::Simple,
+            Inner::RawArray => &Kind::Array(Type(Inner::Raw)),
+            Inner::Blob => &Kind::Simple,
+            Inner::Clob => &Kind::Simple,
             Inner::Json => &Kind::Simple,
             Inner::Xml => &Kind::Simple,
             Inner::XmlArray => &Kind::Array(Type(Inner::Xml)),
@@ -671,6 +718,7 @@ impl Inner {
             Inner::Interval => &Kind::Simple,
             Inner::IntervalArray => &Kind::Array(Type(Inner::Interval)),
             Inner::NumericArray => &Kind::Array(Type(Inner::Numeric)),
+            Inner::Int16Array => &Kind::Array(Type(Inner::Int16)),
             Inner::CstringArray => &Kind::Array(Type(Inner::Cstring)),
             Inner::Timetz => &Kind::Simple,
             Inner::TimetzArray => &Kind::Array(Type(Inner::Timetz)),
@@ -700,13 +748,15 @@ impl Inner {
             Inner::LanguageHandler => &Kind::Pseudo,
             Inner::Internal => &Kind::Pseudo,
             Inner::Anyelement => &Kind::Pseudo,
-            Inner::RecordArray => &Kind::Pseudo,
+            Inner::RecordArray => &Kind::Array(Type(Inner::Record)),
             Inner::Anynonarray => &Kind::Pseudo,
             Inner::TxidSnapshotArray => &Kind::Array(Type(Inner::TxidSnapshot)),
             Inner::Uuid => &Kind::Simple,
             Inner::UuidArray => &Kind::Array(Type(Inner::Uuid)),
             Inner::TxidSnapshot => &Kind::Simple,
             Inner::FdwHandler => &Kind::Pseudo,
+            Inner::BlobArray => &Kind::Array(Type(Inner::Blob)),
+            Inner::ClobArray => &Kind::Array(Type(Inner::Clob)),
             Inner::PgLsn => &Kind::Simple,
             Inner::PgLsnArray => &Kind::Array(Type(Inner::PgLsn)),
             Inner::TsmHandler => &Kind::Pseudo,
@@ -739,6 +789,8 @@ impl Inner {
             Inner::DateRangeArray => &Kind::Array(Type(Inner::DateRange)),
             Inner::Int8Range => &Kind::Range(Type(Inner::Int8)),
             Inner::Int8RangeArray => &Kind::Array(Type(Inner::Int8Range)),
+            Inner::Nvarchar2Array => &Kind::Array(Type(Inner::Nvarchar2)),
+            Inner::Nvarchar2 => &Kind::Simple,
             Inner::Jsonpath => &Kind::Simple,
             Inner::JsonpathArray => &Kind::Array(Type(Inner::Jsonpath)),
             Inner::Regnamespace => &Kind::Simple,
@@ -765,12 +817,16 @@ impl Inner {
             Inner::Anycompatiblearray => &Kind::Pseudo,
             Inner::Anycompatiblenonarray => &Kind::Pseudo,
             Inner::AnycompatibleRange => &Kind::Pseudo,
+            Inner::Int1 => &Kind::Simple,
+            Inner::Int1Array => &Kind::Array(Type(Inner::Int1)),
             Inner::Int4multiRangeArray => &Kind::Array(Type(Inner::Int4multiRange)),
             Inner::NummultiRangeArray => &Kind::Array(Type(Inner::NummultiRange)),
             Inner::TsmultiRangeArray => &Kind::Array(Type(Inner::TsmultiRange)),
             Inner::TstzmultiRangeArray => &Kind::Array(Type(Inner::TstzmultiRange)),
             Inner::DatemultiRangeArray => &Kind::Array(Type(Inner::DatemultiRange)),
             Inner::Int8multiRangeArray => &Kind::Array(Type(Inner::Int8multiRange)),
+            Inner::Smalldatetime => &Kind::Simple,
+            Inner::SmalldatetimeArray => &Kind::Array(Type(Inner::Smalldatetime)),
             Inner::Other(ref u) => &u.kind,
         }
     }
@@ -793,6 +849,11 @@ impl Inner {
             Inner::Cid => "cid",
             Inner::OidVector => "oidvector",
             Inner::PgDdlCommand => "pg_ddl_command",
+            Inner::Int16 => "int16",
+            Inner::Raw => "raw",
+            Inner::RawArray => "_raw",
+            Inner::Blob => "blob",
+            Inner::Clob => "clob",
             Inner::Json => "json",
             Inner::Xml => "xml",
             Inner::XmlArray => "_xml",
@@ -862,6 +923,7 @@ impl Inner {
             Inner::Interval => "interval",
             Inner::IntervalArray => "_interval",
             Inner::NumericArray => "_numeric",
+            Inner::Int16Array => "_int16",
             Inner::CstringArray => "_cstring",
             Inner::Timetz => "timetz",
             Inner::TimetzArray => "_timetz",
@@ -898,6 +960,8 @@ impl Inner {
             Inner::UuidArray => "_uuid",
             Inner::TxidSnapshot => "txid_snapshot",
             Inner::FdwHandler => "fdw_handler",
+            Inner::BlobArray => "_blob",
+            Inner::ClobArray => "_clob",
             Inner::PgLsn => "pg_lsn",
             Inner::PgLsnArray => "_pg_lsn",
             Inner::TsmHandler => "tsm_handler",
@@ -930,6 +994,8 @@ impl Inner {
             Inner::DateRangeArray => "_daterange",
             Inner::Int8Range => "int8range",
             Inner::Int8RangeArray => "_int8range",
+            Inner::Nvarchar2Array => "_nvarchar2",
+            Inner::Nvarchar2 => "nvarchar2",
             Inner::Jsonpath => "jsonpath",
             Inner::JsonpathArray => "_jsonpath",
             Inner::Regnamespace => "regnamespace",
@@ -956,12 +1022,16 @@ impl Inner {
             Inner::Anycompatiblearray => "anycompatiblearray",
             Inner::Anycompatiblenonarray => "anycompatiblenonarray",
             Inner::AnycompatibleRange => "anycompatiblerange",
+            Inner::Int1 => "int1",
+            Inner::Int1Array => "_int1",
             Inner::Int4multiRangeArray => "_int4multirange",
             Inner::NummultiRangeArray => "_nummultirange",
             Inner::TsmultiRangeArray => "_tsmultirange",
             Inner::TstzmultiRangeArray => "_tstzmultirange",
             Inner::DatemultiRangeArray => "_datemultirange",
             Inner::Int8multiRangeArray => "_int8multirange",
+            Inner::Smalldatetime => "smalldatetime",
+            Inner::SmalldatetimeArray => "_smalldatetime",
             Inner::Other(ref u) => &u.name,
         }
     }
@@ -1015,6 +1085,21 @@ impl Type {
     /// PG_DDL_COMMAND - internal type for passing CollectedCommand
     pub const PG_DDL_COMMAND: Type = Type(Inner::PgDdlCommand);
 
+    /// INT16 - ~38 digit integer, 16-byte storage
+    pub const INT16: Type = Type(Inner::Int16);
+
+    /// RAW - variable-length binary string, output as hexadecimal
+    pub const RAW: Type = Type(Inner::Raw);
+
+    /// RAW&#91;&#93;
+    pub const RAW_ARRAY: Type = Type(Inner::RawArray);
+
+    /// BLOB - binary large object
+    pub const BLOB: Type = Type(Inner::Blob);
+
+    /// CLOB - character large object
+    pub const CLOB: Type = Type(Inner::Clob);
+
     /// JSON - JSON stored as text
     pub const JSON: Type = Type(Inner::Json);
 
@@ -1222,6 +1307,9 @@ impl Type {
     /// NUMERIC&#91;&#93;
     pub const NUMERIC_ARRAY: Type = Type(Inner::NumericArray);
 
+    /// INT16&#91;&#93;
+    pub const INT16_ARRAY: Type = Type(Inner::Int16Array);
+
     /// CSTRING&#91;&#93;
     pub const CSTRING_ARRAY: Type = Type(Inner::CstringArray);
 
@@ -1330,6 +1418,12 @@ impl Type {
     /// FDW_HANDLER - pseudo-type for the result of an FDW handler function
     pub const FDW_HANDLER: Type = Type(Inner::FdwHandler);
 
+    /// BLOB&#91;&#93;
+    pub const BLOB_ARRAY: Type = Type(Inner::BlobArray);
+
+    /// CLOB&#91;&#93;
+    pub const CLOB_ARRAY: Type = Type(Inner::ClobArray);
+
     /// PG_LSN - PostgreSQL LSN datatype
     pub const PG_LSN: Type = Type(Inner::PgLsn);
 
@@ -1426,6 +1520,12 @@ impl Type {
     /// INT8RANGE&#91;&#93;
     pub const INT8_RANGE_ARRAY: Type = Type(Inner::Int8RangeArray);
 
+    /// NVARCHAR2&#91;&#93;
+    pub const NVARCHAR2_ARRAY: Type = Type(Inner::Nvarchar2Array);
+
+    /// NVARCHAR2 - varchar2&#40;length&#41; in characters, blank-padded
+    pub const NVARCHAR2: Type = Type(Inner::Nvarchar2);
+
     /// JSONPATH - JSON path
     pub const JSONPATH: Type = Type(Inner::Jsonpath);
 
@@ -1504,6 +1604,12 @@ impl Type {
     /// ANYCOMPATIBLERANGE - pseudo-type representing a range over a polymorphic common type
     pub const ANYCOMPATIBLE_RANGE: Type = Type(Inner::AnycompatibleRange);
 
+    /// INT1 - 0 to 255, 1-byte storage
+    pub const INT1: Type = Type(Inner::Int1);
+
+    /// INT1&#91;&#93;
+    pub const INT1_ARRAY: Type = Type(Inner::Int1Array);
+
     /// INT4MULTIRANGE&#91;&#93;
     pub const INT4MULTI_RANGE_ARRAY: Type = Type(Inner::Int4multiRangeArray);
 
@@ -1521,4 +1627,10 @@ impl Type {
 
     /// INT8MULTIRANGE&#91;&#93;
     pub const INT8MULTI_RANGE_ARRAY: Type = Type(Inner::Int8multiRangeArray);
+
+    /// SMALLDATETIME - date and time, rounded to the minute
+    pub const SMALLDATETIME: Type = Type(Inner::Smalldatetime);
+
+    /// SMALLDATETIME&#91;&#93;
+    pub const SMALLDATETIME_ARRAY: Type = Type(Inner::SmalldatetimeArray);
 }
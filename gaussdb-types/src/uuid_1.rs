@@ -3,7 +3,7 @@ use gaussdb_protocol::types;
 use std::error::Error;
 use uuid_1::Uuid;
 
-use crate::{FromSql, IsNull, ToSql, Type};
+use crate::{FromSql, FromSqlText, IsNull, ToSql, Type};
 
 impl<'a> FromSql<'a> for Uuid {
     fn from_sql(_: &Type, raw: &[u8]) -> Result<Uuid, Box<dyn Error + Sync + Send>> {
@@ -14,6 +14,12 @@ impl<'a> FromSql<'a> for Uuid {
     accepts!(UUID);
 }
 
+impl<'a> FromSqlText<'a> for Uuid {
+    fn from_sql_text(raw: &'a str) -> Result<Uuid, Box<dyn Error + Sync + Send>> {
+        Uuid::parse_str(raw).map_err(Into::into)
+    }
+}
+
 impl ToSql for Uuid {
     fn to_sql(&self, _: &Type, w: &mut BytesMut) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
         types::uuid_to_sql(*self.as_bytes(), w);
@@ -0,0 +1,593 @@
+//! A dynamically typed value.
+
+use fallible_iterator::FallibleIterator;
+use gaussdb_protocol::types;
+use gaussdb_protocol::Oid;
+use std::error::Error;
+use std::fmt::{self, Write};
+use std::net::IpAddr;
+use std::ops::Bound;
+
+use crate::private::{read_be_i32, read_value};
+use crate::{FromSql, Interval, Kind, Numeric, Record, Type};
+
+/// A value of any Postgres type, decoded without knowing its type ahead of time.
+///
+/// `Value` implements `FromSql` for every type, which makes it useful for running queries whose result columns are
+/// only known at runtime. Types without a dedicated variant are returned as [`Value::Unknown`] with their raw binary
+/// representation.
+///
+/// Date and time values are stored in Postgres's own representation, as offsets from 2000-01-01 00:00:00. The
+/// `Display` implementation formats every value the way the server does in its text format, so no information is lost
+/// when values are rendered as text.
+///
+/// If the `serde` cargo feature is enabled, `Value` implements `Serialize`. Numbers, strings, booleans, arrays and
+/// composites map to their natural serde counterparts, byte strings are serialized as bytes and all other values are
+/// serialized as their text representation.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    /// A `NULL` value.
+    Null,
+    /// A `BOOL`.
+    Bool(bool),
+    /// A `"char"`.
+    Char(i8),
+    /// A `SMALLINT`.
+    Int2(i16),
+    /// An `INT`.
+    Int4(i32),
+    /// A `BIGINT`.
+    Int8(i64),
+    /// An `OID`.
+    Oid(Oid),
+    /// A `REAL`.
+    Float4(f32),
+    /// A `DOUBLE PRECISION`.
+    Float8(f64),
+    /// A `NUMERIC`, in its text representation.
+    Numeric(String),
+    /// A `TEXT`, `VARCHAR`, `CHAR(n)`, `NAME`, `XML` or enum value.
+    Text(String),
+    /// A `BYTEA`.
+    Bytea(Vec<u8>),
+    /// A `DATE`, as the number of days since 2000-01-01.
+    ///
+    /// `infinity` and `-infinity` are represented by `i32::MAX` and `i32::MIN`.
+    Date(i32),
+    /// A `TIME`, as the number of microseconds since midnight.
+    Time(i64),
+    /// A `TIMESTAMP`, as the number of microseconds since 2000-01-01 00:00:00.
+    ///
+    /// `infinity` and `-infinity` are represented by `i64::MAX` and `i64::MIN`.
+    Timestamp(i64),
+    /// A `TIMESTAMP WITH TIME ZONE`, as the number of microseconds since 2000-01-01 00:00:00 UTC.
+    ///
+    /// `infinity` and `-infinity` are represented by `i64::MAX` and `i64::MIN`.
+    TimestampTz(i64),
+    /// An `INTERVAL`.
+    Interval {
+        /// The number of months.
+        months: i32,
+        /// The number of days.
+        days: i32,
+        /// The number of microseconds.
+        microseconds: i64,
+    },
+    /// A `JSON` or `JSONB` document, in its text representation.
+    Json(String),
+    /// A `UUID`.
+    Uuid([u8; 16]),
+    /// An `INET`.
+    Inet {
+        /// The host address.
+        addr: IpAddr,
+        /// The network mask length.
+        netmask: u8,
+    },
+    /// A `CIDR`.
+    Cidr {
+        /// The network address.
+        addr: IpAddr,
+        /// The network mask length.
+        netmask: u8,
+    },
+    /// A `MACADDR`.
+    MacAddr([u8; 6]),
+    /// An array.
+    ///
+    /// The elements of multi-dimensional arrays are themselves arrays.
+    Array(Vec<Value>),
+    /// A nonempty range.
+    Range {
+        /// The lower bound of the range.
+        lower: Bound<Box<Value>>,
+        /// The upper bound of the range.
+        upper: Bound<Box<Value>>,
+    },
+    /// An empty range.
+    EmptyRange,
+    /// A composite value, as its field names and values.
+    Composite(Vec<(String, Value)>),
+    /// A value of a type without a dedicated variant, in its binary representation.
+    Unknown {
+        /// The type of the value.
+        type_: Type,
+        /// The binary representation of the value.
+        raw: Vec<u8>,
+    },
+}
+
+impl Value {
+    /// Determines if the value is `NULL`.
+    pub fn is_null(&self) -> bool {
+        *self == Value::Null
+    }
+}
+
+impl<'a> FromSql<'a> for Value {
+    fn from_sql(ty: &Type, raw: &'a [u8]) -> Result<Value, Box<dyn Error + Sync + Send>> {
+        let value = match *ty {
+            Type::BOOL => Value::Bool(types::bool_from_sql(raw)?),
+            Type::CHAR => Value::Char(types::char_from_sql(raw)?),
+            Type::INT2 => Value::Int2(types::int2_from_sql(raw)?),
+            Type::INT4 => Value::Int4(types::int4_from_sql(raw)?),
+            Type::INT8 => Value::Int8(types::int8_from_sql(raw)?),
+            Type::OID => Value::Oid(types::oid_from_sql(raw)?),
+            Type::FLOAT4 => Value::Float4(types::float4_from_sql(raw)?),
+            Type::FLOAT8 => Value::Float8(types::float8_from_sql(raw)?),
+            Type::NUMERIC => Value::Numeric(Numeric::from_sql(ty, raw)?.to_string()),
+            Type::TEXT
+            | Type::VARCHAR
+            | Type::BPCHAR
+            | Type::NAME
+            | Type::UNKNOWN
+            | Type::XML
+            | Type::CLOB
+            | Type::NVARCHAR2 => Value::Text(types::text_from_sql(raw)?.to_string()),
+            Type::BYTEA | Type::RAW | Type::BLOB => {
+                Value::Bytea(types::bytea_from_sql(raw).to_vec())
+            }
+            Type::DATE => Value::Date(types::date_from_sql(raw)?),
+            Type::TIME => Value::Time(types::time_from_sql(raw)?),
+            Type::TIMESTAMP | Type::SMALLDATETIME => {
+                Value::Timestamp(types::timestamp_from_sql(raw)?)
+            }
+            Type::TIMESTAMPTZ => Value::TimestampTz(types::timestamp_from_sql(raw)?),
+            Type::INTERVAL => {
+                let interval = Interval::from_sql(ty, raw)?;
+                Value::Interval {
+                    months: interval.months,
+                    days: interval.days,
+                    microseconds: interval.microseconds,
+                }
+            }
+            Type::JSON => Value::Json(types::text_from_sql(raw)?.to_string()),
+            Type::JSONB => match raw.split_first() {
+                Some((1, json)) => Value::Json(types::text_from_sql(json)?.to_string()),
+                _ => return Err("unsupported JSONB encoding version".into()),
+            },
+            Type::UUID => Value::Uuid(types::uuid_from_sql(raw)?),
+            Type::INET => {
+                let inet = types::inet_from_sql(raw)?;
+                Value::Inet {
+                    addr: inet.addr(),
+                    netmask: inet.netmask(),
+                }
+            }
+            Type::CIDR => {
+                let inet = types::inet_from_sql(raw)?;
+                Value::Cidr {
+                    addr: inet.addr(),
+                    netmask: inet.netmask(),
+                }
+            }
+            Type::MACADDR => Value::MacAddr(types::macaddr_from_sql(raw)?),
+            Type::RECORD => record_from_sql(raw)?,
+            _ => match ty.kind() {
+                Kind::Enum(_) => Value::Text(types::text_from_sql(raw)?.to_string()),
+                Kind::Domain(inner) => Value::from_sql(inner, raw)?,
+                Kind::Array(member) => array_from_sql(member, raw)?,
+                Kind::Range(member) => range_from_sql(member, raw)?,
+                Kind::Composite(fields) => composite_from_sql(fields, raw)?,
+                _ => match ty.name() {
+                    "citext" => Value::Text(types::text_from_sql(raw)?.to_string()),
+                    "ltree" => Value::Text(types::ltree_from_sql(raw)?.to_string()),
+                    "lquery" => Value::Text(types::lquery_from_sql(raw)?.to_string()),
+                    "ltxtquery" => Value::Text(types::ltxtquery_from_sql(raw)?.to_string()),
+                    _ => Value::Unknown {
+                        type_: ty.clone(),
+                        raw: raw.to_vec(),
+                    },
+                },
+            },
+        };
+
+        Ok(value)
+    }
+
+    fn from_sql_null(_: &Type) -> Result<Value, Box<dyn Error + Sync + Send>> {
+        Ok(Value::Null)
+    }
+
+    fn accepts(_: &Type) -> bool {
+        true
+    }
+}
+
+fn array_from_sql(member: &Type, raw: &[u8]) -> Result<Value, Box<dyn Error + Sync + Send>> {
+    let array = types::array_from_sql(raw)?;
+    let dimensions = array
+        .dimensions()
+        .map(|d| Ok(d.len as usize))
+        .collect::<Vec<_>>()?;
+    let values = array
+        .values()
+        .map(|v| Value::from_sql_nullable(member, v))
+        .collect::<Vec<_>>()?;
+
+    if dimensions.is_empty() {
+        return Ok(Value::Array(vec![]));
+    }
+    if dimensions.iter().product::<usize>() != values.len() {
+        return Err("array dimensions do not match its length".into());
+    }
+
+    Ok(Value::Array(nest(values, &dimensions)))
+}
+
+fn nest(values: Vec<Value>, dimensions: &[usize]) -> Vec<Value> {
+    match dimensions {
+        [] | [_] => values,
+        [_, inner @ ..] => {
+            let size = inner.iter().product::<usize>();
+            let mut values = values.into_iter();
+            (0..values.len() / size.max(1))
+                .map(|_| Value::Array(nest(values.by_ref().take(size).collect(), inner)))
+                .collect()
+        }
+    }
+}
+
+fn range_from_sql(member: &Type, raw: &[u8]) -> Result<Value, Box<dyn Error + Sync + Send>> {
+    fn bound(
+        member: &Type,
+        bound: types::RangeBound<Option<&[u8]>>,
+    ) -> Result<Bound<Box<Value>>, Box<dyn Error + Sync + Send>> {
+        match bound {
+            types::RangeBound::Inclusive(v) => Ok(Bound::Included(Box::new(
+                Value::from_sql_nullable(member, v)?,
+            ))),
+            types::RangeBound::Exclusive(v) => Ok(Bound::Excluded(Box::new(
+                Value::from_sql_nullable(member, v)?,
+            ))),
+            types::RangeBound::Unbounded => Ok(Bound::Unbounded),
+        }
+    }
+
+    match types::range_from_sql(raw)? {
+        types::Range::Empty => Ok(Value::EmptyRange),
+        types::Range::Nonempty(lower, upper) => Ok(Value::Range {
+            lower: bound(member, lower)?,
+            upper: bound(member, upper)?,
+        }),
+    }
+}
+
+fn composite_from_sql(
+    fields: &[crate::Field],
+    mut raw: &[u8],
+) -> Result<Value, Box<dyn Error + Sync + Send>> {
+    let num_fields = read_be_i32(&mut raw)?;
+    if num_fields as usize != fields.len() {
+        return Err(format!("invalid field count: {} vs {}", num_fields, fields.len()).into());
+    }
+
+    let mut values = Vec::with_capacity(fields.len());
+    for field in fields {
+        let oid = read_be_i32(&mut raw)? as u32;
+        if oid != field.type_().oid() {
+            return Err(format!("field {} has an unexpected type", field.name()).into());
+        }
+        values.push((
+            field.name().to_string(),
+            read_value(field.type_(), &mut raw)?,
+        ));
+    }
+
+    Ok(Value::Composite(values))
+}
+
+// Anonymous records carry the types of their fields, and name them like the server does.
+fn record_from_sql(raw: &[u8]) -> Result<Value, Box<dyn Error + Sync + Send>> {
+    let record = Record::from_sql(&Type::RECORD, raw)?;
+    let values = (0..record.len())
+        .map(|i| Ok((format!("f{}", i + 1), record.try_get(i)?)))
+        .collect::<Result<_, Box<dyn Error + Sync + Send>>>()?;
+
+    Ok(Value::Composite(values))
+}
+
+const USEC_PER_SEC: i64 = 1_000_000;
+const USEC_PER_DAY: i64 = 86_400 * USEC_PER_SEC;
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Null => f.write_str("NULL"),
+            Value::Bool(v) => f.write_str(if *v { "t" } else { "f" }),
+            Value::Char(v) => match *v as u8 {
+                b @ 0x20..=0x7e => write!(f, "{}", b as char),
+                b => write!(f, "\\{:03o}", b),
+            },
+            Value::Int2(v) => write!(f, "{}", v),
+            Value::Int4(v) => write!(f, "{}", v),
+            Value::Int8(v) => write!(f, "{}", v),
+            Value::Oid(v) => write!(f, "{}", v),
+            Value::Float4(v) => fmt_float(f, f64::from(*v), v.to_string()),
+            Value::Float8(v) => fmt_float(f, *v, v.to_string()),
+            Value::Numeric(v) | Value::Text(v) | Value::Json(v) => f.write_str(v),
+            Value::Bytea(v) => fmt_hex(f, v),
+            Value::Date(v) => match *v {
+                i32::MAX => f.write_str("infinity"),
+                i32::MIN => f.write_str("-infinity"),
+                days => fmt_date(f, i64::from(days), false),
+            },
+            Value::Time(v) => fmt_time(f, *v),
+            Value::Timestamp(v) => fmt_timestamp(f, *v, ""),
+            Value::TimestampTz(v) => fmt_timestamp(f, *v, "+00"),
+            Value::Interval {
+                months,
+                days,
+                microseconds,
+            } => Interval::new(*months, *days, *microseconds).fmt(f),
+            Value::Uuid(v) => {
+                for (i, b) in v.iter().enumerate() {
+                    if let 4 | 6 | 8 | 10 = i {
+                        f.write_char('-')?;
+                    }
+                    write!(f, "{:02x}", b)?;
+                }
+                Ok(())
+            }
+            Value::Inet { addr, netmask } => {
+                let max = if addr.is_ipv4() { 32 } else { 128 };
+                if *netmask == max {
+                    write!(f, "{}", addr)
+                } else {
+                    write!(f, "{}/{}", addr, netmask)
+                }
+            }
+            Value::Cidr { addr, netmask } => write!(f, "{}/{}", addr, netmask),
+            Value::MacAddr(v) => write!(
+                f,
+                "{:02x}:{:02x}:{:02x}:{:02x}:{:02x}:{:02x}",
+                v[0], v[1], v[2], v[3], v[4], v[5]
+            ),
+            Value::Array(values) => {
+                f.write_char('{')?;
+                for (i, value) in values.iter().enumerate() {
+                    if i > 0 {
+                        f.write_char(',')?;
+                    }
+                    match value {
+                        Value::Array(_) => write!(f, "{}", value)?,
+                        value => fmt_element(f, value)?,
+                    }
+                }
+                f.write_char('}')
+            }
+            Value::Range { lower, upper } => {
+                match lower {
+                    Bound::Included(v) => {
+                        f.write_char('[')?;
+                        fmt_field(f, v, "()[],\"\\")?;
+                    }
+                    Bound::Excluded(v) => {
+                        f.write_char('(')?;
+                        fmt_field(f, v, "()[],\"\\")?;
+                    }
+                    Bound::Unbounded => f.write_char('(')?,
+                }
+                f.write_char(',')?;
+                match upper {
+                    Bound::Included(v) => {
+                        fmt_field(f, v, "()[],\"\\")?;
+                        f.write_char(']')
+                    }
+                    Bound::Excluded(v) => {
+                        fmt_field(f, v, "()[],\"\\")?;
+                        f.write_char(')')
+                    }
+                    Bound::Unbounded => f.write_char(')'),
+                }
+            }
+            Value::EmptyRange => f.write_str("empty"),
+            Value::Composite(fields) => {
+                f.write_char('(')?;
+                for (i, (_, value)) in fields.iter().enumerate() {
+                    if i > 0 {
+                        f.write_char(',')?;
+                    }
+                    fmt_field(f, value, "(),\"\\")?;
+                }
+                f.write_char(')')
+            }
+            Value::Unknown { raw, .. } => fmt_hex(f, raw),
+        }
+    }
+}
+
+fn fmt_float(f: &mut fmt::Formatter<'_>, v: f64, s: String) -> fmt::Result {
+    if v.is_nan() {
+        f.write_str("NaN")
+    } else if v.is_infinite() {
+        f.write_str(if v > 0. { "Infinity" } else { "-Infinity" })
+    } else {
+        f.write_str(&s)
+    }
+}
+
+fn fmt_hex(f: &mut fmt::Formatter<'_>, v: &[u8]) -> fmt::Result {
+    f.write_str("\\x")?;
+    for b in v {
+        write!(f, "{:02x}", b)?;
+    }
+    Ok(())
+}
+
+// Formats a date given as the number of days since 2000-01-01.
+fn fmt_date(f: &mut fmt::Formatter<'_>, days: i64, time: bool) -> fmt::Result {
+    let (year, month, day) = civil_from_days(days + 10_957);
+    if year > 0 {
+        write!(f, "{:04}-{:02}-{:02}", year, month, day)
+    } else if time {
+        // the era is written after the time of day
+        write!(f, "{:04}-{:02}-{:02}", 1 - year, month, day)
+    } else {
+        write!(f, "{:04}-{:02}-{:02} BC", 1 - year, month, day)
+    }
+}
+
+fn fmt_time(f: &mut fmt::Formatter<'_>, usecs: i64) -> fmt::Result {
+    let secs = usecs / USEC_PER_SEC;
+    write!(
+        f,
+        "{:02}:{:02}:{:02}",
+        secs / 3600,
+        secs / 60 % 60,
+        secs % 60
+    )?;
+    fmt_fraction(f, usecs % USEC_PER_SEC)
+}
+
+fn fmt_fraction(f: &mut fmt::Formatter<'_>, usecs: i64) -> fmt::Result {
+    if usecs == 0 {
+        return Ok(());
+    }
+    let fraction = format!("{:06}", usecs);
+    write!(f, ".{}", fraction.trim_end_matches('0'))
+}
+
+fn fmt_timestamp(f: &mut fmt::Formatter<'_>, usecs: i64, offset: &str) -> fmt::Result {
+    match usecs {
+        i64::MAX => f.write_str("infinity"),
+        i64::MIN => f.write_str("-infinity"),
+        usecs => {
+            let days = usecs.div_euclid(USEC_PER_DAY);
+            fmt_date(f, days, true)?;
+            f.write_char(' ')?;
+            fmt_time(f, usecs.rem_euclid(USEC_PER_DAY))?;
+            f.write_str(offset)?;
+            if civil_from_days(days + 10_957).0 <= 0 {
+                f.write_str(" BC")?;
+            }
+            Ok(())
+        }
+    }
+}
+
+// Formats an array element, quoting it if required.
+fn fmt_element(f: &mut fmt::Formatter<'_>, value: &Value) -> fmt::Result {
+    if value.is_null() {
+        return f.write_str("NULL");
+    }
+
+    let s = value.to_string();
+    let quote = s.is_empty()
+        || s.eq_ignore_ascii_case("NULL")
+        || s.bytes()
+            .any(|b| b"{}\",\\".contains(&b) || b.is_ascii_whitespace());
+    if !quote {
+        return f.write_str(&s);
+    }
+
+    f.write_char('"')?;
+    for c in s.chars() {
+        if c == '"' || c == '\\' {
+            f.write_char('\\')?;
+        }
+        f.write_char(c)?;
+    }
+    f.write_char('"')
+}
+
+// Formats a composite field or range bound, quoting it if required. `NULL`s are left empty.
+fn fmt_field(f: &mut fmt::Formatter<'_>, value: &Value, special: &str) -> fmt::Result {
+    if value.is_null() {
+        return Ok(());
+    }
+
+    let s = value.to_string();
+    let quote = s.is_empty()
+        || s.chars()
+            .any(|c| special.contains(c) || c.is_ascii_whitespace());
+    if !quote {
+        return f.write_str(&s);
+    }
+
+    f.write_char('"')?;
+    for c in s.chars() {
+        if c == '"' || c == '\\' {
+            f.write_char(c)?;
+        }
+        f.write_char(c)?;
+    }
+    f.write_char('"')
+}
+
+// Converts a number of days since 1970-01-01 to a year, month and day of the proleptic Gregorian calendar.
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+#[cfg(feature = "serde")]
+mod serialize {
+    use serde_1::ser::{Serialize, SerializeMap, SerializeSeq, Serializer};
+
+    use super::Value;
+
+    impl Serialize for Value {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            match self {
+                Value::Null => serializer.serialize_none(),
+                Value::Bool(v) => serializer.serialize_bool(*v),
+                Value::Int2(v) => serializer.serialize_i16(*v),
+                Value::Int4(v) => serializer.serialize_i32(*v),
+                Value::Int8(v) => serializer.serialize_i64(*v),
+                Value::Oid(v) => serializer.serialize_u32(*v),
+                Value::Float4(v) => serializer.serialize_f32(*v),
+                Value::Float8(v) => serializer.serialize_f64(*v),
+                Value::Text(v) | Value::Json(v) | Value::Numeric(v) => serializer.serialize_str(v),
+                Value::Bytea(v) | Value::Unknown { raw: v, .. } => serializer.serialize_bytes(v),
+                Value::Array(values) => {
+                    let mut seq = serializer.serialize_seq(Some(values.len()))?;
+                    for value in values {
+                        seq.serialize_element(value)?;
+                    }
+                    seq.end()
+                }
+                Value::Composite(fields) => {
+                    let mut map = serializer.serialize_map(Some(fields.len()))?;
+                    for (name, value) in fields {
+                        map.serialize_entry(name, value)?;
+                    }
+                    map.end()
+                }
+                value => serializer.collect_str(value),
+            }
+        }
+    }
+}
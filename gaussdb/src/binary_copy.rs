@@ -1,7 +1,7 @@
 //! Utilities for working with the PostgreSQL binary copy format.
 
 use crate::connection::ConnectionRef;
-use crate::types::{BorrowToSql, ToSql, Type};
+use crate::types::{BorrowToSql, ToParams, ToSql, Type};
 use crate::{CopyInWriter, CopyOutReader, Error};
 use fallible_iterator::FallibleIterator;
 use futures_util::StreamExt;
@@ -41,6 +41,22 @@ impl<'a> BinaryCopyInWriter<'a> {
         self.connection.block_on(self.sink.as_mut().write(values))
     }
 
+    /// Writes a single row from the parameters of a value.
+    ///
+    /// The writer's types must be in the order of `ToParams::param_names`, as in the statement returned by
+    /// `ToParams::copy_in_query`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of parameters does not match the number expected.
+    pub fn write_params<T>(&mut self, value: &T) -> Result<(), Error>
+    where
+        T: ?Sized + ToParams,
+    {
+        self.connection
+            .block_on(self.sink.as_mut().write_params(value))
+    }
+
     /// A maximally-flexible version of `write`.
     ///
     /// # Panics
@@ -1,29 +1,98 @@
 use crate::connection::Connection;
 use crate::{
-    CancelToken, Config, CopyInWriter, CopyOutReader, Notifications, RowIter, Statement,
-    ToStatement, Transaction, TransactionBuilder,
+    CancelToken, ChunkedRowIter, Config, CopyInWriter, CopyOutReader, CursorBuilder, Notifications,
+    RowIter, Statement, StatementCache, ToStatement, Transaction, TransactionBuilder,
 };
+use std::ops::{Deref, DerefMut};
 use std::task::Poll;
 use std::time::Duration;
 use tokio_gaussdb::tls::{MakeTlsConnect, TlsConnect};
 use tokio_gaussdb::types::{BorrowToSql, ToSql, Type};
-use tokio_gaussdb::{Error, Row, SimpleQueryMessage, Socket};
+use tokio_gaussdb::{Column, Error, FromRow, Row, SimpleQueryMessage, Socket};
 
 /// A synchronous PostgreSQL client.
 pub struct Client {
     connection: Connection,
-    client: tokio_gaussdb::Client,
+    client: AsyncClient,
+}
+
+// The asynchronous client is either owned outright, or checked out of a pool which owns its connection.
+enum AsyncClient {
+    Owned(tokio_gaussdb::Client),
+    #[cfg(feature = "pool")]
+    Pooled(gaussdb_pool::PooledClient),
+}
+
+impl Deref for AsyncClient {
+    type Target = tokio_gaussdb::Client;
+
+    fn deref(&self) -> &tokio_gaussdb::Client {
+        match self {
+            AsyncClient::Owned(client) => client,
+            #[cfg(feature = "pool")]
+            AsyncClient::Pooled(client) => client,
+        }
+    }
+}
+
+impl DerefMut for AsyncClient {
+    fn deref_mut(&mut self) -> &mut tokio_gaussdb::Client {
+        match self {
+            AsyncClient::Owned(client) => client,
+            #[cfg(feature = "pool")]
+            AsyncClient::Pooled(client) => client,
+        }
+    }
 }
 
 impl Drop for Client {
     fn drop(&mut self) {
+        // pooled clients are returned to their pool instead
+        #[cfg(feature = "pool")]
+        if let AsyncClient::Pooled(_) = self.client {
+            return;
+        }
+
         let _ = self.close_inner();
     }
 }
 
 impl Client {
     pub(crate) fn new(connection: Connection, client: tokio_gaussdb::Client) -> Client {
-        Client { connection, client }
+        Client {
+            connection,
+            client: AsyncClient::Owned(client),
+        }
+    }
+
+    #[cfg(feature = "pool")]
+    pub(crate) fn pooled(connection: Connection, client: gaussdb_pool::PooledClient) -> Client {
+        Client {
+            connection,
+            client: AsyncClient::Pooled(client),
+        }
+    }
+
+    #[cfg(feature = "pool")]
+    pub(crate) fn pooled_client(&self) -> &gaussdb_pool::PooledClient {
+        match &self.client {
+            AsyncClient::Pooled(client) => client,
+            AsyncClient::Owned(_) => panic!("client is not pooled"),
+        }
+    }
+
+    #[cfg(feature = "pool")]
+    pub(crate) fn prepare_typed_cached(
+        &mut self,
+        query: &str,
+        types: &[Type],
+    ) -> Result<Statement, Error> {
+        match &self.client {
+            AsyncClient::Pooled(client) => self
+                .connection
+                .block_on(client.prepare_typed_cached(query, types)),
+            AsyncClient::Owned(_) => panic!("client is not pooled"),
+        }
     }
 
     /// A convenience function which parses a configuration string into a `Config` and then connects to the database.
@@ -115,6 +184,39 @@ impl Client {
         self.connection.block_on(self.client.query(query, params))
     }
 
+    /// Like `query`, but requests the resulting values in the text format.
+    ///
+    /// Values of the returned rows are read with `Row::get_text` using the `FromSqlText` trait, which makes it
+    /// possible to retrieve values of types lacking a binary `FromSql` implementation.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use gaussdb::{Client, NoTls};
+    ///
+    /// # fn main() -> Result<(), gaussdb::Error> {
+    /// let mut client = Client::connect("host=localhost user=postgres", NoTls)?;
+    ///
+    /// for row in client.query_text("SELECT '1 day'::interval, $1::int4", &[&1i32])? {
+    ///     let interval: &str = row.get_text(0);
+    ///     let value: i32 = row.get_text(1);
+    ///     println!("{} {}", interval, value);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn query_text<T>(
+        &mut self,
+        query: &T,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Vec<Row>, Error>
+    where
+        T: ?Sized + ToStatement,
+    {
+        self.connection
+            .block_on(self.client.query_text(query, params))
+    }
+
     /// Executes a statement which returns a single row, returning it.
     ///
     /// Returns an error if the query does not return exactly one row.
@@ -192,6 +294,48 @@ impl Client {
             .block_on(self.client.query_opt(query, params))
     }
 
+    /// Like `query`, but converts each row into `R` with its `FromRow` implementation.
+    pub fn query_as<R, T>(
+        &mut self,
+        query: &T,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Vec<R>, Error>
+    where
+        R: FromRow,
+        T: ?Sized + ToStatement,
+    {
+        self.connection
+            .block_on(self.client.query_as(query, params))
+    }
+
+    /// Like `query_one`, but converts the row into `R` with its `FromRow` implementation.
+    pub fn query_one_as<R, T>(
+        &mut self,
+        query: &T,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<R, Error>
+    where
+        R: FromRow,
+        T: ?Sized + ToStatement,
+    {
+        self.connection
+            .block_on(self.client.query_one_as(query, params))
+    }
+
+    /// Like `query_opt`, but converts the row into `R` with its `FromRow` implementation.
+    pub fn query_opt_as<R, T>(
+        &mut self,
+        query: &T,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<R>, Error>
+    where
+        R: FromRow,
+        T: ?Sized + ToStatement,
+    {
+        self.connection
+            .block_on(self.client.query_opt_as(query, params))
+    }
+
     /// A maximally-flexible version of `query`.
     ///
     /// It takes an iterator of parameters rather than a slice, and returns an iterator of rows rather than collecting
@@ -257,6 +401,48 @@ impl Client {
         Ok(RowIter::new(self.connection.as_ref(), stream))
     }
 
+    /// Executes a statement, fetching the resulting rows `fetch_size` at a time.
+    ///
+    /// The statement is bound to an unnamed portal in an implicit transaction (or the current transaction, if there
+    /// is one), and further rows are only requested from the server once the previous batch has been consumed. This
+    /// keeps memory use bounded for queries returning very many rows. Dropping the iterator early closes the portal.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of parameters provided does not match the number expected.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use gaussdb::{Client, NoTls};
+    /// use fallible_iterator::FallibleIterator;
+    /// # fn main() -> Result<(), gaussdb::Error> {
+    /// # let mut client = Client::connect("host=localhost user=postgres", NoTls)?;
+    ///
+    /// let mut it = client.query_chunked("SELECT * FROM generate_series(1, 1000000)", &[], 1000)?;
+    ///
+    /// while let Some(row) = it.next()? {
+    ///     let n: i32 = row.get(0);
+    ///     println!("{}", n);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn query_chunked<T>(
+        &mut self,
+        query: &T,
+        params: &[&(dyn ToSql + Sync)],
+        fetch_size: i32,
+    ) -> Result<ChunkedRowIter<'_>, Error>
+    where
+        T: ?Sized + ToStatement,
+    {
+        let stream = self
+            .connection
+            .block_on(self.client.query_chunked(query, params, fetch_size))?;
+        Ok(ChunkedRowIter::new(self.connection.as_ref(), stream))
+    }
+
     /// Like `query`, but requires the types of query parameters to be explicitly specified.
     ///
     /// Compared to `query`, this method allows performing queries without three round trips (for
@@ -322,6 +508,75 @@ impl Client {
         Ok(RowIter::new(self.connection.as_ref(), stream))
     }
 
+    /// Like `query`, but with parameters specified by name rather than position.
+    ///
+    /// Parameters are written `:name` or `@name` in the query, and may appear more than once. See
+    /// `tokio_gaussdb::Client::query_named` for details.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use gaussdb::{Client, NoTls};
+    ///
+    /// # fn main() -> Result<(), gaussdb::Error> {
+    /// let mut client = Client::connect("host=localhost user=postgres", NoTls)?;
+    ///
+    /// let rows = client.query_named(
+    ///     "SELECT foo FROM bar WHERE baz = :baz AND qux > :min",
+    ///     &[("baz", &true), ("min", &10i32)],
+    /// )?;
+    /// for row in rows {
+    ///     let foo: i32 = row.get("foo");
+    ///     println!("foo: {}", foo);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn query_named(
+        &mut self,
+        query: &str,
+        params: &[(&str, &(dyn ToSql + Sync))],
+    ) -> Result<Vec<Row>, Error> {
+        self.connection
+            .block_on(self.client.query_named(query, params))
+    }
+
+    /// Like `query_one`, but with parameters specified by name rather than position.
+    ///
+    /// See `query_named` for details.
+    pub fn query_one_named(
+        &mut self,
+        query: &str,
+        params: &[(&str, &(dyn ToSql + Sync))],
+    ) -> Result<Row, Error> {
+        self.connection
+            .block_on(self.client.query_one_named(query, params))
+    }
+
+    /// Like `query_opt`, but with parameters specified by name rather than position.
+    ///
+    /// See `query_named` for details.
+    pub fn query_opt_named(
+        &mut self,
+        query: &str,
+        params: &[(&str, &(dyn ToSql + Sync))],
+    ) -> Result<Option<Row>, Error> {
+        self.connection
+            .block_on(self.client.query_opt_named(query, params))
+    }
+
+    /// Like `execute`, but with parameters specified by name rather than position.
+    ///
+    /// See `query_named` for details.
+    pub fn execute_named(
+        &mut self,
+        query: &str,
+        params: &[(&str, &(dyn ToSql + Sync))],
+    ) -> Result<u64, Error> {
+        self.connection
+            .block_on(self.client.execute_named(query, params))
+    }
+
     /// Creates a new prepared statement.
     ///
     /// Prepared statements can be executed repeatedly, and may contain query parameters (indicated by `$1`, `$2`, etc),
@@ -381,6 +636,32 @@ impl Client {
             .block_on(self.client.prepare_typed(query, types))
     }
 
+    /// Looks up whether each of the provided columns may contain `NULL` values in the system catalog.
+    ///
+    /// The result contains `Some(false)` for `NOT NULL` columns, `Some(true)` for nullable ones, and `None` for
+    /// columns which are not a plain reference to a table column.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use gaussdb::{Client, NoTls};
+    ///
+    /// # fn main() -> Result<(), gaussdb::Error> {
+    /// let mut client = Client::connect("host=localhost user=gaussdb", NoTls)?;
+    ///
+    /// let statement = client.prepare("SELECT id, name, 1 AS one FROM users")?;
+    /// let nullability = client.column_nullability(statement.columns())?;
+    /// for (column, nullable) in statement.columns().iter().zip(nullability) {
+    ///     println!("{}: {:?}", column.name(), nullable);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn column_nullability(&mut self, columns: &[Column]) -> Result<Vec<Option<bool>>, Error> {
+        self.connection
+            .block_on(self.client.column_nullability(columns))
+    }
+
     /// Executes a `COPY FROM STDIN` statement, returning the number of rows created.
     ///
     /// The `query` argument can either be a `Statement`, or a raw query string. The data in the provided reader is
@@ -539,6 +820,32 @@ impl Client {
         TransactionBuilder::new(self.connection.as_ref(), self.client.build_transaction())
     }
 
+    /// Returns a builder for a server-side cursor over the rows of a query.
+    ///
+    /// Outside of a transaction, the cursor must be declared `WITH HOLD` by enabling the builder's `hold` option.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use gaussdb::{Client, FetchDirection, NoTls};
+    ///
+    /// # fn main() -> Result<(), gaussdb::Error> {
+    /// let mut client = Client::connect("host=localhost user=postgres", NoTls)?;
+    ///
+    /// let mut cursor = client
+    ///     .build_cursor("SELECT * FROM generate_series(1, 100)")
+    ///     .scroll(true)
+    ///     .hold(true)
+    ///     .declare(&[])?;
+    /// let last = cursor.fetch_last()?;
+    /// let rows = cursor.fetch(FetchDirection::Backward(10))?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn build_cursor(&mut self, query: &str) -> CursorBuilder<'_> {
+        CursorBuilder::new(self.connection.as_ref(), self.client.build_cursor(query))
+    }
+
     /// Returns a structure providing access to asynchronous notifications.
     ///
     /// Use the `LISTEN` command to register this connection for notifications.
@@ -586,6 +893,78 @@ impl Client {
         CancelToken::new(self.client.cancel_token())
     }
 
+    /// Returns the default timeout applied to statements executed by this client.
+    ///
+    /// This is initialized from [`Config::statement_timeout`].
+    pub fn statement_timeout(&self) -> Option<Duration> {
+        self.client.statement_timeout()
+    }
+
+    /// Sets the default timeout applied to statements executed by this client, or removes it if `None`.
+    ///
+    /// When a statement does not complete within the timeout, the client sends a cancellation request to the server,
+    /// waits for the connection to finish processing the cancelled request, and returns an error for which
+    /// [`Error::is_timeout`] returns `true`.
+    pub fn set_statement_timeout(&mut self, statement_timeout: Option<Duration>) {
+        self.client.set_statement_timeout(statement_timeout);
+    }
+
+    /// Sets the TLS connector used to send cancellation requests when a statement times out.
+    ///
+    /// By default these requests are sent without TLS, which servers requiring it reject.
+    pub fn set_cancel_tls<T>(&mut self, tls: T)
+    where
+        T: MakeTlsConnect<Socket> + 'static + Send,
+        T::TlsConnect: Send,
+        T::Stream: Send,
+        <T::TlsConnect as TlsConnect<Socket>>::Future: Send,
+    {
+        self.client.set_cancel_tls(tls);
+    }
+
+    /// Returns the client's cache of statements prepared for query strings.
+    pub fn statement_cache(&self) -> &StatementCache {
+        self.client.statement_cache()
+    }
+
+    /// Sets the number of statements the client caches for query strings passed to methods like `query` and
+    /// `execute`, evicting statements if the cache holds more than `capacity`.
+    ///
+    /// This is initialized from [`Config::statement_cache_capacity`]. A capacity of 0 disables the cache.
+    pub fn set_statement_cache_capacity(&mut self, capacity: usize) {
+        self.client.set_statement_cache_capacity(capacity);
+    }
+
+    /// Runs `f` with the statement timeout temporarily set to `timeout`.
+    ///
+    /// The previous default timeout is restored once `f` returns.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use gaussdb::{Client, NoTls};
+    /// use std::time::Duration;
+    ///
+    /// # fn main() -> Result<(), gaussdb::Error> {
+    /// let mut client = Client::connect("host=localhost user=postgres", NoTls)?;
+    ///
+    /// let rows = client.with_timeout(Duration::from_secs(5), |client| {
+    ///     client.query("SELECT * FROM big_table", &[])
+    /// })?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_timeout<F, T>(&mut self, timeout: Duration, f: F) -> Result<T, Error>
+    where
+        F: FnOnce(&mut Client) -> Result<T, Error>,
+    {
+        let previous = self.client.statement_timeout();
+        self.client.set_statement_timeout(Some(timeout));
+        let result = f(self);
+        self.client.set_statement_timeout(previous);
+        result
+    }
+
     /// Clears the client's type information cache.
     ///
     /// When user-defined types are used in a query, the client loads their definitions from the database and caches
@@ -610,6 +989,15 @@ impl Client {
         self.close_inner()
     }
 
+    pub(crate) fn reprepare(
+        &mut self,
+        statement: &Statement,
+        query: &str,
+    ) -> Result<Statement, Error> {
+        self.connection
+            .block_on(self.client.__private_api_reprepare(statement, query))
+    }
+
     fn close_inner(&mut self) -> Result<(), Error> {
         self.client.__private_api_close();
 
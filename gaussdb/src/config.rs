@@ -335,6 +335,39 @@ impl Config {
         self.config.get_tcp_user_timeout()
     }
 
+    /// Sets the default timeout applied to statements executed by the client.
+    ///
+    /// If a statement does not complete in time, a cancellation request is sent to the server and the call returns an
+    /// error for which [`Error::is_timeout`] returns `true`. Defaults to no limit.
+    ///
+    /// [`Error::is_timeout`]: tokio_gaussdb::Error::is_timeout
+    pub fn statement_timeout(&mut self, statement_timeout: Duration) -> &mut Config {
+        self.config.statement_timeout(statement_timeout);
+        self
+    }
+
+    /// Gets the default statement timeout, if one has been set with the
+    /// `statement_timeout` method.
+    pub fn get_statement_timeout(&self) -> Option<&Duration> {
+        self.config.get_statement_timeout()
+    }
+
+    /// Sets the number of statements the client caches for query strings passed to methods like `query` and
+    /// `execute`.
+    ///
+    /// See [`StatementCache`] for details. Defaults to 0, which disables the cache.
+    ///
+    /// [`StatementCache`]: crate::StatementCache
+    pub fn statement_cache_capacity(&mut self, capacity: usize) -> &mut Config {
+        self.config.statement_cache_capacity(capacity);
+        self
+    }
+
+    /// Gets the statement cache capacity.
+    pub fn get_statement_cache_capacity(&self) -> usize {
+        self.config.get_statement_cache_capacity()
+    }
+
     /// Controls the use of TCP keepalive.
     ///
     /// This is ignored for Unix domain socket connections. Defaults to `true`.
@@ -522,6 +555,11 @@ impl Config {
             }).join().unwrap()
         })
     }
+
+    #[cfg(feature = "pool")]
+    pub(crate) fn into_inner(self) -> tokio_gaussdb::Config {
+        self.config
+    }
 }
 
 impl FromStr for Config {
@@ -1,4 +1,6 @@
 use crate::{Error, Notification};
+#[cfg(feature = "pool")]
+use futures_util::stream;
 use futures_util::{future, pin_mut, Stream};
 use std::collections::VecDeque;
 use std::future::Future;
@@ -12,7 +14,7 @@ use tokio_gaussdb::error::DbError;
 use tokio_gaussdb::AsyncMessage;
 
 pub struct Connection {
-    runtime: Runtime,
+    runtime: Arc<Runtime>,
     connection: Pin<Box<dyn Stream<Item = Result<AsyncMessage, Error>> + Send>>,
     notifications: VecDeque<Notification>,
     notice_callback: Arc<dyn Fn(DbError) + Sync + Send>,
@@ -29,13 +31,27 @@ impl Connection {
         T: AsyncRead + AsyncWrite + Unpin + 'static + Send,
     {
         Connection {
-            runtime,
+            runtime: Arc::new(runtime),
             connection: Box::pin(ConnectionStream { connection }),
             notifications: VecDeque::new(),
             notice_callback,
         }
     }
 
+    /// Creates a connection whose underlying `tokio_gaussdb::Connection` has been spawned onto `runtime`, as is the
+    /// case for pooled connections.
+    ///
+    /// The spawned task logs notices and drops notifications, so this never yields any.
+    #[cfg(feature = "pool")]
+    pub fn spawned(runtime: Arc<Runtime>) -> Connection {
+        Connection {
+            runtime,
+            connection: Box::pin(stream::pending()),
+            notifications: VecDeque::new(),
+            notice_callback: Arc::new(|_| {}),
+        }
+    }
+
     pub fn as_ref(&mut self) -> ConnectionRef<'_> {
         ConnectionRef { connection: self }
     }
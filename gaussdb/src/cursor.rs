@@ -0,0 +1,122 @@
+use crate::connection::ConnectionRef;
+use tokio_gaussdb::types::ToSql;
+use tokio_gaussdb::{Error, FetchDirection, Row};
+
+/// A builder for server-side cursors.
+pub struct CursorBuilder<'a> {
+    connection: ConnectionRef<'a>,
+    builder: tokio_gaussdb::CursorBuilder<'a>,
+}
+
+impl<'a> CursorBuilder<'a> {
+    pub(crate) fn new(
+        connection: ConnectionRef<'a>,
+        builder: tokio_gaussdb::CursorBuilder<'a>,
+    ) -> CursorBuilder<'a> {
+        CursorBuilder {
+            connection,
+            builder,
+        }
+    }
+
+    /// Sets whether the cursor can move backwards.
+    ///
+    /// If unset, the server decides based on the query.
+    pub fn scroll(mut self, scroll: bool) -> Self {
+        self.builder = self.builder.scroll(scroll);
+        self
+    }
+
+    /// Sets whether the cursor remains open after the transaction that declared it commits.
+    ///
+    /// Cursors declared outside of a transaction must be held.
+    pub fn hold(mut self, hold: bool) -> Self {
+        self.builder = self.builder.hold(hold);
+        self
+    }
+
+    /// Declares the cursor, binding the query's parameters.
+    pub fn declare(mut self, params: &[&(dyn ToSql + Sync)]) -> Result<Cursor<'a>, Error> {
+        let cursor = self.connection.block_on(self.builder.declare(params))?;
+        Ok(Cursor {
+            connection: self.connection,
+            cursor,
+        })
+    }
+}
+
+/// A server-side cursor, declared with `DECLARE ... CURSOR`.
+///
+/// Unless declared with `WITH HOLD`, a cursor only exists for the duration of the transaction in which it was
+/// declared. The cursor is closed when dropped.
+pub struct Cursor<'a> {
+    connection: ConnectionRef<'a>,
+    cursor: tokio_gaussdb::Cursor,
+}
+
+impl Cursor<'_> {
+    /// Returns the name of the cursor.
+    pub fn name(&self) -> &str {
+        self.cursor.name()
+    }
+
+    /// Fetches rows from the cursor, moving it to the last row fetched.
+    pub fn fetch(&mut self, direction: FetchDirection) -> Result<Vec<Row>, Error> {
+        self.connection.block_on(self.cursor.fetch(direction))
+    }
+
+    /// Fetches the next row.
+    pub fn fetch_next(&mut self) -> Result<Option<Row>, Error> {
+        self.connection.block_on(self.cursor.fetch_next())
+    }
+
+    /// Fetches the prior row.
+    pub fn fetch_prior(&mut self) -> Result<Option<Row>, Error> {
+        self.connection.block_on(self.cursor.fetch_prior())
+    }
+
+    /// Fetches the first row.
+    pub fn fetch_first(&mut self) -> Result<Option<Row>, Error> {
+        self.connection.block_on(self.cursor.fetch_first())
+    }
+
+    /// Fetches the last row.
+    pub fn fetch_last(&mut self) -> Result<Option<Row>, Error> {
+        self.connection.block_on(self.cursor.fetch_last())
+    }
+
+    /// Fetches the row at the given position, counting from the end if negative.
+    pub fn fetch_absolute(&mut self, position: i64) -> Result<Option<Row>, Error> {
+        self.connection
+            .block_on(self.cursor.fetch_absolute(position))
+    }
+
+    /// Fetches the row at the given offset from the current position.
+    pub fn fetch_relative(&mut self, offset: i64) -> Result<Option<Row>, Error> {
+        self.connection.block_on(self.cursor.fetch_relative(offset))
+    }
+
+    /// Fetches up to the given number of rows forwards.
+    pub fn fetch_forward(&mut self, count: i64) -> Result<Vec<Row>, Error> {
+        self.connection.block_on(self.cursor.fetch_forward(count))
+    }
+
+    /// Fetches up to the given number of rows backwards.
+    pub fn fetch_backward(&mut self, count: i64) -> Result<Vec<Row>, Error> {
+        self.connection.block_on(self.cursor.fetch_backward(count))
+    }
+
+    /// Moves the cursor without fetching any rows, as with `MOVE`.
+    ///
+    /// Returns the number of rows the cursor moved over.
+    pub fn seek(&mut self, direction: FetchDirection) -> Result<u64, Error> {
+        self.connection.block_on(self.cursor.seek(direction))
+    }
+
+    /// Closes the cursor.
+    ///
+    /// This is equivalent to `Cursor`'s `Drop` implementation, but provides any error encountered to the caller.
+    pub fn close(mut self) -> Result<(), Error> {
+        self.connection.block_on(self.cursor.close())
+    }
+}
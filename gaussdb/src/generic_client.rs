@@ -1,7 +1,7 @@
 use crate::types::{BorrowToSql, ToSql, Type};
 use crate::{
-    Client, CopyInWriter, CopyOutReader, Error, Row, RowIter, SimpleQueryMessage, Statement,
-    ToStatement, Transaction,
+    Client, CopyInWriter, CopyOutReader, Error, FromRow, Row, RowIter, SimpleQueryMessage,
+    Statement, ToStatement, Transaction,
 };
 
 mod private {
@@ -22,6 +22,15 @@ pub trait GenericClient: private::Sealed {
     where
         T: ?Sized + ToStatement;
 
+    /// Like `Client::query_text`.
+    fn query_text<T>(
+        &mut self,
+        query: &T,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Vec<Row>, Error>
+    where
+        T: ?Sized + ToStatement;
+
     /// Like `Client::query_one`.
     fn query_one<T>(&mut self, query: &T, params: &[&(dyn ToSql + Sync)]) -> Result<Row, Error>
     where
@@ -36,6 +45,32 @@ pub trait GenericClient: private::Sealed {
     where
         T: ?Sized + ToStatement;
 
+    /// Like `Client::query_as`.
+    fn query_as<R, T>(
+        &mut self,
+        query: &T,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Vec<R>, Error>
+    where
+        R: FromRow,
+        T: ?Sized + ToStatement;
+
+    /// Like `Client::query_one_as`.
+    fn query_one_as<R, T>(&mut self, query: &T, params: &[&(dyn ToSql + Sync)]) -> Result<R, Error>
+    where
+        R: FromRow,
+        T: ?Sized + ToStatement;
+
+    /// Like `Client::query_opt_as`.
+    fn query_opt_as<R, T>(
+        &mut self,
+        query: &T,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<R>, Error>
+    where
+        R: FromRow,
+        T: ?Sized + ToStatement;
+
     /// Like `Client::query_raw`.
     fn query_raw<T, P, I>(&mut self, query: &T, params: I) -> Result<RowIter<'_>, Error>
     where
@@ -57,6 +92,34 @@ pub trait GenericClient: private::Sealed {
         P: BorrowToSql,
         I: IntoIterator<Item = (P, Type)> + Sync + Send;
 
+    /// Like `Client::query_named`.
+    fn query_named(
+        &mut self,
+        query: &str,
+        params: &[(&str, &(dyn ToSql + Sync))],
+    ) -> Result<Vec<Row>, Error>;
+
+    /// Like `Client::query_one_named`.
+    fn query_one_named(
+        &mut self,
+        query: &str,
+        params: &[(&str, &(dyn ToSql + Sync))],
+    ) -> Result<Row, Error>;
+
+    /// Like `Client::query_opt_named`.
+    fn query_opt_named(
+        &mut self,
+        query: &str,
+        params: &[(&str, &(dyn ToSql + Sync))],
+    ) -> Result<Option<Row>, Error>;
+
+    /// Like `Client::execute_named`.
+    fn execute_named(
+        &mut self,
+        query: &str,
+        params: &[(&str, &(dyn ToSql + Sync))],
+    ) -> Result<u64, Error>;
+
     /// Like `Client::prepare`.
     fn prepare(&mut self, query: &str) -> Result<Statement, Error>;
 
@@ -100,6 +163,17 @@ impl GenericClient for Client {
         self.query(query, params)
     }
 
+    fn query_text<T>(
+        &mut self,
+        query: &T,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Vec<Row>, Error>
+    where
+        T: ?Sized + ToStatement,
+    {
+        self.query_text(query, params)
+    }
+
     fn query_one<T>(&mut self, query: &T, params: &[&(dyn ToSql + Sync)]) -> Result<Row, Error>
     where
         T: ?Sized + ToStatement,
@@ -118,6 +192,34 @@ impl GenericClient for Client {
         self.query_opt(query, params)
     }
 
+    fn query_as<R, T>(&mut self, query: &T, params: &[&(dyn ToSql + Sync)]) -> Result<Vec<R>, Error>
+    where
+        R: FromRow,
+        T: ?Sized + ToStatement,
+    {
+        self.query_as(query, params)
+    }
+
+    fn query_one_as<R, T>(&mut self, query: &T, params: &[&(dyn ToSql + Sync)]) -> Result<R, Error>
+    where
+        R: FromRow,
+        T: ?Sized + ToStatement,
+    {
+        self.query_one_as(query, params)
+    }
+
+    fn query_opt_as<R, T>(
+        &mut self,
+        query: &T,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<R>, Error>
+    where
+        R: FromRow,
+        T: ?Sized + ToStatement,
+    {
+        self.query_opt_as(query, params)
+    }
+
     fn query_raw<T, P, I>(&mut self, query: &T, params: I) -> Result<RowIter<'_>, Error>
     where
         T: ?Sized + ToStatement,
@@ -144,6 +246,38 @@ impl GenericClient for Client {
         self.query_typed_raw(statement, params)
     }
 
+    fn query_named(
+        &mut self,
+        query: &str,
+        params: &[(&str, &(dyn ToSql + Sync))],
+    ) -> Result<Vec<Row>, Error> {
+        self.query_named(query, params)
+    }
+
+    fn query_one_named(
+        &mut self,
+        query: &str,
+        params: &[(&str, &(dyn ToSql + Sync))],
+    ) -> Result<Row, Error> {
+        self.query_one_named(query, params)
+    }
+
+    fn query_opt_named(
+        &mut self,
+        query: &str,
+        params: &[(&str, &(dyn ToSql + Sync))],
+    ) -> Result<Option<Row>, Error> {
+        self.query_opt_named(query, params)
+    }
+
+    fn execute_named(
+        &mut self,
+        query: &str,
+        params: &[(&str, &(dyn ToSql + Sync))],
+    ) -> Result<u64, Error> {
+        self.execute_named(query, params)
+    }
+
     fn prepare(&mut self, query: &str) -> Result<Statement, Error> {
         self.prepare(query)
     }
@@ -196,6 +330,17 @@ impl GenericClient for Transaction<'_> {
         self.query(query, params)
     }
 
+    fn query_text<T>(
+        &mut self,
+        query: &T,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Vec<Row>, Error>
+    where
+        T: ?Sized + ToStatement,
+    {
+        self.query_text(query, params)
+    }
+
     fn query_one<T>(&mut self, query: &T, params: &[&(dyn ToSql + Sync)]) -> Result<Row, Error>
     where
         T: ?Sized + ToStatement,
@@ -214,6 +359,34 @@ impl GenericClient for Transaction<'_> {
         self.query_opt(query, params)
     }
 
+    fn query_as<R, T>(&mut self, query: &T, params: &[&(dyn ToSql + Sync)]) -> Result<Vec<R>, Error>
+    where
+        R: FromRow,
+        T: ?Sized + ToStatement,
+    {
+        self.query_as(query, params)
+    }
+
+    fn query_one_as<R, T>(&mut self, query: &T, params: &[&(dyn ToSql + Sync)]) -> Result<R, Error>
+    where
+        R: FromRow,
+        T: ?Sized + ToStatement,
+    {
+        self.query_one_as(query, params)
+    }
+
+    fn query_opt_as<R, T>(
+        &mut self,
+        query: &T,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<R>, Error>
+    where
+        R: FromRow,
+        T: ?Sized + ToStatement,
+    {
+        self.query_opt_as(query, params)
+    }
+
     fn query_raw<T, P, I>(&mut self, query: &T, params: I) -> Result<RowIter<'_>, Error>
     where
         T: ?Sized + ToStatement,
@@ -240,6 +413,38 @@ impl GenericClient for Transaction<'_> {
         self.query_typed_raw(statement, params)
     }
 
+    fn query_named(
+        &mut self,
+        query: &str,
+        params: &[(&str, &(dyn ToSql + Sync))],
+    ) -> Result<Vec<Row>, Error> {
+        self.query_named(query, params)
+    }
+
+    fn query_one_named(
+        &mut self,
+        query: &str,
+        params: &[(&str, &(dyn ToSql + Sync))],
+    ) -> Result<Row, Error> {
+        self.query_one_named(query, params)
+    }
+
+    fn query_opt_named(
+        &mut self,
+        query: &str,
+        params: &[(&str, &(dyn ToSql + Sync))],
+    ) -> Result<Option<Row>, Error> {
+        self.query_opt_named(query, params)
+    }
+
+    fn execute_named(
+        &mut self,
+        query: &str,
+        params: &[(&str, &(dyn ToSql + Sync))],
+    ) -> Result<u64, Error> {
+        self.execute_named(query, params)
+    }
+
     fn prepare(&mut self, query: &str) -> Result<Statement, Error> {
         self.prepare(query)
     }
@@ -53,12 +53,22 @@
 //!
 //! | Feature | Description | Extra dependencies | Default |
 //! | ------- | ----------- | ------------------ | ------- |
+//! | `derive` | Enable the `FromRow` derive, and the `ToSql` and `FromSql` derives in the `types` module. | [gaussdb-derive](https://crates.io/crates/gaussdb-derive) 0.1 | no |
+//! | `pool` | Enable the blocking connection pool in the `pool` module. | [gaussdb-pool](https://crates.io/crates/gaussdb-pool) 0.1 | no |
+//! | `serde` | Enable `Serialize` for the dynamically typed `types::Value`, and deserializing rows with `Row::deserialize`. | [serde](https://crates.io/crates/serde) 1.0 and [serde_json](https://crates.io/crates/serde_json) 1.0 | no |
+//! | `with-bigdecimal-0_4` | Enable support for the `bigdecimal` crate. | [bigdecimal](https://crates.io/crates/bigdecimal) 0.4 | no |
 //! | `with-bit-vec-0_6` | Enable support for the `bit-vec` crate. | [bit-vec](https://crates.io/crates/bit-vec) 0.6 | no |
+//! | `with-bytes-1` | Has no effect, since the `Bytes` and `BytesMut` types of the `bytes` crate are always supported. Kept for compatibility. | - | no |
 //! | `with-chrono-0_4` | Enable support for the `chrono` crate. | [chrono](https://crates.io/crates/chrono) 0.4 | no |
+//! | `with-chrono-tz-0_10` | Enable support for the `chrono-tz` crate. | [chrono-tz](https://crates.io/crates/chrono-tz) 0.10 | no |
 //! | `with-eui48-0_4` | Enable support for the 0.4 version of the `eui48` crate. This is deprecated and will be removed. | [eui48](https://crates.io/crates/eui48) 0.4 | no |
 //! | `with-eui48-1` | Enable support for the 1.0 version of the `eui48` crate. | [eui48](https://crates.io/crates/eui48) 1.0 | no |
 //! | `with-geo-types-0_6` | Enable support for the 0.6 version of the `geo-types` crate. | [geo-types](https://crates.io/crates/geo-types/0.6.0) 0.6 | no |
 //! | `with-geo-types-0_7` | Enable support for the 0.7 version of the `geo-types` crate. | [geo-types](https://crates.io/crates/geo-types/0.7.0) 0.7 | no |
+//! | `with-ipnetwork-0_20` | Enable support for the `ipnetwork` crate. | [ipnetwork](https://crates.io/crates/ipnetwork) 0.20 | no |
+//! | `with-ndarray-0_16` | Enable support for multidimensional arrays as the `ArrayD` type of the `ndarray` crate. | [ndarray](https://crates.io/crates/ndarray) 0.16 | no |
+//! | `with-num-bigint-0_4` | Enable support for the `BigInt` type of the `num-bigint` crate. | [num-bigint](https://crates.io/crates/num-bigint) 0.4 | no |
+//! | `with-rust_decimal-1` | Enable support for the `rust_decimal` crate. | [rust_decimal](https://crates.io/crates/rust_decimal) 1.23 | no |
 //! | `with-serde_json-1` | Enable support for the `serde_json` crate. | [serde_json](https://crates.io/crates/serde_json) 1.0 | no |
 //! | `with-uuid-0_8` | Enable support for the `uuid` crate. | [uuid](https://crates.io/crates/uuid) 0.8 | no |
 //! | `with-uuid-1` | Enable support for the `uuid` crate. | [uuid](https://crates.io/crates/uuid) 1.0 | no |
@@ -68,8 +78,8 @@
 
 pub use fallible_iterator;
 pub use tokio_gaussdb::{
-    error, row, tls, types, Column, IsolationLevel, Notification, Portal, SimpleQueryMessage,
-    Socket, Statement, ToStatement,
+    error, row, tls, types, Column, FetchDirection, IsolationLevel, Notification, Portal,
+    SimpleQueryMessage, Socket, Statement, StatementCache, ToStatement,
 };
 
 pub use crate::cancel_token::CancelToken;
@@ -77,14 +87,16 @@ pub use crate::client::*;
 pub use crate::config::Config;
 pub use crate::copy_in_writer::CopyInWriter;
 pub use crate::copy_out_reader::CopyOutReader;
+pub use crate::cursor::{Cursor, CursorBuilder};
 #[doc(no_inline)]
 pub use crate::error::Error;
 pub use crate::generic_client::GenericClient;
 #[doc(inline)]
 pub use crate::notifications::Notifications;
+pub use crate::reconnecting_client::ReconnectingClient;
 #[doc(no_inline)]
-pub use crate::row::{Row, SimpleQueryRow};
-pub use crate::row_iter::RowIter;
+pub use crate::row::{FromRow, Row, SimpleQueryRow};
+pub use crate::row_iter::{ChunkedRowIter, RowIter};
 #[doc(no_inline)]
 pub use crate::tls::NoTls;
 pub use crate::transaction::*;
@@ -97,9 +109,13 @@ pub mod config;
 mod connection;
 mod copy_in_writer;
 mod copy_out_reader;
+mod cursor;
 mod generic_client;
 mod lazy_pin;
 pub mod notifications;
+#[cfg(feature = "pool")]
+pub mod pool;
+mod reconnecting_client;
 mod row_iter;
 mod transaction;
 mod transaction_builder;
@@ -0,0 +1,179 @@
+//! A blocking connection pool.
+//!
+//! This module wraps the asynchronous pool of the `gaussdb-pool` crate in the same way [`Client`] wraps
+//! `tokio_gaussdb::Client`. The pool runs its connections and maintenance task on a small runtime of its own, and the
+//! clients it hands out block on that runtime. It requires the `pool` feature.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use gaussdb::pool::{Pool, PoolConfig};
+//! use gaussdb::NoTls;
+//! use std::thread;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let config = "host=localhost user=gaussdb".parse::<gaussdb::Config>()?;
+//! let pool = Pool::connect(PoolConfig::new().max_size(4), config, NoTls)?;
+//!
+//! let handles = (0..8i32)
+//!     .map(|i| {
+//!         let pool = pool.clone();
+//!         thread::spawn(move || {
+//!             let mut client = pool.get().unwrap();
+//!             let stmt = client.prepare_cached("SELECT $1::INT * 2").unwrap();
+//!             client.query_one(&stmt, &[&i]).unwrap().get::<_, i32>(0)
+//!         })
+//!     })
+//!     .collect::<Vec<_>>();
+//!
+//! for handle in handles {
+//!     println!("{}", handle.join().unwrap());
+//! }
+//! # Ok(())
+//! # }
+//! ```
+use crate::connection::Connection;
+use crate::{Client, Config, Error, Socket, Statement};
+use std::fmt;
+use std::ops::{Deref, DerefMut};
+use std::sync::Arc;
+use tokio::runtime::{self, Runtime};
+use tokio_gaussdb::tls::{MakeTlsConnect, TlsConnect};
+use tokio_gaussdb::types::Type;
+
+#[doc(inline)]
+pub use gaussdb_pool::{Metrics, PoolConfig, RecycleCheck, Status};
+
+/// An error returned when requesting a client from a pool.
+pub type PoolError = gaussdb_pool::Error;
+
+/// A blocking pool of database connections.
+///
+/// Cloning a pool creates a new handle to the same set of connections.
+#[derive(Clone)]
+pub struct Pool {
+    runtime: Arc<Runtime>,
+    pool: gaussdb_pool::Pool,
+}
+
+impl fmt::Debug for Pool {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.pool, f)
+    }
+}
+
+impl Pool {
+    /// Creates a pool of connections to the database described by `config`, opening `min_size` connections up
+    /// front.
+    ///
+    /// The pool's connections and its maintenance task, which closes expired idle connections and opens new ones to
+    /// maintain `min_size`, run on a background thread until every handle to the pool and every client checked out of
+    /// it has been dropped. The notice callback of `config` is not used; notices on pooled connections are logged.
+    pub fn connect<T>(pool_config: &PoolConfig, config: Config, tls: T) -> Result<Pool, PoolError>
+    where
+        T: MakeTlsConnect<Socket> + Clone + 'static + Sync + Send,
+        T::TlsConnect: Sync + Send,
+        T::Stream: Sync + Send,
+        <T::TlsConnect as TlsConnect<Socket>>::Future: Send,
+    {
+        let runtime = runtime::Builder::new_multi_thread()
+            .worker_threads(1)
+            .thread_name("gaussdb-pool")
+            .enable_all()
+            .build()
+            .expect("failed to build pool runtime");
+
+        let pool = runtime.block_on(pool_config.connect(config.into_inner(), tls))?;
+        Ok(Pool {
+            runtime: Arc::new(runtime),
+            pool,
+        })
+    }
+
+    /// Returns the pool's configuration.
+    pub fn config(&self) -> &PoolConfig {
+        self.pool.config()
+    }
+
+    /// Retrieves a client from the pool, opening a new connection if none are idle and the pool is not full.
+    ///
+    /// Blocks until a client is returned if the pool is full, failing with [`PoolError::Timeout`] once the acquire
+    /// timeout expires.
+    pub fn get(&self) -> Result<PooledClient, PoolError> {
+        let client = self.runtime.block_on(self.pool.get())?;
+        let connection = Connection::spawned(self.runtime.clone());
+        Ok(PooledClient {
+            client: Client::pooled(connection, client),
+        })
+    }
+
+    /// Returns the current state of the pool.
+    pub fn status(&self) -> Status {
+        self.pool.status()
+    }
+
+    /// Returns counters describing the activity of the pool.
+    pub fn metrics(&self) -> Metrics {
+        self.pool.metrics()
+    }
+
+    /// Closes the pool.
+    ///
+    /// Idle connections are closed immediately, and clients in use are closed when they are returned. Pending and
+    /// future requests for a client fail with [`PoolError::Closed`].
+    pub fn close(&self) {
+        self.pool.close();
+    }
+
+    /// Determines if the pool has been closed.
+    pub fn is_closed(&self) -> bool {
+        self.pool.is_closed()
+    }
+}
+
+/// A client checked out of a [`Pool`].
+///
+/// The client dereferences to a [`Client`], and is returned to the pool when dropped. Connections which have been
+/// closed or have outlived the pool's maximum lifetime are closed instead of being returned. Pooled clients do not
+/// receive notifications.
+pub struct PooledClient {
+    client: Client,
+}
+
+impl fmt::Debug for PooledClient {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.client.pooled_client(), f)
+    }
+}
+
+impl PooledClient {
+    /// Like [`Client::prepare`], but reuses the statement previously prepared for the same query on this
+    /// connection.
+    pub fn prepare_cached(&mut self, query: &str) -> Result<Statement, Error> {
+        self.prepare_typed_cached(query, &[])
+    }
+
+    /// Like [`Client::prepare_typed`], but reuses the statement previously prepared for the same query and
+    /// parameter types on this connection.
+    pub fn prepare_typed_cached(
+        &mut self,
+        query: &str,
+        types: &[Type],
+    ) -> Result<Statement, Error> {
+        self.client.prepare_typed_cached(query, types)
+    }
+}
+
+impl Deref for PooledClient {
+    type Target = Client;
+
+    fn deref(&self) -> &Client {
+        &self.client
+    }
+}
+
+impl DerefMut for PooledClient {
+    fn deref_mut(&mut self) -> &mut Client {
+        &mut self.client
+    }
+}
@@ -0,0 +1,345 @@
+use crate::{Client, Config, Statement, ToStatement, Transaction};
+use log::{debug, warn};
+use std::thread;
+use std::time::Duration;
+use tokio_gaussdb::error::Severity;
+use tokio_gaussdb::tls::{MakeTlsConnect, TlsConnect};
+use tokio_gaussdb::types::{ToSql, Type};
+use tokio_gaussdb::{Error, Row, SimpleQueryMessage, Socket};
+
+/// A prepared statement tracked so that it can be restored after reconnecting.
+struct TrackedStatement {
+    query: String,
+    statement: Statement,
+}
+
+/// A synchronous client which transparently reconnects after its connection is lost.
+///
+/// The client wraps a [`Client`] along with the [`Config`] and TLS implementation used to create it. When a request
+/// fails because the connection to the server was lost, the client is marked as broken and the error is returned to
+/// the caller. The next request opens a new connection, retrying with exponential backoff, and restores the state of
+/// the old session on it:
+///
+/// * statements prepared with [`prepare`] or [`prepare_typed`] are prepared again under the same name, so the
+///   `Statement`s returned earlier keep working;
+/// * session parameters set with [`set_parameter`] are set again.
+///
+/// Requests are never retried on a new connection, since there is no way to know whether the server executed them
+/// before the connection was lost. In particular, a [`Transaction`] borrows the client for its whole lifetime, so a
+/// transaction interrupted by a lost connection always fails rather than silently continuing on a new connection in
+/// autocommit mode. Transactions must be started with [`transaction`] rather than by executing `BEGIN` directly for
+/// this to hold.
+///
+/// [`prepare`]: ReconnectingClient::prepare
+/// [`prepare_typed`]: ReconnectingClient::prepare_typed
+/// [`set_parameter`]: ReconnectingClient::set_parameter
+/// [`transaction`]: ReconnectingClient::transaction
+///
+/// # Examples
+///
+/// ```no_run
+/// use gaussdb::{Config, NoTls, ReconnectingClient};
+///
+/// # fn main() -> Result<(), gaussdb::Error> {
+/// let config = "host=localhost user=postgres".parse::<Config>()?;
+/// let mut client = ReconnectingClient::connect(config, NoTls)?;
+///
+/// client.set_parameter("search_path", "app")?;
+/// let stmt = client.prepare("SELECT name FROM jobs WHERE id = $1")?;
+/// for id in 0..1000i32 {
+///     match client.query(&stmt, &[&id]) {
+///         Ok(rows) => println!("{} rows", rows.len()),
+///         // the next call will reconnect
+///         Err(e) => eprintln!("error processing job {}: {}", id, e),
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub struct ReconnectingClient<T> {
+    config: Config,
+    tls: T,
+    client: Client,
+    broken: bool,
+    unverified: bool,
+    statements: Vec<TrackedStatement>,
+    parameters: Vec<(String, String)>,
+    reconnect_attempts: u32,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+    health_check_timeout: Duration,
+}
+
+impl<T> ReconnectingClient<T>
+where
+    T: MakeTlsConnect<Socket> + Clone + 'static + Send,
+    T::TlsConnect: Send,
+    T::Stream: Send,
+    <T::TlsConnect as TlsConnect<Socket>>::Future: Send,
+{
+    /// Connects to the database described by `config`.
+    ///
+    /// The configuration and TLS implementation are retained to open new connections later on.
+    pub fn connect(config: Config, tls: T) -> Result<ReconnectingClient<T>, Error> {
+        let client = config.connect(tls.clone())?;
+        Ok(ReconnectingClient {
+            config,
+            tls,
+            client,
+            broken: false,
+            unverified: false,
+            statements: vec![],
+            parameters: vec![],
+            reconnect_attempts: 5,
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(10),
+            health_check_timeout: Duration::from_secs(5),
+        })
+    }
+
+    /// Sets the number of connection attempts made when reconnecting before giving up.
+    ///
+    /// Defaults to 5.
+    pub fn reconnect_attempts(&mut self, reconnect_attempts: u32) -> &mut ReconnectingClient<T> {
+        self.reconnect_attempts = reconnect_attempts.max(1);
+        self
+    }
+
+    /// Sets the delay between connection attempts when reconnecting.
+    ///
+    /// The delay starts at `initial` and doubles after each failed attempt, up to `max`. Defaults to 100 milliseconds
+    /// and 10 seconds.
+    pub fn reconnect_backoff(
+        &mut self,
+        initial: Duration,
+        max: Duration,
+    ) -> &mut ReconnectingClient<T> {
+        self.initial_backoff = initial;
+        self.max_backoff = max;
+        self
+    }
+
+    /// Sets the timeout of the health check run after a request fails with an error which may have been caused by
+    /// a lost connection.
+    ///
+    /// Defaults to 5 seconds.
+    pub fn health_check_timeout(&mut self, timeout: Duration) -> &mut ReconnectingClient<T> {
+        self.health_check_timeout = timeout;
+        self
+    }
+
+    /// Returns the underlying client, reconnecting first if the connection has been lost.
+    ///
+    /// Errors returned by requests made directly on the client are not tracked, so the connection is checked before
+    /// the next request made through the `ReconnectingClient`.
+    pub fn client(&mut self) -> Result<&mut Client, Error> {
+        self.ensure_connected()?;
+        self.unverified = true;
+        Ok(&mut self.client)
+    }
+
+    /// Determines if the connection is known to have been lost.
+    ///
+    /// If this returns `true`, the next request will open a new connection.
+    pub fn is_broken(&self) -> bool {
+        self.broken || self.client.is_closed()
+    }
+
+    /// Checks that the connection to the server is still working, marking the client as broken if it is not.
+    pub fn is_valid(&mut self, timeout: Duration) -> Result<(), Error> {
+        let result = self.client.is_valid(timeout);
+        if result.is_err() {
+            self.broken = true;
+        }
+        result
+    }
+
+    /// Closes the current connection and opens a new one, restoring prepared statements and session parameters.
+    pub fn reconnect(&mut self) -> Result<(), Error> {
+        let mut backoff = self.initial_backoff;
+        let mut attempt = 1;
+        let client = loop {
+            match self.config.connect(self.tls.clone()) {
+                Ok(client) => break client,
+                Err(e) if attempt >= self.reconnect_attempts => return Err(e),
+                Err(e) => {
+                    warn!(
+                        "reconnect attempt {} of {} failed: {}",
+                        attempt, self.reconnect_attempts, e
+                    );
+                    thread::sleep(backoff);
+                    backoff = (backoff * 2).min(self.max_backoff);
+                    attempt += 1;
+                }
+            }
+        };
+
+        // the old connection is usually dead, so any error closing it is uninteresting
+        let _ = std::mem::replace(&mut self.client, client).close();
+        self.broken = true;
+        self.restore()?;
+        self.broken = false;
+        Ok(())
+    }
+
+    fn restore(&mut self) -> Result<(), Error> {
+        for (name, value) in &self.parameters {
+            debug!("restoring session parameter {}", name);
+            self.client
+                .execute("SELECT set_config($1, $2, false)", &[name, value])?;
+        }
+
+        for tracked in &mut self.statements {
+            debug!("restoring prepared statement: {}", tracked.query);
+            tracked.statement = self.client.reprepare(&tracked.statement, &tracked.query)?;
+        }
+
+        Ok(())
+    }
+
+    fn ensure_connected(&mut self) -> Result<(), Error> {
+        // errors from requests we didn't see may have left the connection dead
+        if self.unverified {
+            self.unverified = false;
+            if self.client.is_valid(self.health_check_timeout).is_err() {
+                self.broken = true;
+            }
+        }
+        if self.is_broken() {
+            self.reconnect()?;
+        }
+        Ok(())
+    }
+
+    fn track<R>(&mut self, result: Result<R, Error>) -> Result<R, Error> {
+        if let Err(e) = &result {
+            // ordinary errors reported by the server leave the session usable
+            let session_error = match e.as_db_error() {
+                Some(db) => !matches!(
+                    db.parsed_severity(),
+                    Some(Severity::Fatal) | Some(Severity::Panic)
+                ),
+                None => false,
+            };
+            if !session_error
+                && (e.is_closed()
+                    || self.client.is_closed()
+                    || self.client.is_valid(self.health_check_timeout).is_err())
+            {
+                debug!("connection lost: {}", e);
+                self.broken = true;
+            }
+        }
+        result
+    }
+
+    /// Like [`Client::execute`].
+    pub fn execute<S>(&mut self, query: &S, params: &[&(dyn ToSql + Sync)]) -> Result<u64, Error>
+    where
+        S: ?Sized + ToStatement,
+    {
+        self.ensure_connected()?;
+        let result = self.client.execute(query, params);
+        self.track(result)
+    }
+
+    /// Like [`Client::query`].
+    pub fn query<S>(&mut self, query: &S, params: &[&(dyn ToSql + Sync)]) -> Result<Vec<Row>, Error>
+    where
+        S: ?Sized + ToStatement,
+    {
+        self.ensure_connected()?;
+        let result = self.client.query(query, params);
+        self.track(result)
+    }
+
+    /// Like [`Client::query_one`].
+    pub fn query_one<S>(&mut self, query: &S, params: &[&(dyn ToSql + Sync)]) -> Result<Row, Error>
+    where
+        S: ?Sized + ToStatement,
+    {
+        self.ensure_connected()?;
+        let result = self.client.query_one(query, params);
+        self.track(result)
+    }
+
+    /// Like [`Client::query_opt`].
+    pub fn query_opt<S>(
+        &mut self,
+        query: &S,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<Row>, Error>
+    where
+        S: ?Sized + ToStatement,
+    {
+        self.ensure_connected()?;
+        let result = self.client.query_opt(query, params);
+        self.track(result)
+    }
+
+    /// Like [`Client::prepare`].
+    ///
+    /// The statement will be prepared again under the same name if the client reconnects, so the returned
+    /// `Statement` can be used for the lifetime of the `ReconnectingClient`. The statement is not closed on the server
+    /// until the `ReconnectingClient` is dropped.
+    pub fn prepare(&mut self, query: &str) -> Result<Statement, Error> {
+        self.prepare_typed(query, &[])
+    }
+
+    /// Like [`Client::prepare_typed`].
+    ///
+    /// The statement is restored after reconnecting in the same way as with [`prepare`](ReconnectingClient::prepare).
+    pub fn prepare_typed(&mut self, query: &str, types: &[Type]) -> Result<Statement, Error> {
+        self.ensure_connected()?;
+        let result = self.client.prepare_typed(query, types);
+        let statement = self.track(result)?;
+        self.statements.push(TrackedStatement {
+            query: query.to_string(),
+            statement: statement.clone(),
+        });
+        Ok(statement)
+    }
+
+    /// Like [`Client::simple_query`].
+    pub fn simple_query(&mut self, query: &str) -> Result<Vec<SimpleQueryMessage>, Error> {
+        self.ensure_connected()?;
+        let result = self.client.simple_query(query);
+        self.track(result)
+    }
+
+    /// Like [`Client::batch_execute`].
+    pub fn batch_execute(&mut self, query: &str) -> Result<(), Error> {
+        self.ensure_connected()?;
+        let result = self.client.batch_execute(query);
+        self.track(result)
+    }
+
+    /// Sets a session parameter, restoring it on every new connection.
+    ///
+    /// This is equivalent to `SET name = value`, but the value is passed as a query parameter rather than being
+    /// embedded into the SQL.
+    pub fn set_parameter(&mut self, name: &str, value: &str) -> Result<(), Error> {
+        self.ensure_connected()?;
+        let result = self
+            .client
+            .execute("SELECT set_config($1, $2, false)", &[&name, &value]);
+        self.track(result)?;
+
+        match self.parameters.iter_mut().find(|(n, _)| n == name) {
+            Some(parameter) => parameter.1 = value.to_string(),
+            None => self.parameters.push((name.to_string(), value.to_string())),
+        }
+        Ok(())
+    }
+
+    /// Begins a new database transaction, reconnecting first if the connection has been lost.
+    ///
+    /// If the connection is lost while the transaction is open, its requests fail and nothing is retried. The
+    /// connection is checked before the next request made after the transaction has been dropped, reconnecting if
+    /// necessary.
+    pub fn transaction(&mut self) -> Result<Transaction<'_>, Error> {
+        self.ensure_connected()?;
+        self.unverified = true;
+        self.client.transaction()
+    }
+}
@@ -2,7 +2,7 @@ use crate::connection::ConnectionRef;
 use fallible_iterator::FallibleIterator;
 use futures_util::StreamExt;
 use std::pin::Pin;
-use tokio_gaussdb::{Error, Row, RowStream};
+use tokio_gaussdb::{ChunkedRowStream, Error, Row, RowStream};
 
 /// The iterator returned by `query_raw`.
 pub struct RowIter<'a> {
@@ -36,3 +36,39 @@ impl FallibleIterator for RowIter<'_> {
             .block_on(async { it.next().await.transpose() })
     }
 }
+
+/// The iterator returned by `query_chunked`.
+pub struct ChunkedRowIter<'a> {
+    connection: ConnectionRef<'a>,
+    it: Pin<Box<ChunkedRowStream>>,
+}
+
+impl<'a> ChunkedRowIter<'a> {
+    pub(crate) fn new(
+        connection: ConnectionRef<'a>,
+        stream: ChunkedRowStream,
+    ) -> ChunkedRowIter<'a> {
+        ChunkedRowIter {
+            connection,
+            it: Box::pin(stream),
+        }
+    }
+
+    /// Returns the number of rows affected by the query.
+    ///
+    /// This function will return `None` until the iterator has been exhausted.
+    pub fn rows_affected(&self) -> Option<u64> {
+        self.it.rows_affected()
+    }
+}
+
+impl FallibleIterator for ChunkedRowIter<'_> {
+    type Item = Row;
+    type Error = Error;
+
+    fn next(&mut self) -> Result<Option<Row>, Error> {
+        let it = &mut self.it;
+        self.connection
+            .block_on(async { it.next().await.transpose() })
+    }
+}
@@ -471,6 +471,313 @@ fn cancel_query() {
     cancel_thread.join().unwrap();
 }
 
+#[test]
+fn statement_timeout() {
+    let mut client = Client::connect(
+        "host=localhost port=5433 user=gaussdb password=Gaussdb@123 dbname=postgres",
+        NoTls,
+    )
+    .unwrap();
+
+    let err = client
+        .with_timeout(Duration::from_millis(100), |client| {
+            client.batch_execute("SELECT pg_sleep(100)")
+        })
+        .unwrap_err();
+    assert!(err.is_timeout());
+    assert_eq!(client.statement_timeout(), None);
+
+    let row = client.query_one("SELECT 1::INT", &[]).unwrap();
+    assert_eq!(row.get::<_, i32>(0), 1);
+}
+
+#[test]
+fn query_named() {
+    let mut client = Client::connect(
+        "host=localhost port=5433 user=gaussdb password=Gaussdb@123 dbname=postgres",
+        NoTls,
+    )
+    .unwrap();
+
+    let row = client
+        .query_one_named(
+            "SELECT :a::INT + :b::INT, ':a', @a::INT * 2",
+            &[("a", &1i32), ("b", &2i32)],
+        )
+        .unwrap();
+    assert_eq!(row.get::<_, i32>(0), 3);
+    assert_eq!(row.get::<_, &str>(1), ":a");
+    assert_eq!(row.get::<_, i32>(2), 2);
+
+    let mut transaction = client.transaction().unwrap();
+    let rows = transaction
+        .query_named("SELECT generate_series(1, :n)", &[("n", &3i32)])
+        .unwrap();
+    assert_eq!(rows.len(), 3);
+}
+
+#[test]
+fn column_nullability() {
+    let mut client = Client::connect(
+        "host=localhost port=5433 user=gaussdb password=Gaussdb@123 dbname=postgres",
+        NoTls,
+    )
+    .unwrap();
+
+    client
+        .batch_execute("CREATE TEMPORARY TABLE foo (id INT NOT NULL, name VARCHAR(10))")
+        .unwrap();
+
+    let mut transaction = client.transaction().unwrap();
+    let stmt = transaction
+        .prepare("SELECT id, name, id + 1 FROM foo")
+        .unwrap();
+    assert_eq!(stmt.columns()[1].character_length(), Some(10));
+    assert_eq!(
+        transaction.column_nullability(stmt.columns()).unwrap(),
+        vec![Some(false), Some(true), None]
+    );
+}
+
+#[test]
+fn query_text() {
+    let mut client = Client::connect(
+        "host=localhost port=5433 user=gaussdb password=Gaussdb@123 dbname=postgres",
+        NoTls,
+    )
+    .unwrap();
+
+    let mut transaction = client.transaction().unwrap();
+    let rows = transaction
+        .query_text("SELECT $1::INT8, '{1,2}'::INT4[]", &[&3i64])
+        .unwrap();
+    assert_eq!(rows[0].get_text::<_, i64>(0), 3);
+    assert_eq!(rows[0].get_text::<_, Vec<i32>>(1), vec![1, 2]);
+}
+
+#[test]
+fn cursor() {
+    let mut client = Client::connect(
+        "host=localhost port=5433 user=gaussdb password=Gaussdb@123 dbname=postgres",
+        NoTls,
+    )
+    .unwrap();
+
+    let mut transaction = client.transaction().unwrap();
+    let mut cursor = transaction
+        .build_cursor("SELECT * FROM generate_series(1, 10)")
+        .scroll(true)
+        .hold(true)
+        .declare(&[])
+        .unwrap();
+    assert_eq!(cursor.fetch_last().unwrap().unwrap().get::<_, i32>(0), 10);
+    let rows = cursor.fetch(FetchDirection::Backward(3)).unwrap();
+    assert_eq!(
+        rows.iter().map(|r| r.get(0)).collect::<Vec<i32>>(),
+        [9, 8, 7]
+    );
+    assert_eq!(cursor.seek(FetchDirection::Absolute(2)).unwrap(), 1);
+    assert_eq!(cursor.fetch_next().unwrap().unwrap().get::<_, i32>(0), 3);
+    drop(cursor);
+
+    let mut cursor = transaction
+        .cursor("SELECT * FROM generate_series(1, $1::INT4)", &[&3i32])
+        .unwrap();
+    assert_eq!(cursor.fetch_forward(10).unwrap().len(), 3);
+    cursor.close().unwrap();
+    transaction.commit().unwrap();
+
+    let count = client
+        .query_one("SELECT count(*) FROM pg_cursors WHERE name <> ''", &[])
+        .unwrap()
+        .get::<_, i64>(0);
+    assert_eq!(count, 0);
+}
+
+#[test]
+fn query_chunked() {
+    let mut client = Client::connect(
+        "host=localhost port=5433 user=gaussdb password=Gaussdb@123 dbname=postgres",
+        NoTls,
+    )
+    .unwrap();
+
+    let mut it = client
+        .query_chunked("SELECT * FROM generate_series(1, 10)", &[], 4)
+        .unwrap();
+    let mut values = vec![];
+    while let Some(row) = it.next().unwrap() {
+        values.push(row.get::<_, i32>(0));
+    }
+    assert_eq!(values, (1..=10).collect::<Vec<_>>());
+    assert_eq!(it.rows_affected(), Some(10));
+    drop(it);
+
+    let mut it = client
+        .query_chunked("SELECT * FROM generate_series(1, 1000000)", &[], 4)
+        .unwrap();
+    assert_eq!(it.nth(5).unwrap().unwrap().get::<_, i32>(0), 6);
+    drop(it);
+
+    let row = client.query_one("SELECT 1", &[]).unwrap();
+    assert_eq!(row.get::<_, i32>(0), 1);
+}
+
+#[test]
+fn reconnecting_client() {
+    let config = "host=localhost port=5433 user=gaussdb password=Gaussdb@123 dbname=postgres"
+        .parse::<Config>()
+        .unwrap();
+    let mut admin = config.connect(NoTls).unwrap();
+    let mut client = ReconnectingClient::connect(config, NoTls).unwrap();
+    client.reconnect_backoff(Duration::from_millis(10), Duration::from_millis(100));
+
+    client
+        .set_parameter("application_name", "reconnect_test")
+        .unwrap();
+    let stmt = client.prepare("SELECT $1::INT + 1").unwrap();
+    let pid = client
+        .query_one("SELECT pg_backend_pid()", &[])
+        .unwrap()
+        .get::<_, i32>(0);
+
+    admin
+        .execute("SELECT pg_terminate_backend($1)", &[&pid])
+        .unwrap();
+
+    assert!(client.query_one(&stmt, &[&1i32]).is_err());
+    assert!(client.is_broken());
+
+    let row = client.query_one(&stmt, &[&1i32]).unwrap();
+    assert_eq!(row.get::<_, i32>(0), 2);
+    assert!(!client.is_broken());
+
+    let row = client
+        .query_one(
+            "SELECT pg_backend_pid(), current_setting('application_name')",
+            &[],
+        )
+        .unwrap();
+    assert_ne!(row.get::<_, i32>(0), pid);
+    assert_eq!(row.get::<_, &str>(1), "reconnect_test");
+}
+
+#[test]
+fn reconnecting_client_transaction() {
+    let config = "host=localhost port=5433 user=gaussdb password=Gaussdb@123 dbname=postgres"
+        .parse::<Config>()
+        .unwrap();
+    let mut admin = config.connect(NoTls).unwrap();
+    let mut client = ReconnectingClient::connect(config, NoTls).unwrap();
+
+    admin
+        .batch_execute(
+            "DROP TABLE IF EXISTS reconnecting_client_transaction;
+             CREATE TABLE reconnecting_client_transaction (id INT)",
+        )
+        .unwrap();
+
+    {
+        let mut transaction = client.transaction().unwrap();
+        transaction
+            .execute(
+                "INSERT INTO reconnecting_client_transaction VALUES (1)",
+                &[],
+            )
+            .unwrap();
+        let pid = transaction
+            .query_one("SELECT pg_backend_pid()", &[])
+            .unwrap()
+            .get::<_, i32>(0);
+        admin
+            .execute("SELECT pg_terminate_backend($1)", &[&pid])
+            .unwrap();
+
+        assert!(transaction
+            .execute(
+                "INSERT INTO reconnecting_client_transaction VALUES (2)",
+                &[]
+            )
+            .is_err());
+        assert!(transaction.commit().is_err());
+    }
+
+    let count = client
+        .query_one("SELECT COUNT(*) FROM reconnecting_client_transaction", &[])
+        .unwrap()
+        .get::<_, i64>(0);
+    assert_eq!(count, 0);
+
+    admin
+        .batch_execute("DROP TABLE reconnecting_client_transaction")
+        .unwrap();
+}
+
+#[test]
+#[cfg(feature = "pool")]
+fn pool() {
+    use crate::pool::{Pool, PoolConfig, PoolError};
+
+    let config = "host=localhost port=5433 user=gaussdb password=Gaussdb@123 dbname=postgres"
+        .parse::<Config>()
+        .unwrap();
+    let pool = Pool::connect(
+        PoolConfig::new()
+            .min_size(1)
+            .max_size(2)
+            .acquire_timeout(Some(Duration::from_millis(100))),
+        config,
+        NoTls,
+    )
+    .unwrap();
+    assert_eq!(pool.status().idle, 1);
+
+    let mut a = pool.get().unwrap();
+    let stmt = a.prepare_cached("SELECT $1::INT * 2").unwrap();
+    assert_eq!(a.query_one(&stmt, &[&2i32]).unwrap().get::<_, i32>(0), 4);
+
+    let b = pool.get().unwrap();
+    match pool.get() {
+        Err(PoolError::Timeout) => {}
+        r => panic!("unexpected result {:?}", r),
+    }
+    assert_eq!(pool.status().in_use, 2);
+    drop(b);
+    drop(a);
+
+    let handles = (0..4)
+        .map(|_| {
+            let pool = pool.clone();
+            thread::spawn(move || {
+                let mut client = pool.get().unwrap();
+                let stmt = client.prepare_cached("SELECT $1::INT * 2").unwrap();
+                client.query_one(&stmt, &[&3i32]).unwrap().get::<_, i32>(0)
+            })
+        })
+        .collect::<Vec<_>>();
+    for handle in handles {
+        assert_eq!(handle.join().unwrap(), 6);
+    }
+
+    let status = pool.status();
+    assert_eq!(status.size, 2);
+    assert_eq!(status.idle, 2);
+    let metrics = pool.metrics();
+    assert_eq!(metrics.connections_created, 2);
+    assert_eq!(metrics.acquire_timeouts, 1);
+    assert_eq!(
+        metrics.statement_cache_hits + metrics.statement_cache_misses,
+        5
+    );
+
+    pool.close();
+    assert_eq!(pool.status().size, 0);
+    match pool.get() {
+        Err(PoolError::Closed) => {}
+        r => panic!("unexpected result {:?}", r),
+    }
+}
+
 #[test]
 #[ignore] // GaussDB doesn't fully support LISTEN/NOTIFY functionality yet
 fn notifications_iter() {
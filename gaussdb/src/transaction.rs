@@ -1,7 +1,10 @@
 use crate::connection::ConnectionRef;
-use crate::{CancelToken, CopyInWriter, CopyOutReader, Portal, RowIter, Statement, ToStatement};
+use crate::{
+    CancelToken, ChunkedRowIter, CopyInWriter, CopyOutReader, Cursor, CursorBuilder, Portal,
+    RowIter, Statement, ToStatement,
+};
 use tokio_gaussdb::types::{BorrowToSql, ToSql, Type};
-use tokio_gaussdb::{Error, Row, SimpleQueryMessage};
+use tokio_gaussdb::{Column, Error, FromRow, Row, SimpleQueryMessage};
 
 /// A representation of a PostgreSQL database transaction.
 ///
@@ -61,6 +64,16 @@ impl<'a> Transaction<'a> {
         )
     }
 
+    /// Like `Client::column_nullability`.
+    pub fn column_nullability(&mut self, columns: &[Column]) -> Result<Vec<Option<bool>>, Error> {
+        self.connection.block_on(
+            self.transaction
+                .as_ref()
+                .unwrap()
+                .column_nullability(columns),
+        )
+    }
+
     /// Like `Client::execute`.
     pub fn execute<T>(&mut self, query: &T, params: &[&(dyn ToSql + Sync)]) -> Result<u64, Error>
     where
@@ -79,6 +92,19 @@ impl<'a> Transaction<'a> {
             .block_on(self.transaction.as_ref().unwrap().query(query, params))
     }
 
+    /// Like `Client::query_text`.
+    pub fn query_text<T>(
+        &mut self,
+        query: &T,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Vec<Row>, Error>
+    where
+        T: ?Sized + ToStatement,
+    {
+        self.connection
+            .block_on(self.transaction.as_ref().unwrap().query_text(query, params))
+    }
+
     /// Like `Client::query_one`.
     pub fn query_one<T>(&mut self, query: &T, params: &[&(dyn ToSql + Sync)]) -> Result<Row, Error>
     where
@@ -101,6 +127,56 @@ impl<'a> Transaction<'a> {
             .block_on(self.transaction.as_ref().unwrap().query_opt(query, params))
     }
 
+    /// Like `Client::query_as`.
+    pub fn query_as<R, T>(
+        &mut self,
+        query: &T,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Vec<R>, Error>
+    where
+        R: FromRow,
+        T: ?Sized + ToStatement,
+    {
+        self.connection
+            .block_on(self.transaction.as_ref().unwrap().query_as(query, params))
+    }
+
+    /// Like `Client::query_one_as`.
+    pub fn query_one_as<R, T>(
+        &mut self,
+        query: &T,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<R, Error>
+    where
+        R: FromRow,
+        T: ?Sized + ToStatement,
+    {
+        self.connection.block_on(
+            self.transaction
+                .as_ref()
+                .unwrap()
+                .query_one_as(query, params),
+        )
+    }
+
+    /// Like `Client::query_opt_as`.
+    pub fn query_opt_as<R, T>(
+        &mut self,
+        query: &T,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<R>, Error>
+    where
+        R: FromRow,
+        T: ?Sized + ToStatement,
+    {
+        self.connection.block_on(
+            self.transaction
+                .as_ref()
+                .unwrap()
+                .query_opt_as(query, params),
+        )
+    }
+
     /// Like `Client::query_raw`.
     pub fn query_raw<T, P, I>(&mut self, query: &T, params: I) -> Result<RowIter<'_>, Error>
     where
@@ -115,6 +191,25 @@ impl<'a> Transaction<'a> {
         Ok(RowIter::new(self.connection.as_ref(), stream))
     }
 
+    /// Like `Client::query_chunked`.
+    pub fn query_chunked<T>(
+        &mut self,
+        query: &T,
+        params: &[&(dyn ToSql + Sync)],
+        fetch_size: i32,
+    ) -> Result<ChunkedRowIter<'_>, Error>
+    where
+        T: ?Sized + ToStatement,
+    {
+        let stream = self.connection.block_on(
+            self.transaction
+                .as_ref()
+                .unwrap()
+                .query_chunked(query, params, fetch_size),
+        )?;
+        Ok(ChunkedRowIter::new(self.connection.as_ref(), stream))
+    }
+
     /// Like `Client::query_typed`.
     pub fn query_typed(
         &mut self,
@@ -144,6 +239,62 @@ impl<'a> Transaction<'a> {
         Ok(RowIter::new(self.connection.as_ref(), stream))
     }
 
+    /// Like `Client::query_named`.
+    pub fn query_named(
+        &mut self,
+        query: &str,
+        params: &[(&str, &(dyn ToSql + Sync))],
+    ) -> Result<Vec<Row>, Error> {
+        self.connection.block_on(
+            self.transaction
+                .as_ref()
+                .unwrap()
+                .query_named(query, params),
+        )
+    }
+
+    /// Like `Client::query_one_named`.
+    pub fn query_one_named(
+        &mut self,
+        query: &str,
+        params: &[(&str, &(dyn ToSql + Sync))],
+    ) -> Result<Row, Error> {
+        self.connection.block_on(
+            self.transaction
+                .as_ref()
+                .unwrap()
+                .query_one_named(query, params),
+        )
+    }
+
+    /// Like `Client::query_opt_named`.
+    pub fn query_opt_named(
+        &mut self,
+        query: &str,
+        params: &[(&str, &(dyn ToSql + Sync))],
+    ) -> Result<Option<Row>, Error> {
+        self.connection.block_on(
+            self.transaction
+                .as_ref()
+                .unwrap()
+                .query_opt_named(query, params),
+        )
+    }
+
+    /// Like `Client::execute_named`.
+    pub fn execute_named(
+        &mut self,
+        query: &str,
+        params: &[(&str, &(dyn ToSql + Sync))],
+    ) -> Result<u64, Error> {
+        self.connection.block_on(
+            self.transaction
+                .as_ref()
+                .unwrap()
+                .execute_named(query, params),
+        )
+    }
+
     /// Binds parameters to a statement, creating a "portal".
     ///
     /// Portals can be used with the `query_portal` method to page through the results of a query without being forced
@@ -190,6 +341,26 @@ impl<'a> Transaction<'a> {
         Ok(RowIter::new(self.connection.as_ref(), stream))
     }
 
+    /// Declares a server-side cursor over the rows of a query.
+    ///
+    /// Unlike portals, cursors can be scrolled backwards and repositioned, subject to the options they were declared
+    /// with. Use `build_cursor` to set those options.
+    pub fn cursor(
+        &mut self,
+        query: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Cursor<'_>, Error> {
+        self.build_cursor(query).declare(params)
+    }
+
+    /// Like `Client::build_cursor`.
+    pub fn build_cursor(&mut self, query: &str) -> CursorBuilder<'_> {
+        CursorBuilder::new(
+            self.connection.as_ref(),
+            self.transaction.as_ref().unwrap().build_cursor(query),
+        )
+    }
+
     /// Like `Client::copy_in`.
     pub fn copy_in<T>(&mut self, query: &T) -> Result<CopyInWriter<'_>, Error>
     where
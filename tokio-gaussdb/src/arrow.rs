@@ -0,0 +1,544 @@
+//! Conversion of query results into Apache Arrow record batches.
+//!
+//! Requires the `arrow` feature.
+//!
+//! Values are decoded directly from the binary format returned by the server into Arrow builders. The supported
+//! column types are:
+//!
+//! | Postgres type | Arrow type |
+//! | ------------- | ---------- |
+//! | `BOOL` | `Boolean` |
+//! | `"char"` | `Int8` |
+//! | `INT2` | `Int16` |
+//! | `INT4` | `Int32` |
+//! | `INT8` | `Int64` |
+//! | `OID` | `UInt32` |
+//! | `FLOAT4` | `Float32` |
+//! | `FLOAT8` | `Float64` |
+//! | `NUMERIC` | `Decimal128` |
+//! | `TEXT`, `VARCHAR`, `CHAR(n)`, `NAME`, enums | `Utf8` |
+//! | `JSON`, `JSONB` | `Utf8` with the `arrow.json` extension type |
+//! | `BYTEA` | `Binary` |
+//! | `DATE` | `Date32` |
+//! | `TIME` | `Time64(Microsecond)` |
+//! | `TIMESTAMP` | `Timestamp(Microsecond, None)` |
+//! | `TIMESTAMPTZ` | `Timestamp(Microsecond, "UTC")` |
+//! | `UUID` | `FixedSizeBinary(16)` with the `arrow.uuid` extension type |
+//! | one dimensional arrays of the above | `List` |
+//!
+//! Domains are converted as their underlying type. `NUMERIC` columns use the declared precision and scale of the
+//! column, or a precision of 38 and a scale of 10 if none was declared; values which do not fit, along with `NaN`
+//! and infinities, are reported as errors rather than being rounded. Infinite dates and timestamps are also errors.
+use crate::binary_copy::BinaryCopyOutStream;
+use crate::query::RowStream;
+use crate::types::{Kind, Type, WrongType};
+use crate::Error;
+use arrow_array_54::builder::{
+    BinaryBuilder, BooleanBuilder, Date32Builder, Decimal128Builder, FixedSizeBinaryBuilder,
+    Float32Builder, Float64Builder, Int16Builder, Int32Builder, Int64Builder, Int8Builder,
+    StringBuilder, Time64MicrosecondBuilder, TimestampMicrosecondBuilder, UInt32Builder,
+};
+use arrow_array_54::{ArrayRef, ListArray, RecordBatch};
+use arrow_buffer_54::{NullBufferBuilder, OffsetBuffer};
+use arrow_schema_54::{DataType, Field, FieldRef, Schema, SchemaRef, TimeUnit};
+use byteorder::{BigEndian, ReadBytesExt};
+use fallible_iterator::FallibleIterator;
+use futures_util::{ready, Stream};
+use gaussdb_protocol::types;
+use std::collections::HashMap;
+use std::error;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+const DEFAULT_DECIMAL_PRECISION: u8 = 38;
+const DEFAULT_DECIMAL_SCALE: i8 = 10;
+
+// microseconds and days between the Unix and Postgres epochs
+const EPOCH_OFFSET_MICROS: i64 = 946_684_800_000_000;
+const EPOCH_OFFSET_DAYS: i32 = 10_957;
+
+impl RowStream {
+    /// Converts the stream into a stream of record batches of up to `batch_size` rows.
+    ///
+    /// Returns an error if a column has a type which cannot be converted.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `batch_size` is zero.
+    pub fn into_record_batches(self, batch_size: usize) -> Result<RecordBatchStream, Error> {
+        let columns = self
+            .statement()
+            .columns()
+            .iter()
+            .enumerate()
+            .map(|(i, c)| {
+                let decimal = c.numeric_precision().zip(c.numeric_scale());
+                column(c.name().to_string(), c.type_(), decimal, i)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(RecordBatchStream::new(
+            Source::Rows(Box::pin(self)),
+            columns,
+            batch_size,
+        ))
+    }
+}
+
+impl BinaryCopyOutStream {
+    /// Converts the stream into a stream of record batches of up to `batch_size` rows.
+    ///
+    /// The fields of the batches are named `column1`, `column2`, and so on. `NUMERIC` values are converted with the
+    /// default precision and scale, since the binary copy format carries no type modifiers.
+    ///
+    /// Returns an error if a column has a type which cannot be converted.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `batch_size` is zero.
+    pub fn into_record_batches(self, batch_size: usize) -> Result<RecordBatchStream, Error> {
+        let columns = self
+            .types()
+            .iter()
+            .enumerate()
+            .map(|(i, type_)| column(format!("column{}", i + 1), type_, None, i))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(RecordBatchStream::new(
+            Source::Copy(Box::pin(self)),
+            columns,
+            batch_size,
+        ))
+    }
+}
+
+enum Source {
+    Rows(Pin<Box<RowStream>>),
+    Copy(Pin<Box<BinaryCopyOutStream>>),
+}
+
+/// A stream of Arrow record batches converted from the rows of a `RowStream` or `BinaryCopyOutStream`.
+pub struct RecordBatchStream {
+    source: Source,
+    schema: SchemaRef,
+    builders: Vec<Builder>,
+    batch_size: usize,
+    len: usize,
+    done: bool,
+}
+
+impl RecordBatchStream {
+    fn new(source: Source, columns: Vec<(Field, Builder)>, batch_size: usize) -> RecordBatchStream {
+        assert!(batch_size > 0, "batch size must be positive");
+        let (fields, builders): (Vec<_>, Vec<_>) = columns.into_iter().unzip();
+        RecordBatchStream {
+            source,
+            schema: Arc::new(Schema::new(fields)),
+            builders,
+            batch_size,
+            len: 0,
+            done: false,
+        }
+    }
+
+    /// Returns the schema of the record batches.
+    pub fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+
+    fn finish(&mut self) -> RecordBatch {
+        let columns = self.builders.iter_mut().map(Builder::finish).collect();
+        self.len = 0;
+        RecordBatch::try_new(self.schema.clone(), columns).expect("columns match the schema")
+    }
+}
+
+impl Stream for RecordBatchStream {
+    type Item = Result<RecordBatch, Error>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = &mut *self;
+        while !this.done {
+            match ready!(this.poll_row(cx)) {
+                Ok(true) => {
+                    this.len += 1;
+                    if this.len == this.batch_size {
+                        return Poll::Ready(Some(Ok(this.finish())));
+                    }
+                }
+                Ok(false) => this.done = true,
+                Err(e) => {
+                    // the builders may hold part of a row, so the remaining rows can't be converted
+                    this.done = true;
+                    this.len = 0;
+                    return Poll::Ready(Some(Err(e)));
+                }
+            }
+        }
+
+        if this.len > 0 {
+            Poll::Ready(Some(Ok(this.finish())))
+        } else {
+            Poll::Ready(None)
+        }
+    }
+}
+
+impl RecordBatchStream {
+    // Appends the next row to the builders, returning `false` once the source is exhausted.
+    fn poll_row(&mut self, cx: &mut Context<'_>) -> Poll<Result<bool, Error>> {
+        match &mut self.source {
+            Source::Rows(stream) => {
+                let body = match ready!(stream.as_mut().poll_next_raw(cx)?) {
+                    Some(body) => body,
+                    None => return Poll::Ready(Ok(false)),
+                };
+                let buf = body.buffer();
+                let mut ranges = body.ranges();
+                for (i, builder) in self.builders.iter_mut().enumerate() {
+                    let range = ranges.next().map_err(Error::parse)?.flatten();
+                    builder
+                        .append(range.map(|r| &buf[r]))
+                        .map_err(|e| Error::from_sql(e, i))?;
+                }
+            }
+            Source::Copy(stream) => {
+                let row = match ready!(stream.as_mut().poll_next(cx)?) {
+                    Some(row) => row,
+                    None => return Poll::Ready(Ok(false)),
+                };
+                for (i, builder) in self.builders.iter_mut().enumerate() {
+                    builder
+                        .append(row.raw(i))
+                        .map_err(|e| Error::from_sql(e, i))?;
+                }
+            }
+        }
+        Poll::Ready(Ok(true))
+    }
+}
+
+fn column(
+    name: String,
+    type_: &Type,
+    decimal: Option<(u32, i32)>,
+    idx: usize,
+) -> Result<(Field, Builder), Error> {
+    match Builder::new(type_, decimal) {
+        Some(builder) => Ok((builder.field(name), builder)),
+        None => Err(Error::from_sql(
+            Box::new(WrongType::new::<RecordBatch>(type_.clone())),
+            idx,
+        )),
+    }
+}
+
+enum Builder {
+    Bool(BooleanBuilder),
+    Char(Int8Builder),
+    Int2(Int16Builder),
+    Int4(Int32Builder),
+    Int8(Int64Builder),
+    Oid(UInt32Builder),
+    Float4(Float32Builder),
+    Float8(Float64Builder),
+    Numeric(Decimal128Builder, u8, i8),
+    Text(StringBuilder),
+    Json(StringBuilder),
+    Jsonb(StringBuilder),
+    Bytea(BinaryBuilder),
+    Date(Date32Builder),
+    Time(Time64MicrosecondBuilder),
+    Timestamp(TimestampMicrosecondBuilder),
+    TimestampTz(TimestampMicrosecondBuilder),
+    Uuid(FixedSizeBinaryBuilder),
+    List(Box<ListBuilder>),
+}
+
+struct ListBuilder {
+    field: FieldRef,
+    values: Builder,
+    offsets: Vec<i32>,
+    nulls: NullBufferBuilder,
+}
+
+impl Builder {
+    fn new(type_: &Type, decimal: Option<(u32, i32)>) -> Option<Builder> {
+        let builder = match *type_ {
+            Type::BOOL => Builder::Bool(BooleanBuilder::new()),
+            Type::CHAR => Builder::Char(Int8Builder::new()),
+            Type::INT2 => Builder::Int2(Int16Builder::new()),
+            Type::INT4 => Builder::Int4(Int32Builder::new()),
+            Type::INT8 => Builder::Int8(Int64Builder::new()),
+            Type::OID => Builder::Oid(UInt32Builder::new()),
+            Type::FLOAT4 => Builder::Float4(Float32Builder::new()),
+            Type::FLOAT8 => Builder::Float8(Float64Builder::new()),
+            Type::NUMERIC => {
+                let (precision, scale) = match decimal {
+                    Some((precision, scale)) if precision <= 38 && scale.abs() <= 38 => {
+                        (precision as u8, scale as i8)
+                    }
+                    Some(_) => return None,
+                    None => (DEFAULT_DECIMAL_PRECISION, DEFAULT_DECIMAL_SCALE),
+                };
+                let builder = Decimal128Builder::new()
+                    .with_precision_and_scale(precision, scale)
+                    .ok()?;
+                Builder::Numeric(builder, precision, scale)
+            }
+            Type::TEXT | Type::VARCHAR | Type::BPCHAR | Type::NAME | Type::UNKNOWN => {
+                Builder::Text(StringBuilder::new())
+            }
+            Type::JSON => Builder::Json(StringBuilder::new()),
+            Type::JSONB => Builder::Jsonb(StringBuilder::new()),
+            Type::BYTEA => Builder::Bytea(BinaryBuilder::new()),
+            Type::DATE => Builder::Date(Date32Builder::new()),
+            Type::TIME => Builder::Time(Time64MicrosecondBuilder::new()),
+            Type::TIMESTAMP => Builder::Timestamp(TimestampMicrosecondBuilder::new()),
+            Type::TIMESTAMPTZ => {
+                Builder::TimestampTz(TimestampMicrosecondBuilder::new().with_timezone("UTC"))
+            }
+            Type::UUID => Builder::Uuid(FixedSizeBinaryBuilder::new(16)),
+            _ => match type_.kind() {
+                Kind::Enum(_) => Builder::Text(StringBuilder::new()),
+                Kind::Domain(type_) => return Builder::new(type_, decimal),
+                Kind::Array(member) => {
+                    let values = Builder::new(member, None)?;
+                    Builder::List(Box::new(ListBuilder {
+                        field: Arc::new(values.field("item".to_string())),
+                        values,
+                        offsets: vec![0],
+                        nulls: NullBufferBuilder::new(0),
+                    }))
+                }
+                _ => return None,
+            },
+        };
+        Some(builder)
+    }
+
+    fn field(&self, name: String) -> Field {
+        let data_type = match self {
+            Builder::Bool(_) => DataType::Boolean,
+            Builder::Char(_) => DataType::Int8,
+            Builder::Int2(_) => DataType::Int16,
+            Builder::Int4(_) => DataType::Int32,
+            Builder::Int8(_) => DataType::Int64,
+            Builder::Oid(_) => DataType::UInt32,
+            Builder::Float4(_) => DataType::Float32,
+            Builder::Float8(_) => DataType::Float64,
+            Builder::Numeric(_, precision, scale) => DataType::Decimal128(*precision, *scale),
+            Builder::Text(_) | Builder::Json(_) | Builder::Jsonb(_) => DataType::Utf8,
+            Builder::Bytea(_) => DataType::Binary,
+            Builder::Date(_) => DataType::Date32,
+            Builder::Time(_) => DataType::Time64(TimeUnit::Microsecond),
+            Builder::Timestamp(_) => DataType::Timestamp(TimeUnit::Microsecond, None),
+            Builder::TimestampTz(_) => {
+                DataType::Timestamp(TimeUnit::Microsecond, Some("UTC".into()))
+            }
+            Builder::Uuid(_) => DataType::FixedSizeBinary(16),
+            Builder::List(list) => DataType::List(list.field.clone()),
+        };
+
+        let field = Field::new(name, data_type, true);
+        let extension = match self {
+            Builder::Json(_) | Builder::Jsonb(_) => "arrow.json",
+            Builder::Uuid(_) => "arrow.uuid",
+            _ => return field,
+        };
+        field.with_metadata(HashMap::from([(
+            "ARROW:extension:name".to_string(),
+            extension.to_string(),
+        )]))
+    }
+
+    fn append(&mut self, raw: Option<&[u8]>) -> Result<(), Box<dyn error::Error + Sync + Send>> {
+        let raw = match raw {
+            Some(raw) => raw,
+            None => {
+                self.append_null();
+                return Ok(());
+            }
+        };
+
+        match self {
+            Builder::Bool(builder) => builder.append_value(types::bool_from_sql(raw)?),
+            Builder::Char(builder) => builder.append_value(types::char_from_sql(raw)?),
+            Builder::Int2(builder) => builder.append_value(types::int2_from_sql(raw)?),
+            Builder::Int4(builder) => builder.append_value(types::int4_from_sql(raw)?),
+            Builder::Int8(builder) => builder.append_value(types::int8_from_sql(raw)?),
+            Builder::Oid(builder) => builder.append_value(types::oid_from_sql(raw)?),
+            Builder::Float4(builder) => builder.append_value(types::float4_from_sql(raw)?),
+            Builder::Float8(builder) => builder.append_value(types::float8_from_sql(raw)?),
+            Builder::Numeric(builder, precision, scale) => {
+                builder.append_value(decimal_from_sql(raw, *precision, *scale)?)
+            }
+            Builder::Text(builder) | Builder::Json(builder) => {
+                builder.append_value(types::text_from_sql(raw)?)
+            }
+            Builder::Jsonb(builder) => match raw.split_first() {
+                Some((1, raw)) => builder.append_value(types::text_from_sql(raw)?),
+                _ => return Err("unsupported JSONB encoding version".into()),
+            },
+            Builder::Bytea(builder) => builder.append_value(types::bytea_from_sql(raw)),
+            Builder::Date(builder) => {
+                let days = types::date_from_sql(raw)?;
+                if days == i32::MIN || days == i32::MAX {
+                    return Err("infinite dates cannot be converted".into());
+                }
+                let days = days
+                    .checked_add(EPOCH_OFFSET_DAYS)
+                    .ok_or("date out of range")?;
+                builder.append_value(days)
+            }
+            Builder::Time(builder) => builder.append_value(types::time_from_sql(raw)?),
+            Builder::Timestamp(builder) | Builder::TimestampTz(builder) => {
+                let micros = types::timestamp_from_sql(raw)?;
+                if micros == i64::MIN || micros == i64::MAX {
+                    return Err("infinite timestamps cannot be converted".into());
+                }
+                let micros = micros
+                    .checked_add(EPOCH_OFFSET_MICROS)
+                    .ok_or("timestamp out of range")?;
+                builder.append_value(micros)
+            }
+            Builder::Uuid(builder) => builder.append_value(types::uuid_from_sql(raw)?)?,
+            Builder::List(list) => list.append(raw)?,
+        }
+
+        Ok(())
+    }
+
+    fn append_null(&mut self) {
+        match self {
+            Builder::Bool(builder) => builder.append_null(),
+            Builder::Char(builder) => builder.append_null(),
+            Builder::Int2(builder) => builder.append_null(),
+            Builder::Int4(builder) => builder.append_null(),
+            Builder::Int8(builder) => builder.append_null(),
+            Builder::Oid(builder) => builder.append_null(),
+            Builder::Float4(builder) => builder.append_null(),
+            Builder::Float8(builder) => builder.append_null(),
+            Builder::Numeric(builder, _, _) => builder.append_null(),
+            Builder::Text(builder) | Builder::Json(builder) | Builder::Jsonb(builder) => {
+                builder.append_null()
+            }
+            Builder::Bytea(builder) => builder.append_null(),
+            Builder::Date(builder) => builder.append_null(),
+            Builder::Time(builder) => builder.append_null(),
+            Builder::Timestamp(builder) | Builder::TimestampTz(builder) => builder.append_null(),
+            Builder::Uuid(builder) => builder.append_null(),
+            Builder::List(list) => {
+                list.offsets.push(*list.offsets.last().unwrap());
+                list.nulls.append_null();
+            }
+        }
+    }
+
+    fn finish(&mut self) -> ArrayRef {
+        match self {
+            Builder::Bool(builder) => Arc::new(builder.finish()),
+            Builder::Char(builder) => Arc::new(builder.finish()),
+            Builder::Int2(builder) => Arc::new(builder.finish()),
+            Builder::Int4(builder) => Arc::new(builder.finish()),
+            Builder::Int8(builder) => Arc::new(builder.finish()),
+            Builder::Oid(builder) => Arc::new(builder.finish()),
+            Builder::Float4(builder) => Arc::new(builder.finish()),
+            Builder::Float8(builder) => Arc::new(builder.finish()),
+            Builder::Numeric(builder, _, _) => Arc::new(builder.finish()),
+            Builder::Text(builder) | Builder::Json(builder) | Builder::Jsonb(builder) => {
+                Arc::new(builder.finish())
+            }
+            Builder::Bytea(builder) => Arc::new(builder.finish()),
+            Builder::Date(builder) => Arc::new(builder.finish()),
+            Builder::Time(builder) => Arc::new(builder.finish()),
+            Builder::Timestamp(builder) | Builder::TimestampTz(builder) => {
+                Arc::new(builder.finish())
+            }
+            Builder::Uuid(builder) => Arc::new(builder.finish()),
+            Builder::List(list) => Arc::new(list.finish()),
+        }
+    }
+}
+
+impl ListBuilder {
+    fn append(&mut self, raw: &[u8]) -> Result<(), Box<dyn error::Error + Sync + Send>> {
+        let array = types::array_from_sql(raw)?;
+        if array.dimensions().count()? > 1 {
+            return Err("multidimensional arrays cannot be converted".into());
+        }
+
+        let mut len = 0;
+        let mut values = array.values();
+        while let Some(value) = values.next()? {
+            self.values.append(value)?;
+            len += 1;
+        }
+
+        let end = *self.offsets.last().unwrap() + len;
+        self.offsets.push(end);
+        self.nulls.append_non_null();
+        Ok(())
+    }
+
+    fn finish(&mut self) -> ListArray {
+        let offsets = OffsetBuffer::new(std::mem::replace(&mut self.offsets, vec![0]).into());
+        ListArray::new(
+            self.field.clone(),
+            offsets,
+            self.values.finish(),
+            self.nulls.finish(),
+        )
+    }
+}
+
+// Converts a binary numeric to an unscaled integer at the given scale, rejecting values which would need rounding.
+fn decimal_from_sql(
+    mut raw: &[u8],
+    precision: u8,
+    scale: i8,
+) -> Result<i128, Box<dyn error::Error + Sync + Send>> {
+    let ndigits = raw.read_i16::<BigEndian>()?;
+    let weight = raw.read_i16::<BigEndian>()?;
+    let sign = raw.read_u16::<BigEndian>()?;
+    let _dscale = raw.read_i16::<BigEndian>()?;
+
+    let negative = match sign {
+        0x0000 => false,
+        0x4000 => true,
+        0xc000 => return Err("NaN cannot be converted to a decimal".into()),
+        _ => return Err("infinity cannot be converted to a decimal".into()),
+    };
+
+    let overflow = || "numeric value does not fit in the decimal type";
+    let mut value = 0i128;
+    for i in 0..ndigits {
+        let digit = i128::from(raw.read_i16::<BigEndian>()?);
+        // the decimal exponent of this base 10000 digit, relative to the scale
+        let exponent = 4 * (i32::from(weight) - i32::from(i)) + i32::from(scale);
+        let digit = if exponent >= 0 {
+            10i128
+                .checked_pow(exponent as u32)
+                .and_then(|power| digit.checked_mul(power))
+                .ok_or_else(overflow)?
+        } else {
+            let power = 10i128.checked_pow(-exponent as u32).unwrap_or(i128::MAX);
+            if digit % power != 0 {
+                return Err(
+                    "numeric value has more digits than the scale of the decimal type".into(),
+                );
+            }
+            digit / power
+        };
+        value = value.checked_add(digit).ok_or_else(overflow)?;
+    }
+
+    if value >= 10i128.pow(u32::from(precision)) {
+        return Err(overflow().into());
+    }
+
+    if !raw.is_empty() {
+        return Err("invalid buffer size".into());
+    }
+
+    Ok(if negative { -value } else { value })
+}
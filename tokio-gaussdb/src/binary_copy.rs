@@ -1,6 +1,6 @@
 //! Utilities for working with the PostgreSQL binary copy format.
 
-use crate::types::{FromSql, IsNull, ToSql, Type, WrongType};
+use crate::types::{FromSql, IsNull, ToParams, ToSql, Type, WrongType};
 use crate::{slice_iter, CopyInSink, CopyOutStream, Error};
 use byteorder::{BigEndian, ByteOrder};
 use bytes::{Buf, BufMut, Bytes, BytesMut};
@@ -54,6 +54,21 @@ impl BinaryCopyInWriter {
         self.write_raw(slice_iter(values)).await
     }
 
+    /// Writes a single row from the parameters of a value.
+    ///
+    /// The writer's types must be in the order of `ToParams::param_names`, as in the statement returned by
+    /// `ToParams::copy_in_query`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of parameters does not match the number expected.
+    pub async fn write_params<T>(self: Pin<&mut Self>, value: &T) -> Result<(), Error>
+    where
+        T: ?Sized + ToParams,
+    {
+        self.write(&value.to_params()).await
+    }
+
     /// A maximally-flexible version of `write`.
     ///
     /// # Panics
@@ -134,6 +149,11 @@ impl BinaryCopyOutStream {
             header: None,
         }
     }
+
+    #[cfg(feature = "arrow")]
+    pub(crate) fn types(&self) -> &[Type] {
+        &self.types
+    }
 }
 
 impl Stream for BinaryCopyOutStream {
@@ -231,6 +251,11 @@ pub struct BinaryCopyOutRow {
 }
 
 impl BinaryCopyOutRow {
+    #[cfg(feature = "arrow")]
+    pub(crate) fn raw(&self, idx: usize) -> Option<&[u8]> {
+        self.ranges[idx].clone().map(|range| &self.buf[range])
+    }
+
     /// Like `get`, but returns a `Result` rather than panicking.
     pub fn try_get<'a, T>(&'a self, idx: usize) -> Result<T, Error>
     where
@@ -0,0 +1,143 @@
+use crate::client::{InnerClient, Responses};
+use crate::codec::FrontendMessage;
+use crate::connection::RequestMessages;
+use crate::query::{self, extract_row_affected};
+use crate::types::BorrowToSql;
+use crate::{Error, Row, Statement};
+use bytes::BytesMut;
+use futures_channel::mpsc;
+use futures_util::{ready, Stream};
+use gaussdb_protocol::message::backend::Message;
+use gaussdb_protocol::message::frontend;
+use pin_project_lite::pin_project;
+use std::marker::PhantomPinned;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+pub fn query_chunked<P, I>(
+    client: &InnerClient,
+    statement: Statement,
+    params: I,
+    fetch_size: i32,
+) -> Result<ChunkedRowStream, Error>
+where
+    P: BorrowToSql,
+    I: IntoIterator<Item = P>,
+    I::IntoIter: ExactSizeIterator,
+{
+    let buf = client.with_buf(|buf| {
+        query::encode_bind(&statement, params, "", buf)?;
+        frontend::execute("", fetch_size, buf).map_err(Error::encode)?;
+        frontend::flush(buf);
+        Ok(buf.split().freeze())
+    })?;
+
+    let (sender, receiver) = mpsc::unbounded();
+    sender
+        .unbounded_send(FrontendMessage::Raw(buf))
+        .map_err(|_| Error::closed())?;
+    let responses = client.send(RequestMessages::Chunked(receiver))?;
+
+    Ok(ChunkedRowStream {
+        statement,
+        responses,
+        sender: Some(sender),
+        fetch_size,
+        suspended: 0,
+        rows_affected: None,
+        _p: PhantomPinned,
+    })
+}
+
+pin_project! {
+    /// A stream of table rows fetched from an unnamed portal a fixed number of rows at a time.
+    ///
+    /// The connection is reserved for the stream until it has been exhausted or dropped.
+    pub struct ChunkedRowStream {
+        statement: Statement,
+        responses: Responses,
+        sender: Option<mpsc::UnboundedSender<FrontendMessage>>,
+        fetch_size: i32,
+        suspended: u64,
+        rows_affected: Option<u64>,
+        #[pin]
+        _p: PhantomPinned,
+    }
+
+    impl PinnedDrop for ChunkedRowStream {
+        fn drop(this: Pin<&mut Self>) {
+            let this = this.project();
+            if let Some(sender) = this.sender.take() {
+                // the portal is destroyed along with the implicit transaction, and any rows still in flight are
+                // discarded along with our response receiver
+                let mut buf = BytesMut::new();
+                frontend::close(b'P', "", &mut buf).unwrap();
+                frontend::sync(&mut buf);
+                let _ = sender.unbounded_send(FrontendMessage::Raw(buf.freeze()));
+            }
+        }
+    }
+}
+
+impl Stream for ChunkedRowStream {
+    type Item = Result<Row, Error>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        loop {
+            let message = match ready!(this.responses.poll_next(cx)) {
+                Ok(message) => message,
+                Err(e) => {
+                    // the server skips everything up to the next Sync after an error
+                    sync(this.sender);
+                    return Poll::Ready(Some(Err(e)));
+                }
+            };
+
+            match message {
+                Message::BindComplete => {}
+                Message::DataRow(body) => {
+                    return Poll::Ready(Some(Ok(Row::new(this.statement.clone(), body, false)?)))
+                }
+                Message::PortalSuspended => {
+                    *this.suspended += 1;
+                    if let Some(sender) = this.sender {
+                        let mut buf = BytesMut::new();
+                        frontend::execute("", *this.fetch_size, &mut buf).map_err(Error::encode)?;
+                        frontend::flush(&mut buf);
+                        sender
+                            .unbounded_send(FrontendMessage::Raw(buf.freeze()))
+                            .map_err(|_| Error::closed())?;
+                    }
+                }
+                Message::CommandComplete(body) => {
+                    // the tag only counts the rows of the final execution of the portal
+                    let rows = extract_row_affected(&body)?;
+                    *this.rows_affected = Some(*this.suspended * *this.fetch_size as u64 + rows);
+                    sync(this.sender);
+                }
+                Message::EmptyQueryResponse => sync(this.sender),
+                Message::ReadyForQuery(_) => return Poll::Ready(None),
+                _ => return Poll::Ready(Some(Err(Error::unexpected_message()))),
+            }
+        }
+    }
+}
+
+impl ChunkedRowStream {
+    /// Returns the number of rows affected by the query.
+    ///
+    /// This function will return `None` until the stream has been exhausted.
+    pub fn rows_affected(&self) -> Option<u64> {
+        self.rows_affected
+    }
+}
+
+// Ends the request, releasing the connection once the server responds with ReadyForQuery.
+fn sync(sender: &mut Option<mpsc::UnboundedSender<FrontendMessage>>) {
+    if let Some(sender) = sender.take() {
+        let mut buf = BytesMut::new();
+        frontend::sync(&mut buf);
+        let _ = sender.unbounded_send(FrontendMessage::Raw(buf.freeze()));
+    }
+}
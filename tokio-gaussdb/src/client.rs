@@ -4,34 +4,41 @@ use crate::connection::{Request, RequestMessages};
 use crate::copy_out::CopyOutStream;
 #[cfg(feature = "runtime")]
 use crate::keepalive::KeepaliveConfig;
+use crate::named_params::NamedQuery;
 use crate::query::RowStream;
 use crate::simple_query::SimpleQueryStream;
 #[cfg(feature = "runtime")]
 use crate::tls::MakeTlsConnect;
+#[cfg(feature = "runtime")]
+use crate::tls::NoTls;
 use crate::tls::TlsConnect;
 use crate::types::{Oid, ToSql, Type};
 #[cfg(feature = "runtime")]
 use crate::Socket;
 use crate::{
-    copy_in, copy_out, prepare, query, simple_query, slice_iter, CancelToken, CopyInSink, Error,
-    Row, SimpleQueryMessage, Statement, ToStatement, Transaction, TransactionBuilder,
+    chunked, copy_in, copy_out, prepare, query, simple_query, slice_iter, CancelToken,
+    ChunkedRowStream, Column, CopyInSink, CursorBuilder, Error, FromRow, Row, SimpleQueryMessage,
+    Statement, StatementCache, ToStatement, Transaction, TransactionBuilder,
 };
-use bytes::{Buf, BytesMut};
+use bytes::{Buf, Bytes, BytesMut};
 use fallible_iterator::FallibleIterator;
 use futures_channel::mpsc;
 use futures_util::{future, pin_mut, ready, StreamExt, TryStreamExt};
 use gaussdb_protocol::message::backend::Message;
 use gaussdb_types::BorrowToSql;
+use log::debug;
 use parking_lot::Mutex;
 use std::collections::HashMap;
 use std::fmt;
+use std::future::Future;
 #[cfg(feature = "runtime")]
 use std::net::IpAddr;
 #[cfg(all(feature = "runtime", unix))]
 use std::path::PathBuf;
+#[cfg(feature = "runtime")]
+use std::pin::Pin;
 use std::sync::Arc;
 use std::task::{Context, Poll};
-#[cfg(feature = "runtime")]
 use std::time::Duration;
 use tokio::io::{AsyncRead, AsyncWrite};
 
@@ -85,6 +92,10 @@ pub struct InnerClient {
     sender: mpsc::UnboundedSender<Request>,
     cached_typeinfo: Mutex<CachedTypeInfo>,
 
+    /// Statements prepared again after the server reported that a statement could no longer be used, keyed by the
+    /// name of the original statement.
+    reprepared: Mutex<HashMap<String, Statement>>,
+
     /// A buffer to use when writing out postgres commands.
     buffer: Mutex<BytesMut>,
 }
@@ -139,6 +150,32 @@ impl InnerClient {
         self.cached_typeinfo.lock().types.clear();
     }
 
+    /// Returns the statement to execute in place of `statement`, which may have been prepared again.
+    pub fn reprepared(&self, statement: &Statement) -> Statement {
+        let reprepared = self.reprepared.lock();
+        if reprepared.is_empty() {
+            return statement.clone();
+        }
+        reprepared
+            .get(statement.name())
+            .cloned()
+            .unwrap_or_else(|| statement.clone())
+    }
+
+    pub fn set_reprepared(&self, statement: &Statement, replacement: Statement) {
+        let old = self
+            .reprepared
+            .lock()
+            .insert(statement.name().to_string(), replacement);
+        // dropping the old replacement closes it, which takes the lock again
+        drop(old);
+    }
+
+    pub fn remove_reprepared(&self, name: &str) {
+        let old = self.reprepared.lock().remove(name);
+        drop(old);
+    }
+
     /// Call the given function with a buffer to be used when writing out
     /// postgres commands.
     pub fn with_buf<F, R>(&self, f: F) -> R
@@ -163,6 +200,29 @@ pub(crate) struct SocketConfig {
     pub keepalive: Option<KeepaliveConfig>,
 }
 
+/// Sends cancellation requests with the TLS connector set by `Client::set_cancel_tls`.
+#[cfg(feature = "runtime")]
+type CancelFn =
+    dyn Fn(CancelToken) -> Pin<Box<dyn Future<Output = Result<(), Error>> + Send>> + Send + Sync;
+
+// Lets the connector be used for any number of cancellation requests while it is shared by the client.
+#[cfg(feature = "runtime")]
+struct SharedTls<T>(Arc<Mutex<T>>);
+
+#[cfg(feature = "runtime")]
+impl<T> MakeTlsConnect<Socket> for SharedTls<T>
+where
+    T: MakeTlsConnect<Socket>,
+{
+    type Stream = T::Stream;
+    type TlsConnect = T::TlsConnect;
+    type Error = T::Error;
+
+    fn make_tls_connect(&mut self, domain: &str) -> Result<T::TlsConnect, T::Error> {
+        self.0.lock().make_tls_connect(domain)
+    }
+}
+
 #[cfg(feature = "runtime")]
 #[derive(Clone)]
 pub(crate) enum Addr {
@@ -183,6 +243,11 @@ pub struct Client {
     ssl_negotiation: SslNegotiation,
     process_id: i32,
     secret_key: i32,
+    statement_timeout: Option<Duration>,
+    #[cfg(feature = "runtime")]
+    cancel_tls: Option<Box<CancelFn>>,
+    statement_cache: StatementCache,
+    in_transaction: bool,
 }
 
 impl Client {
@@ -192,11 +257,14 @@ impl Client {
         ssl_negotiation: SslNegotiation,
         process_id: i32,
         secret_key: i32,
+        statement_timeout: Option<Duration>,
+        statement_cache_capacity: usize,
     ) -> Client {
         Client {
             inner: Arc::new(InnerClient {
                 sender,
                 cached_typeinfo: Default::default(),
+                reprepared: Default::default(),
                 buffer: Default::default(),
             }),
             #[cfg(feature = "runtime")]
@@ -205,6 +273,11 @@ impl Client {
             ssl_negotiation,
             process_id,
             secret_key,
+            statement_timeout,
+            #[cfg(feature = "runtime")]
+            cancel_tls: None,
+            statement_cache: StatementCache::new(statement_cache_capacity),
+            in_transaction: false,
         }
     }
 
@@ -212,11 +285,36 @@ impl Client {
         &self.inner
     }
 
+    pub(crate) fn set_in_transaction(&mut self, in_transaction: bool) {
+        self.in_transaction = in_transaction;
+    }
+
     #[cfg(feature = "runtime")]
     pub(crate) fn set_socket_config(&mut self, socket_config: SocketConfig) {
         self.socket_config = Some(socket_config);
     }
 
+    /// Sets the TLS connector used to send cancellation requests when a statement times out.
+    ///
+    /// By default these requests are sent without TLS, which servers requiring it reject. See
+    /// [`set_statement_timeout`](Client::set_statement_timeout).
+    ///
+    /// Requires the `runtime` Cargo feature (enabled by default).
+    #[cfg(feature = "runtime")]
+    pub fn set_cancel_tls<T>(&mut self, tls: T)
+    where
+        T: MakeTlsConnect<Socket> + Send + 'static,
+        T::Stream: Send,
+        T::TlsConnect: Send,
+        <T::TlsConnect as TlsConnect<Socket>>::Future: Send,
+    {
+        let tls = Arc::new(Mutex::new(tls));
+        self.cancel_tls = Some(Box::new(move |cancel_token| {
+            let tls = SharedTls(tls.clone());
+            Box::pin(async move { cancel_token.cancel_query(tls).await })
+        }));
+    }
+
     /// Creates a new prepared statement.
     ///
     /// Prepared statements can be executed repeatedly, and may contain query parameters (indicated by `$1`, `$2`, etc),
@@ -234,7 +332,34 @@ impl Client {
         query: &str,
         parameter_types: &[Type],
     ) -> Result<Statement, Error> {
-        prepare::prepare(&self.inner, query, parameter_types).await
+        self.timed(prepare::prepare(&self.inner, query, parameter_types))
+            .await
+    }
+
+    /// Looks up whether each of the provided columns may contain `NULL` values in the system catalog.
+    ///
+    /// The server does not report the nullability of a query's columns, so this queries `pg_attribute` for the table
+    /// column each result column was taken from. The result contains `Some(false)` for `NOT NULL` columns,
+    /// `Some(true)` for nullable ones, and `None` for columns which are not a plain reference to a table column, such
+    /// as computed expressions. Note that a column of an outer join may be `NULL` even if its table column is not.
+    pub async fn column_nullability(&self, columns: &[Column]) -> Result<Vec<Option<bool>>, Error> {
+        let (tables, ids): (Vec<_>, Vec<_>) = columns
+            .iter()
+            .map(|column| (column.table_oid(), column.column_id()))
+            .unzip();
+
+        let rows = self
+            .query(
+                "SELECT NOT a.attnotnull \
+                 FROM generate_subscripts($1::pg_catalog.oid[], 1) AS i \
+                 LEFT JOIN pg_catalog.pg_attribute a \
+                 ON a.attrelid = ($1::pg_catalog.oid[])[i] AND a.attnum = ($2::pg_catalog.int2[])[i] \
+                 ORDER BY i",
+                &[&tables, &ids],
+            )
+            .await?;
+
+        Ok(rows.iter().map(|row| row.get(0)).collect())
     }
 
     /// Executes a statement, returning a vector of the resulting rows.
@@ -253,10 +378,36 @@ impl Client {
     where
         T: ?Sized + ToStatement,
     {
-        self.query_raw(statement, slice_iter(params))
-            .await?
-            .try_collect()
-            .await
+        self.timed(async {
+            self.query_params(statement, params, false)
+                .await?
+                .try_collect()
+                .await
+        })
+        .await
+    }
+
+    /// Like [`query`], but requests the resulting values in the text format.
+    ///
+    /// Values of the returned rows are read with [`Row::get_text`] using the `FromSqlText` trait, which makes it
+    /// possible to retrieve values of types lacking a binary `FromSql` implementation.
+    ///
+    /// [`query`]: #method.query
+    pub async fn query_text<T>(
+        &self,
+        statement: &T,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Vec<Row>, Error>
+    where
+        T: ?Sized + ToStatement,
+    {
+        self.timed(async {
+            self.query_params(statement, params, true)
+                .await?
+                .try_collect()
+                .await
+        })
+        .await
     }
 
     /// Executes a statement which returns a single row, returning it.
@@ -300,25 +451,97 @@ impl Client {
     where
         T: ?Sized + ToStatement,
     {
-        let stream = self.query_raw(statement, slice_iter(params)).await?;
-        pin_mut!(stream);
-
-        let mut first = None;
-
-        // Originally this was two calls to `try_next().await?`,
-        // once for the first element, and second to error if more than one.
-        //
-        // However, this new form with only one .await in a loop generates
-        // slightly smaller codegen/stack usage for the resulting future.
-        while let Some(row) = stream.try_next().await? {
-            if first.is_some() {
-                return Err(Error::row_count());
+        self.timed(async {
+            let stream = self.query_params(statement, params, false).await?;
+            pin_mut!(stream);
+
+            let mut first = None;
+
+            // Originally this was two calls to `try_next().await?`,
+            // once for the first element, and second to error if more than one.
+            //
+            // However, this new form with only one .await in a loop generates
+            // slightly smaller codegen/stack usage for the resulting future.
+            while let Some(row) = stream.try_next().await? {
+                if first.is_some() {
+                    return Err(Error::row_count());
+                }
+
+                first = Some(row);
             }
 
-            first = Some(row);
-        }
+            Ok(first)
+        })
+        .await
+    }
 
-        Ok(first)
+    /// Like [`query`], but converts each row into `R` with its [`FromRow`] implementation.
+    ///
+    /// ```no_run
+    /// # #[cfg(feature = "derive")]
+    /// # async fn async_main(client: &tokio_gaussdb::Client) -> Result<(), tokio_gaussdb::Error> {
+    /// use tokio_gaussdb::FromRow;
+    ///
+    /// #[derive(FromRow)]
+    /// struct User {
+    ///     id: i32,
+    ///     name: String,
+    /// }
+    ///
+    /// let users = client.query_as::<User, _>("SELECT id, name FROM users", &[]).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`query`]: #method.query
+    pub async fn query_as<R, T>(
+        &self,
+        statement: &T,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Vec<R>, Error>
+    where
+        R: FromRow,
+        T: ?Sized + ToStatement,
+    {
+        self.query(statement, params)
+            .await?
+            .iter()
+            .map(R::from_row)
+            .collect()
+    }
+
+    /// Like [`query_one`], but converts the row into `R` with its [`FromRow`] implementation.
+    ///
+    /// [`query_one`]: #method.query_one
+    pub async fn query_one_as<R, T>(
+        &self,
+        statement: &T,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<R, Error>
+    where
+        R: FromRow,
+        T: ?Sized + ToStatement,
+    {
+        R::from_row(&self.query_one(statement, params).await?)
+    }
+
+    /// Like [`query_opt`], but converts the row into `R` with its [`FromRow`] implementation.
+    ///
+    /// [`query_opt`]: #method.query_opt
+    pub async fn query_opt_as<R, T>(
+        &self,
+        statement: &T,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<R>, Error>
+    where
+        R: FromRow,
+        T: ?Sized + ToStatement,
+    {
+        self.query_opt(statement, params)
+            .await?
+            .as_ref()
+            .map(R::from_row)
+            .transpose()
     }
 
     /// The maximally flexible version of [`query`].
@@ -330,6 +553,9 @@ impl Client {
     /// repeatedly executed (perhaps with different query parameters), consider preparing the statement up front
     /// with the `prepare` method.
     ///
+    /// Since `params` can only be iterated once, a statement which the server reports can no longer be used is not
+    /// prepared again and retried as it is by [`query`].
+    ///
     /// [`query`]: #method.query
     ///
     /// # Examples
@@ -361,9 +587,82 @@ impl Client {
         P: BorrowToSql,
         I: IntoIterator<Item = P>,
         I::IntoIter: ExactSizeIterator,
+    {
+        self.timed(async {
+            let statement = statement.__convert().into_statement(self).await?;
+            let current = self.inner.reprepared(&statement);
+            let result = query::query(&self.inner, current, params).await;
+            self.invalidate_if_stale(&statement, result)
+        })
+        .await
+    }
+
+    /// Executes a statement, fetching the resulting rows `fetch_size` at a time.
+    ///
+    /// The statement is bound to an unnamed portal in an implicit transaction (or the current transaction, if there
+    /// is one), and further rows are only requested from the server once the previous batch has been consumed. This
+    /// keeps memory use bounded for queries returning very many rows.
+    ///
+    /// No other requests are sent on the connection until the stream has been exhausted or dropped. Dropping the
+    /// stream early closes the portal and discards any rows already in flight.
+    ///
+    /// A statement may contain parameters, specified by `$n`, where `n` is the index of the parameter of the list
+    /// provided, 1-indexed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of parameters provided does not match the number expected.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn async_main(client: &tokio_gaussdb::Client) -> Result<(), tokio_gaussdb::Error> {
+    /// use futures_util::{pin_mut, TryStreamExt};
+    ///
+    /// let stream = client
+    ///     .query_chunked("SELECT * FROM generate_series(1, 1000000)", &[], 1000)
+    ///     .await?;
+    /// pin_mut!(stream);
+    ///
+    /// while let Some(row) = stream.try_next().await? {
+    ///     let n: i32 = row.get(0);
+    ///     println!("{}", n);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn query_chunked<T>(
+        &self,
+        statement: &T,
+        params: &[&(dyn ToSql + Sync)],
+        fetch_size: i32,
+    ) -> Result<ChunkedRowStream, Error>
+    where
+        T: ?Sized + ToStatement,
     {
         let statement = statement.__convert().into_statement(self).await?;
-        query::query(&self.inner, statement, params).await
+        let statement = self.inner.reprepared(&statement);
+        chunked::query_chunked(&self.inner, statement, slice_iter(params), fetch_size)
+    }
+
+    async fn query_params<T>(
+        &self,
+        statement: &T,
+        params: &[&(dyn ToSql + Sync)],
+        text: bool,
+    ) -> Result<RowStream, Error>
+    where
+        T: ?Sized + ToStatement,
+    {
+        let from_query = statement.__convert().is_query();
+        let statement = statement.__convert().into_statement(self).await?;
+        self.run_reprepared(
+            &statement,
+            from_query,
+            |statement| query::encode_logged(&self.inner, statement, slice_iter(params), text),
+            |statement, buf| query::query_encoded(&self.inner, statement, buf, text),
+        )
+        .await
     }
 
     /// Like `query`, but requires the types of query parameters to be explicitly specified.
@@ -380,10 +679,17 @@ impl Client {
         query: &str,
         params: &[(&(dyn ToSql + Sync), Type)],
     ) -> Result<Vec<Row>, Error> {
-        self.query_typed_raw(query, params.iter().map(|(v, t)| (*v, t.clone())))
+        self.timed(async {
+            query::query_typed(
+                &self.inner,
+                query,
+                params.iter().map(|(v, t)| (*v, t.clone())),
+            )
             .await?
             .try_collect()
             .await
+        })
+        .await
     }
 
     /// The maximally flexible version of [`query_typed`].
@@ -427,7 +733,8 @@ impl Client {
         P: BorrowToSql,
         I: IntoIterator<Item = (P, Type)>,
     {
-        query::query_typed(&self.inner, query, params).await
+        self.timed(query::query_typed(&self.inner, query, params))
+            .await
     }
 
     /// Executes a statement, returning the number of rows modified.
@@ -448,7 +755,18 @@ impl Client {
     where
         T: ?Sized + ToStatement,
     {
-        self.execute_raw(statement, slice_iter(params)).await
+        self.timed(async {
+            let from_query = statement.__convert().is_query();
+            let statement = statement.__convert().into_statement(self).await?;
+            self.run_reprepared(
+                &statement,
+                from_query,
+                |statement| query::encode_logged(&self.inner, statement, slice_iter(params), false),
+                |_, buf| query::execute(&self.inner, buf),
+            )
+            .await
+        })
+        .await
     }
 
     /// The maximally flexible version of [`execute`].
@@ -460,6 +778,9 @@ impl Client {
     /// repeatedly executed (perhaps with different query parameters), consider preparing the statement up front
     /// with the `prepare` method.
     ///
+    /// Since `params` can only be iterated once, a statement which the server reports can no longer be used is not
+    /// prepared again and retried as it is by [`execute`].
+    ///
     /// [`execute`]: #method.execute
     pub async fn execute_raw<T, P, I>(&self, statement: &T, params: I) -> Result<u64, Error>
     where
@@ -468,8 +789,98 @@ impl Client {
         I: IntoIterator<Item = P>,
         I::IntoIter: ExactSizeIterator,
     {
-        let statement = statement.__convert().into_statement(self).await?;
-        query::execute(self.inner(), statement, params).await
+        self.timed(async {
+            let statement = statement.__convert().into_statement(self).await?;
+            let current = self.inner.reprepared(&statement);
+            let buf = query::encode_logged(&self.inner, &current, params, false)?;
+            let result = query::execute(&self.inner, buf).await;
+            self.invalidate_if_stale(&statement, result)
+        })
+        .await
+    }
+
+    /// Like [`query`], but with parameters specified by name rather than position.
+    ///
+    /// Parameters are written `:name` or `@name` in the query, and may appear more than once. They are rewritten to
+    /// positional `$n` parameters before the query is prepared, skipping over string literals, quoted identifiers,
+    /// comments, dollar-quoted strings, `::` casts and the bounds of array slices like `arr[lo:hi]`. An `@` directly
+    /// following an identifier or operator character, as in `a@b` or `<@`, does not start a parameter. A query may not
+    /// mix named and positional parameters.
+    ///
+    /// Returns an error if a parameter in the query has no value, or a value is given for a parameter which does not
+    /// appear in the query.
+    ///
+    /// Values held in a map can be passed with [`NamedParams::named_params`], and the fields of a struct deriving
+    /// [`ToParams`] with [`ToParams::to_named_params`].
+    ///
+    /// [`NamedParams::named_params`]: crate::types::NamedParams::named_params
+    /// [`ToParams`]: crate::types::ToParams
+    /// [`ToParams::to_named_params`]: crate::types::ToParams::to_named_params
+    ///
+    /// [`query`]: #method.query
+    pub async fn query_named(
+        &self,
+        query: &str,
+        params: &[(&str, &(dyn ToSql + Sync))],
+    ) -> Result<Vec<Row>, Error> {
+        let named = self.named_query(query)?;
+        self.query(named.sql(), &named.params(params)?).await
+    }
+
+    /// Like [`query_one`], but with parameters specified by name rather than position.
+    ///
+    /// See [`query_named`] for details.
+    ///
+    /// [`query_one`]: #method.query_one
+    /// [`query_named`]: #method.query_named
+    pub async fn query_one_named(
+        &self,
+        query: &str,
+        params: &[(&str, &(dyn ToSql + Sync))],
+    ) -> Result<Row, Error> {
+        let named = self.named_query(query)?;
+        self.query_one(named.sql(), &named.params(params)?).await
+    }
+
+    /// Like [`query_opt`], but with parameters specified by name rather than position.
+    ///
+    /// See [`query_named`] for details.
+    ///
+    /// [`query_opt`]: #method.query_opt
+    /// [`query_named`]: #method.query_named
+    pub async fn query_opt_named(
+        &self,
+        query: &str,
+        params: &[(&str, &(dyn ToSql + Sync))],
+    ) -> Result<Option<Row>, Error> {
+        let named = self.named_query(query)?;
+        self.query_opt(named.sql(), &named.params(params)?).await
+    }
+
+    /// Like [`execute`], but with parameters specified by name rather than position.
+    ///
+    /// See [`query_named`] for details.
+    ///
+    /// [`execute`]: #method.execute
+    /// [`query_named`]: #method.query_named
+    pub async fn execute_named(
+        &self,
+        query: &str,
+        params: &[(&str, &(dyn ToSql + Sync))],
+    ) -> Result<u64, Error> {
+        let named = self.named_query(query)?;
+        self.execute(named.sql(), &named.params(params)?).await
+    }
+
+    /// Rewrites a query with named parameters, going through the statement cache when it is enabled.
+    fn named_query(&self, query: &str) -> Result<Arc<NamedQuery>, Error> {
+        if let Some(named) = self.statement_cache.get_named(query) {
+            return Ok(named);
+        }
+
+        let named = Arc::new(NamedQuery::parse(query)?);
+        self.statement_cache.insert_named(query, named.clone());
+        Ok(named)
     }
 
     /// Executes a `COPY FROM STDIN` statement, returning a sink used to write the copy data.
@@ -510,7 +921,8 @@ impl Client {
     /// functionality to safely embed that data in the request. Do not form statements via string concatenation and pass
     /// them to this method!
     pub async fn simple_query(&self, query: &str) -> Result<Vec<SimpleQueryMessage>, Error> {
-        self.simple_query_raw(query).await?.try_collect().await
+        self.timed(async { self.simple_query_raw(query).await?.try_collect().await })
+            .await
     }
 
     pub(crate) async fn simple_query_raw(&self, query: &str) -> Result<SimpleQueryStream, Error> {
@@ -528,7 +940,8 @@ impl Client {
     /// functionality to safely embed that data in the request. Do not form statements via string concatenation and pass
     /// them to this method!
     pub async fn batch_execute(&self, query: &str) -> Result<(), Error> {
-        simple_query::batch_execute(self.inner(), query).await
+        self.timed(simple_query::batch_execute(self.inner(), query))
+            .await
     }
 
     /// Begins a new database transaction.
@@ -546,6 +959,13 @@ impl Client {
         TransactionBuilder::new(self)
     }
 
+    /// Returns a builder for a server-side cursor over the rows of a query.
+    ///
+    /// Outside of a transaction, the cursor must be declared `WITH HOLD` by enabling the builder's `hold` option.
+    pub fn build_cursor(&self, query: &str) -> CursorBuilder<'_> {
+        CursorBuilder::new(self, query)
+    }
+
     /// Constructs a cancellation token that can later be used to request cancellation of a query running on the
     /// connection associated with this client.
     pub fn cancel_token(&self) -> CancelToken {
@@ -585,6 +1005,200 @@ impl Client {
         self.cancel_token().cancel_query_raw(stream, tls).await
     }
 
+    /// Returns the default timeout applied to statements executed by this client.
+    ///
+    /// This is initialized from [`Config::statement_timeout`](crate::Config::statement_timeout).
+    pub fn statement_timeout(&self) -> Option<Duration> {
+        self.statement_timeout
+    }
+
+    /// Sets the default timeout applied to statements executed by this client, or removes it if `None`.
+    ///
+    /// When a request does not complete within the timeout, the client sends a cancellation request to the server,
+    /// waits for the connection to finish processing the cancelled request, and returns an error for which
+    /// [`Error::is_timeout`] returns `true`. If the request completes successfully in the meantime, its result is
+    /// returned instead.
+    ///
+    /// The timeout covers `prepare`, `query`, `execute`, `simple_query`, `batch_execute` and their variants, as well
+    /// as the corresponding methods on transactions. Methods returning a stream only time the request up to the point
+    /// where the stream is returned. The cancellation request is sent with the connector set by
+    /// [`set_cancel_tls`](Client::set_cancel_tls), or without TLS if there is none.
+    ///
+    /// Timeouts are only enforced when the `runtime` Cargo feature is enabled.
+    pub fn set_statement_timeout(&mut self, statement_timeout: Option<Duration>) {
+        self.statement_timeout = statement_timeout;
+    }
+
+    /// Runs a request on this client with the given timeout.
+    ///
+    /// The request is cancelled in the same way as for the default statement timeout described in
+    /// [`set_statement_timeout`](Client::set_statement_timeout). The client's default timeout still applies to the
+    /// individual requests made by `future`.
+    ///
+    /// Requires the `runtime` Cargo feature (enabled by default).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn async_main(client: &tokio_gaussdb::Client) -> Result<(), tokio_gaussdb::Error> {
+    /// use std::time::Duration;
+    ///
+    /// let rows = client
+    ///     .with_timeout(Duration::from_secs(5), client.query("SELECT * FROM big_table", &[]))
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "runtime")]
+    pub async fn with_timeout<F, T>(&self, timeout: Duration, future: F) -> Result<T, Error>
+    where
+        F: Future<Output = Result<T, Error>>,
+    {
+        self.run_with_timeout(timeout, future).await
+    }
+
+    #[cfg(feature = "runtime")]
+    async fn timed<F, T>(&self, future: F) -> Result<T, Error>
+    where
+        F: Future<Output = Result<T, Error>>,
+    {
+        match self.statement_timeout {
+            Some(timeout) => self.run_with_timeout(timeout, future).await,
+            None => future.await,
+        }
+    }
+
+    #[cfg(not(feature = "runtime"))]
+    async fn timed<F, T>(&self, future: F) -> Result<T, Error>
+    where
+        F: Future<Output = Result<T, Error>>,
+    {
+        future.await
+    }
+
+    #[cfg(feature = "runtime")]
+    async fn run_with_timeout<F, T>(&self, timeout: Duration, future: F) -> Result<T, Error>
+    where
+        F: Future<Output = Result<T, Error>>,
+    {
+        pin_mut!(future);
+        if let Ok(result) = tokio::time::timeout(timeout, &mut future).await {
+            return result;
+        }
+
+        debug!("statement timed out after {:?}, cancelling", timeout);
+        let cancel_token = self.cancel_token();
+        let cancelled = match &self.cancel_tls {
+            Some(cancel) => cancel(cancel_token).await,
+            None => cancel_token.cancel_query(NoTls).await,
+        };
+        if let Err(e) = cancelled {
+            debug!("error sending cancellation request: {}", e);
+            return Err(Error::statement_timeout());
+        }
+
+        // Wait for the server to abort the request so that the cancellation can't hit a later one. The connection
+        // discards the remaining responses up to ReadyForQuery even if we give up here.
+        match tokio::time::timeout(timeout, future).await {
+            Ok(Ok(value)) => Ok(value),
+            _ => Err(Error::statement_timeout()),
+        }
+    }
+
+    /// Returns the client's cache of statements prepared for query strings.
+    pub fn statement_cache(&self) -> &StatementCache {
+        &self.statement_cache
+    }
+
+    /// Sets the number of statements the client caches for query strings passed to methods like `query` and
+    /// `execute`, evicting statements if the cache holds more than `capacity`.
+    ///
+    /// This is initialized from [`Config::statement_cache_capacity`](crate::Config::statement_cache_capacity). A
+    /// capacity of 0 disables the cache.
+    pub fn set_statement_cache_capacity(&mut self, capacity: usize) {
+        self.statement_cache.set_capacity(capacity);
+    }
+
+    /// Prepares a query string passed in place of a `Statement`, going through the statement cache if it is enabled.
+    ///
+    /// The statement is prepared without a timeout, since it is covered by that of the request it is prepared for.
+    pub(crate) async fn prepare_cached(&self, query: &str) -> Result<Statement, Error> {
+        if !self.statement_cache.is_enabled() {
+            return prepare::prepare(&self.inner, query, &[]).await;
+        }
+
+        if let Some(statement) = self.statement_cache.get(query, &[]) {
+            return Ok(statement);
+        }
+        let statement = prepare::prepare(&self.inner, query, &[]).await?;
+        self.statement_cache.insert(query, &[], statement.clone());
+        Ok(statement)
+    }
+
+    /// Removes a statement from the statement cache if the server reported that it can no longer be used.
+    pub(crate) fn invalidate_if_stale<T>(
+        &self,
+        statement: &Statement,
+        result: Result<T, Error>,
+    ) -> Result<T, Error> {
+        if let Err(e) = &result {
+            if e.is_stale_statement() {
+                debug!("invalidating stale statement {}", statement.name());
+                self.statement_cache.invalidate(statement);
+            }
+        }
+        result
+    }
+
+    /// Runs `f` with the statement currently standing in for `statement` and its parameters encoded by `encode`,
+    /// preparing the statement again and retrying once if the server reports that it can no longer be used.
+    ///
+    /// The retry is skipped inside a `Transaction`, since the error has already aborted it. It is also skipped if the
+    /// statement's result columns changed and the caller holds the `Statement` rather than having passed a query
+    /// string, since its `columns` would no longer describe the rows; an error asking for the statement to be
+    /// prepared again is returned instead.
+    async fn run_reprepared<E, F, Fut, T>(
+        &self,
+        statement: &Statement,
+        from_query: bool,
+        encode: E,
+        f: F,
+    ) -> Result<T, Error>
+    where
+        E: Fn(&Statement) -> Result<Bytes, Error>,
+        F: Fn(Statement, Bytes) -> Fut,
+        Fut: Future<Output = Result<T, Error>>,
+    {
+        let current = self.inner.reprepared(statement);
+        let buf = encode(&current)?;
+        let e = match f(current.clone(), buf).await {
+            Err(e) if e.is_stale_statement() && !statement.name().is_empty() => e,
+            result => return result,
+        };
+        if self.in_transaction {
+            return self.invalidate_if_stale(statement, Err(e));
+        }
+
+        debug!("preparing stale statement {} again", statement.name());
+        let replacement =
+            match prepare::prepare(&self.inner, statement.query(), statement.types()).await {
+                Ok(replacement) if replacement.params() == current.params() => replacement,
+                // e.g. in a transaction block opened with `BEGIN`, which the error has aborted
+                _ => return self.invalidate_if_stale(statement, Err(e)),
+            };
+        if same_columns(replacement.columns(), statement.columns()) {
+            self.inner.set_reprepared(statement, replacement.clone());
+        } else if from_query {
+            self.statement_cache.invalidate(statement);
+            self.statement_cache
+                .insert(statement.query(), statement.types(), replacement.clone());
+        } else {
+            return self.invalidate_if_stale(statement, Err(Error::stale_statement(e)));
+        }
+        let buf = encode(&replacement)?;
+        f(replacement, buf).await
+    }
+
     /// Clears the client's type information cache.
     ///
     /// When user-defined types are used in a query, the client loads their definitions from the database and caches
@@ -601,12 +1215,34 @@ impl Client {
         self.inner.sender.is_closed()
     }
 
+    #[doc(hidden)]
+    pub async fn __private_api_reprepare(
+        &self,
+        statement: &Statement,
+        query: &str,
+    ) -> Result<Statement, Error> {
+        prepare::prepare_named(
+            &self.inner,
+            statement.name().to_string(),
+            query,
+            statement.params(),
+        )
+        .await
+    }
+
     #[doc(hidden)]
     pub fn __private_api_close(&mut self) {
         self.inner.sender.close_channel()
     }
 }
 
+fn same_columns(a: &[Column], b: &[Column]) -> bool {
+    a.len() == b.len()
+        && a.iter()
+            .zip(b)
+            .all(|(a, b)| a.name() == b.name() && a.type_() == b.type_())
+}
+
 impl fmt::Debug for Client {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("Client").finish()
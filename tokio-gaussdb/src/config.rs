@@ -229,6 +229,8 @@ pub struct Config {
     pub(crate) port: Vec<u16>,
     pub(crate) connect_timeout: Option<Duration>,
     pub(crate) tcp_user_timeout: Option<Duration>,
+    pub(crate) statement_timeout: Option<Duration>,
+    pub(crate) statement_cache_capacity: usize,
     pub(crate) keepalives: bool,
     #[cfg(not(target_arch = "wasm32"))]
     pub(crate) keepalive_config: KeepaliveConfig,
@@ -259,6 +261,8 @@ impl Config {
             port: vec![],
             connect_timeout: None,
             tcp_user_timeout: None,
+            statement_timeout: None,
+            statement_cache_capacity: 0,
             keepalives: true,
             #[cfg(not(target_arch = "wasm32"))]
             keepalive_config: KeepaliveConfig {
@@ -461,6 +465,43 @@ impl Config {
         self.tcp_user_timeout.as_ref()
     }
 
+    /// Sets the default timeout applied to statements executed by the client.
+    ///
+    /// The timeout is enforced by the client rather than the server: if a request does not complete in time, a
+    /// cancellation request is sent to the server and the call returns an error for which [`Error::is_timeout`]
+    /// returns `true`. It can be overridden later with [`Client::set_statement_timeout`]. Defaults to no limit.
+    ///
+    /// Timeouts are only enforced when the `runtime` Cargo feature is enabled.
+    ///
+    /// [`Error::is_timeout`]: crate::Error::is_timeout
+    /// [`Client::set_statement_timeout`]: crate::Client::set_statement_timeout
+    pub fn statement_timeout(&mut self, statement_timeout: Duration) -> &mut Config {
+        self.statement_timeout = Some(statement_timeout);
+        self
+    }
+
+    /// Gets the default statement timeout, if one has been set with the
+    /// `statement_timeout` method.
+    pub fn get_statement_timeout(&self) -> Option<&Duration> {
+        self.statement_timeout.as_ref()
+    }
+
+    /// Sets the number of statements the client caches for query strings passed to methods like `query` and
+    /// `execute`.
+    ///
+    /// See [`StatementCache`] for details. Defaults to 0, which disables the cache.
+    ///
+    /// [`StatementCache`]: crate::StatementCache
+    pub fn statement_cache_capacity(&mut self, capacity: usize) -> &mut Config {
+        self.statement_cache_capacity = capacity;
+        self
+    }
+
+    /// Gets the statement cache capacity.
+    pub fn get_statement_cache_capacity(&self) -> usize {
+        self.statement_cache_capacity
+    }
+
     /// Controls the use of TCP keepalive.
     ///
     /// This is ignored for Unix domain socket connections. Defaults to `true`.
@@ -783,6 +824,8 @@ impl fmt::Debug for Config {
             .field("port", &self.port)
             .field("connect_timeout", &self.connect_timeout)
             .field("tcp_user_timeout", &self.tcp_user_timeout)
+            .field("statement_timeout", &self.statement_timeout)
+            .field("statement_cache_capacity", &self.statement_cache_capacity)
             .field("keepalives", &self.keepalives);
 
         #[cfg(not(target_arch = "wasm32"))]
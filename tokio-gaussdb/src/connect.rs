@@ -185,7 +185,7 @@ where
 
             match next.await.transpose()? {
                 Some(SimpleQueryMessage::Row(row)) => {
-                    let read_only_result = row.try_get(0)?;
+                    let read_only_result: Option<&str> = row.try_get(0)?;
                     if read_only_result == Some("on")
                         && config.target_session_attrs == TargetSessionAttrs::ReadWrite
                     {
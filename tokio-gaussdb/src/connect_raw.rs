@@ -122,6 +122,8 @@ where
         config.ssl_negotiation,
         process_id,
         secret_key,
+        config.statement_timeout,
+        config.statement_cache_capacity,
     );
     let connection = Connection::new(stream.inner, stream.delayed, parameters, receiver);
 
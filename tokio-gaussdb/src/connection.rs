@@ -20,6 +20,7 @@ use tokio_util::codec::Framed;
 pub enum RequestMessages {
     Single(FrontendMessage),
     CopyIn(CopyInReceiver),
+    Chunked(mpsc::UnboundedReceiver<FrontendMessage>),
 }
 
 pub struct Request {
@@ -258,6 +259,24 @@ where
                         .map_err(Error::io)?;
                     self.pending_request = Some(RequestMessages::CopyIn(receiver));
                 }
+                RequestMessages::Chunked(mut receiver) => {
+                    let message = match receiver.poll_next_unpin(cx) {
+                        Poll::Ready(Some(message)) => message,
+                        Poll::Ready(None) => {
+                            trace!("poll_write: finished chunked request");
+                            continue;
+                        }
+                        Poll::Pending => {
+                            trace!("poll_write: waiting on chunked request");
+                            self.pending_request = Some(RequestMessages::Chunked(receiver));
+                            return Ok(true);
+                        }
+                    };
+                    Pin::new(&mut self.stream)
+                        .start_send(message)
+                        .map_err(Error::io)?;
+                    self.pending_request = Some(RequestMessages::Chunked(receiver));
+                }
             }
         }
     }
@@ -0,0 +1,231 @@
+use crate::client::InnerClient;
+use crate::codec::FrontendMessage;
+use crate::connection::RequestMessages;
+use crate::query;
+use crate::types::{ToSql, Type};
+use crate::{Client, Error, Row};
+use futures_util::{pin_mut, TryStreamExt};
+use gaussdb_protocol::message::frontend;
+use std::fmt;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Weak};
+
+static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// The rows a `FETCH` or `MOVE` on a cursor operates on.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum FetchDirection {
+    /// The next row.
+    Next,
+    /// The prior row.
+    Prior,
+    /// The first row.
+    First,
+    /// The last row.
+    Last,
+    /// The row at the given position, counting from the end if negative.
+    Absolute(i64),
+    /// The row at the given offset from the current position.
+    Relative(i64),
+    /// The next given number of rows.
+    Forward(i64),
+    /// All remaining rows.
+    ForwardAll,
+    /// The prior given number of rows.
+    Backward(i64),
+    /// All prior rows.
+    BackwardAll,
+}
+
+impl fmt::Display for FetchDirection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FetchDirection::Next => f.write_str("NEXT"),
+            FetchDirection::Prior => f.write_str("PRIOR"),
+            FetchDirection::First => f.write_str("FIRST"),
+            FetchDirection::Last => f.write_str("LAST"),
+            FetchDirection::Absolute(n) => write!(f, "ABSOLUTE {}", n),
+            FetchDirection::Relative(n) => write!(f, "RELATIVE {}", n),
+            FetchDirection::Forward(n) => write!(f, "FORWARD {}", n),
+            FetchDirection::ForwardAll => f.write_str("FORWARD ALL"),
+            FetchDirection::Backward(n) => write!(f, "BACKWARD {}", n),
+            FetchDirection::BackwardAll => f.write_str("BACKWARD ALL"),
+        }
+    }
+}
+
+/// A builder for server-side cursors.
+pub struct CursorBuilder<'a> {
+    client: &'a Client,
+    query: String,
+    scroll: Option<bool>,
+    hold: bool,
+}
+
+impl<'a> CursorBuilder<'a> {
+    pub(crate) fn new(client: &'a Client, query: &str) -> CursorBuilder<'a> {
+        CursorBuilder {
+            client,
+            query: query.to_string(),
+            scroll: None,
+            hold: false,
+        }
+    }
+
+    /// Sets whether the cursor can move backwards.
+    ///
+    /// If unset, the server decides based on the query.
+    pub fn scroll(mut self, scroll: bool) -> Self {
+        self.scroll = Some(scroll);
+        self
+    }
+
+    /// Sets whether the cursor remains open after the transaction that declared it commits.
+    ///
+    /// Cursors declared outside of a transaction must be held.
+    pub fn hold(mut self, hold: bool) -> Self {
+        self.hold = hold;
+        self
+    }
+
+    /// Declares the cursor, binding the query's parameters.
+    pub async fn declare(self, params: &[&(dyn ToSql + Sync)]) -> Result<Cursor, Error> {
+        let name = format!("c{}", NEXT_ID.fetch_add(1, Ordering::SeqCst));
+
+        let mut query = format!("DECLARE {}", name);
+        match self.scroll {
+            Some(true) => query.push_str(" SCROLL"),
+            Some(false) => query.push_str(" NO SCROLL"),
+            None => {}
+        }
+        query.push_str(" CURSOR");
+        if self.hold {
+            query.push_str(" WITH HOLD");
+        }
+        query.push_str(" FOR ");
+        query.push_str(&self.query);
+
+        self.client.execute(&*query, params).await?;
+
+        Ok(Cursor {
+            client: Arc::downgrade(self.client.inner()),
+            name,
+            closed: false,
+        })
+    }
+}
+
+/// A server-side cursor, declared with `DECLARE ... CURSOR`.
+///
+/// Unless declared with `WITH HOLD`, a cursor only exists for the duration of the transaction in which it was
+/// declared. The cursor is closed when dropped.
+pub struct Cursor {
+    client: Weak<InnerClient>,
+    name: String,
+    closed: bool,
+}
+
+impl Drop for Cursor {
+    fn drop(&mut self) {
+        if self.closed {
+            return;
+        }
+
+        // a declared cursor is a portal of the same name, and closing a portal which no longer exists is not an error
+        if let Some(client) = self.client.upgrade() {
+            let buf = client.with_buf(|buf| {
+                frontend::close(b'P', &self.name, buf).unwrap();
+                frontend::sync(buf);
+                buf.split().freeze()
+            });
+            let _ = client.send(RequestMessages::Single(FrontendMessage::Raw(buf)));
+        }
+    }
+}
+
+impl Cursor {
+    /// Returns the name of the cursor.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Fetches rows from the cursor, moving it to the last row fetched.
+    pub async fn fetch(&self, direction: FetchDirection) -> Result<Vec<Row>, Error> {
+        let query = format!("FETCH {} FROM {}", direction, self.name);
+        self.run(&query).await?.try_collect().await
+    }
+
+    /// Fetches the next row.
+    pub async fn fetch_next(&self) -> Result<Option<Row>, Error> {
+        self.fetch_one(FetchDirection::Next).await
+    }
+
+    /// Fetches the prior row.
+    pub async fn fetch_prior(&self) -> Result<Option<Row>, Error> {
+        self.fetch_one(FetchDirection::Prior).await
+    }
+
+    /// Fetches the first row.
+    pub async fn fetch_first(&self) -> Result<Option<Row>, Error> {
+        self.fetch_one(FetchDirection::First).await
+    }
+
+    /// Fetches the last row.
+    pub async fn fetch_last(&self) -> Result<Option<Row>, Error> {
+        self.fetch_one(FetchDirection::Last).await
+    }
+
+    /// Fetches the row at the given position, counting from the end if negative.
+    pub async fn fetch_absolute(&self, position: i64) -> Result<Option<Row>, Error> {
+        self.fetch_one(FetchDirection::Absolute(position)).await
+    }
+
+    /// Fetches the row at the given offset from the current position.
+    pub async fn fetch_relative(&self, offset: i64) -> Result<Option<Row>, Error> {
+        self.fetch_one(FetchDirection::Relative(offset)).await
+    }
+
+    /// Fetches up to the given number of rows forwards.
+    pub async fn fetch_forward(&self, count: i64) -> Result<Vec<Row>, Error> {
+        self.fetch(FetchDirection::Forward(count)).await
+    }
+
+    /// Fetches up to the given number of rows backwards.
+    pub async fn fetch_backward(&self, count: i64) -> Result<Vec<Row>, Error> {
+        self.fetch(FetchDirection::Backward(count)).await
+    }
+
+    async fn fetch_one(&self, direction: FetchDirection) -> Result<Option<Row>, Error> {
+        self.fetch(direction)
+            .await
+            .map(|rows| rows.into_iter().next())
+    }
+
+    /// Moves the cursor without fetching any rows, as with `MOVE`.
+    ///
+    /// Returns the number of rows the cursor moved over.
+    pub async fn seek(&self, direction: FetchDirection) -> Result<u64, Error> {
+        let query = format!("MOVE {} IN {}", direction, self.name);
+        let stream = self.run(&query).await?;
+        pin_mut!(stream);
+        while stream.try_next().await?.is_some() {}
+        Ok(stream.rows_affected().unwrap_or(0))
+    }
+
+    /// Closes the cursor.
+    ///
+    /// This is equivalent to `Cursor`'s `Drop` implementation, but provides any error encountered to the caller.
+    pub async fn close(mut self) -> Result<(), Error> {
+        self.closed = true;
+        let query = format!("CLOSE {}", self.name);
+        let stream = self.run(&query).await?;
+        pin_mut!(stream);
+        while stream.try_next().await?.is_some() {}
+        Ok(())
+    }
+
+    async fn run(&self, query: &str) -> Result<query::RowStream, Error> {
+        let client = self.client.upgrade().ok_or_else(Error::closed)?;
+        query::query_typed(&client, query, Vec::<(&(dyn ToSql + Sync), Type)>::new()).await
+    }
+}
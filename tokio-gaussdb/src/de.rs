@@ -0,0 +1,561 @@
+use crate::query::RowStream;
+use crate::types::Value;
+use crate::{Error, Row};
+use futures_util::{ready, Stream};
+use pin_project_lite::pin_project;
+use serde_1::de::value::SeqDeserializer;
+use serde_1::de::{
+    self, DeserializeOwned, DeserializeSeed, Deserializer, IntoDeserializer, MapAccess, SeqAccess,
+    Visitor,
+};
+use serde_1::forward_to_deserialize_any;
+use std::fmt;
+use std::marker::PhantomData;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::vec;
+
+impl de::Error for Error {
+    fn custom<T>(msg: T) -> Error
+    where
+        T: fmt::Display,
+    {
+        Error::deserialize(msg.to_string().into())
+    }
+}
+
+impl Row {
+    /// Deserializes the row into a value implementing serde's `Deserialize` trait.
+    ///
+    /// Structs and maps are deserialized from the columns of the row by name, and sequences and tuples by index.
+    /// See `RowDeserializer` for details on how the values of columns are deserialized.
+    ///
+    /// Requires the `serde` feature.
+    pub fn deserialize<T>(&self) -> Result<T, Error>
+    where
+        T: DeserializeOwned,
+    {
+        T::deserialize(RowDeserializer::new(self))
+    }
+}
+
+impl RowStream {
+    /// Converts the stream into a stream of values deserialized from its rows with `Row::deserialize`.
+    ///
+    /// Requires the `serde` feature.
+    pub fn deserialize<T>(self) -> DeserializeStream<T>
+    where
+        T: DeserializeOwned,
+    {
+        DeserializeStream {
+            stream: self,
+            _p: PhantomData,
+        }
+    }
+}
+
+pin_project! {
+    /// A stream of values deserialized from the rows of a `RowStream`.
+    pub struct DeserializeStream<T> {
+        #[pin]
+        stream: RowStream,
+        _p: PhantomData<fn() -> T>,
+    }
+}
+
+impl<T> Stream for DeserializeStream<T>
+where
+    T: DeserializeOwned,
+{
+    type Item = Result<T, Error>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        match ready!(this.stream.poll_next(cx)) {
+            Some(Ok(row)) => Poll::Ready(Some(row.deserialize())),
+            Some(Err(e)) => Poll::Ready(Some(Err(e))),
+            None => Poll::Ready(None),
+        }
+    }
+}
+
+impl<T> DeserializeStream<T> {
+    /// Returns the number of rows affected by the query.
+    ///
+    /// This function will return `None` until the stream has been exhausted.
+    pub fn rows_affected(&self) -> Option<u64> {
+        self.stream.rows_affected()
+    }
+}
+
+/// A serde `Deserializer` over the columns of a `Row`.
+///
+/// The row is deserialized as a map from column names to values, or as a sequence of values when a sequence, tuple
+/// or tuple struct is requested. Values are decoded as a `Value` and deserialized as follows:
+///
+/// * `NULL` deserializes as `None` or the unit value.
+/// * Booleans, integers and floating point numbers deserialize as the corresponding primitive. `NUMERIC` values
+///   deserialize as strings, or are parsed when a number is requested.
+/// * `JSON` and `JSONB` values are parsed, and can deserialize into any type.
+/// * Arrays deserialize as sequences, and composite values as maps from field names to values.
+/// * `BYTEA` values deserialize as byte buffers, or as sequences of bytes when a sequence is requested.
+/// * Text values can also deserialize as unit enum variants.
+/// * Other values deserialize as their text representation.
+///
+/// Errors name the column and SQL type of the value which could not be deserialized.
+pub struct RowDeserializer<'a> {
+    row: &'a Row,
+}
+
+impl<'a> RowDeserializer<'a> {
+    /// Creates a deserializer over the columns of a row.
+    pub fn new(row: &'a Row) -> RowDeserializer<'a> {
+        RowDeserializer { row }
+    }
+
+    fn column<'de, T>(&self, idx: usize, seed: T) -> Result<T::Value, Error>
+    where
+        T: DeserializeSeed<'de>,
+    {
+        let column = &self.row.columns()[idx];
+        let value = self.row.value(idx)?;
+        seed.deserialize(ValueDeserializer(value)).map_err(|e| {
+            Error::deserialize(
+                format!(
+                    "column `{}` of type `{}`: {}",
+                    column.name(),
+                    column.type_(),
+                    e.0
+                )
+                .into(),
+            )
+        })
+    }
+}
+
+impl<'de> Deserializer<'de> for RowDeserializer<'_> {
+    type Error = Error;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_map(visitor)
+    }
+
+    fn deserialize_map<V>(self, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_map(RowAccess { de: self, idx: 0 })
+    }
+
+    fn deserialize_struct<V>(
+        self,
+        _: &'static str,
+        _: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_map(visitor)
+    }
+
+    fn deserialize_seq<V>(self, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_seq(RowAccess { de: self, idx: 0 })
+    }
+
+    fn deserialize_tuple<V>(self, _: usize, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_tuple_struct<V>(
+        self,
+        _: &'static str,
+        _: usize,
+        visitor: V,
+    ) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_newtype_struct<V>(self, _: &'static str, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_newtype_struct(self)
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string bytes byte_buf option unit
+        unit_struct enum identifier ignored_any
+    }
+}
+
+struct RowAccess<'a> {
+    de: RowDeserializer<'a>,
+    idx: usize,
+}
+
+impl<'de> MapAccess<'de> for RowAccess<'_> {
+    type Error = Error;
+
+    fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>, Error>
+    where
+        K: DeserializeSeed<'de>,
+    {
+        match self.de.row.columns().get(self.idx) {
+            Some(column) => seed
+                .deserialize(column.name().into_deserializer())
+                .map(Some),
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<V>(&mut self, seed: V) -> Result<V::Value, Error>
+    where
+        V: DeserializeSeed<'de>,
+    {
+        let value = self.de.column(self.idx, seed);
+        self.idx += 1;
+        value
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.de.row.len() - self.idx)
+    }
+}
+
+impl<'de> SeqAccess<'de> for RowAccess<'_> {
+    type Error = Error;
+
+    fn next_element_seed<T>(&mut self, seed: T) -> Result<Option<T::Value>, Error>
+    where
+        T: DeserializeSeed<'de>,
+    {
+        if self.idx == self.de.row.len() {
+            return Ok(None);
+        }
+        let value = self.de.column(self.idx, seed);
+        self.idx += 1;
+        value.map(Some)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.de.row.len() - self.idx)
+    }
+}
+
+// The error of a single value, which is given the context of its column when converted to an `Error`.
+#[derive(Debug)]
+struct ValueError(String);
+
+impl fmt::Display for ValueError {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for ValueError {}
+
+impl de::Error for ValueError {
+    fn custom<T>(msg: T) -> ValueError
+    where
+        T: fmt::Display,
+    {
+        ValueError(msg.to_string())
+    }
+}
+
+struct ValueDeserializer(Value);
+
+impl ValueDeserializer {
+    fn json(json: &str) -> Result<serde_json_1::Value, ValueError> {
+        serde_json_1::from_str(json).map_err(de::Error::custom)
+    }
+
+    fn parse<T>(numeric: &str) -> Result<T, ValueError>
+    where
+        T: std::str::FromStr,
+    {
+        numeric
+            .parse()
+            .map_err(|_| de::Error::custom(format_args!("invalid number `{}`", numeric)))
+    }
+}
+
+macro_rules! deserialize_number {
+    ($($method:ident => $visit:ident,)*) => {
+        $(
+            fn $method<V>(self, visitor: V) -> Result<V::Value, ValueError>
+            where
+                V: Visitor<'de>,
+            {
+                match self.0 {
+                    Value::Numeric(v) => visitor.$visit(ValueDeserializer::parse(&v)?),
+                    Value::Json(v) => ValueDeserializer::json(&v)?.$method(visitor).map_err(de::Error::custom),
+                    v => ValueDeserializer(v).deserialize_any(visitor),
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! deserialize_json {
+    ($($method:ident,)*) => {
+        $(
+            fn $method<V>(self, visitor: V) -> Result<V::Value, ValueError>
+            where
+                V: Visitor<'de>,
+            {
+                match self.0 {
+                    Value::Json(v) => ValueDeserializer::json(&v)?.$method(visitor).map_err(de::Error::custom),
+                    v => ValueDeserializer(v).deserialize_any(visitor),
+                }
+            }
+        )*
+    };
+}
+
+impl<'de> Deserializer<'de> for ValueDeserializer {
+    type Error = ValueError;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, ValueError>
+    where
+        V: Visitor<'de>,
+    {
+        match self.0 {
+            Value::Null => visitor.visit_unit(),
+            Value::Bool(v) => visitor.visit_bool(v),
+            Value::Char(v) => visitor.visit_i8(v),
+            Value::Int2(v) => visitor.visit_i16(v),
+            Value::Int4(v) => visitor.visit_i32(v),
+            Value::Int8(v) => visitor.visit_i64(v),
+            Value::Oid(v) => visitor.visit_u32(v),
+            Value::Float4(v) => visitor.visit_f32(v),
+            Value::Float8(v) => visitor.visit_f64(v),
+            Value::Numeric(v) | Value::Text(v) => visitor.visit_string(v),
+            Value::Bytea(v) => visitor.visit_byte_buf(v),
+            Value::Json(v) => ValueDeserializer::json(&v)?
+                .deserialize_any(visitor)
+                .map_err(de::Error::custom),
+            Value::Array(v) => visitor.visit_seq(ArrayAccess(v.into_iter())),
+            Value::Composite(v) => visitor.visit_map(CompositeAccess {
+                fields: v.into_iter(),
+                value: None,
+            }),
+            Value::Unknown { raw, .. } => visitor.visit_byte_buf(raw),
+            v => visitor.visit_string(v.to_string()),
+        }
+    }
+
+    fn deserialize_seq<V>(self, visitor: V) -> Result<V::Value, ValueError>
+    where
+        V: Visitor<'de>,
+    {
+        match self.0 {
+            Value::Bytea(v) => {
+                let mut seq = SeqDeserializer::new(v.into_iter());
+                let value = visitor.visit_seq(&mut seq)?;
+                seq.end()?;
+                Ok(value)
+            }
+            Value::Json(v) => ValueDeserializer::json(&v)?
+                .deserialize_seq(visitor)
+                .map_err(de::Error::custom),
+            v => ValueDeserializer(v).deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_option<V>(self, visitor: V) -> Result<V::Value, ValueError>
+    where
+        V: Visitor<'de>,
+    {
+        match self.0 {
+            Value::Null => visitor.visit_none(),
+            v => visitor.visit_some(ValueDeserializer(v)),
+        }
+    }
+
+    fn deserialize_enum<V>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, ValueError>
+    where
+        V: Visitor<'de>,
+    {
+        match self.0 {
+            Value::Text(v) => visitor.visit_enum(v.into_deserializer()),
+            Value::Json(v) => ValueDeserializer::json(&v)?
+                .deserialize_enum(name, variants, visitor)
+                .map_err(de::Error::custom),
+            v => ValueDeserializer(v).deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_newtype_struct<V>(
+        self,
+        _: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, ValueError>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_struct<V>(
+        self,
+        name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, ValueError>
+    where
+        V: Visitor<'de>,
+    {
+        match self.0 {
+            Value::Json(v) => ValueDeserializer::json(&v)?
+                .deserialize_struct(name, fields, visitor)
+                .map_err(de::Error::custom),
+            v => ValueDeserializer(v).deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_unit_struct<V>(
+        self,
+        name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, ValueError>
+    where
+        V: Visitor<'de>,
+    {
+        match self.0 {
+            Value::Json(v) => ValueDeserializer::json(&v)?
+                .deserialize_unit_struct(name, visitor)
+                .map_err(de::Error::custom),
+            v => ValueDeserializer(v).deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_tuple<V>(self, len: usize, visitor: V) -> Result<V::Value, ValueError>
+    where
+        V: Visitor<'de>,
+    {
+        match self.0 {
+            Value::Json(v) => ValueDeserializer::json(&v)?
+                .deserialize_tuple(len, visitor)
+                .map_err(de::Error::custom),
+            v => ValueDeserializer(v).deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_tuple_struct<V>(
+        self,
+        name: &'static str,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value, ValueError>
+    where
+        V: Visitor<'de>,
+    {
+        match self.0 {
+            Value::Json(v) => ValueDeserializer::json(&v)?
+                .deserialize_tuple_struct(name, len, visitor)
+                .map_err(de::Error::custom),
+            v => ValueDeserializer(v).deserialize_any(visitor),
+        }
+    }
+
+    deserialize_number! {
+        deserialize_i8 => visit_i8,
+        deserialize_i16 => visit_i16,
+        deserialize_i32 => visit_i32,
+        deserialize_i64 => visit_i64,
+        deserialize_i128 => visit_i128,
+        deserialize_u8 => visit_u8,
+        deserialize_u16 => visit_u16,
+        deserialize_u32 => visit_u32,
+        deserialize_u64 => visit_u64,
+        deserialize_u128 => visit_u128,
+        deserialize_f32 => visit_f32,
+        deserialize_f64 => visit_f64,
+    }
+
+    deserialize_json! {
+        deserialize_bool,
+        deserialize_char,
+        deserialize_str,
+        deserialize_string,
+        deserialize_bytes,
+        deserialize_byte_buf,
+        deserialize_unit,
+        deserialize_map,
+        deserialize_identifier,
+        deserialize_ignored_any,
+    }
+}
+
+struct ArrayAccess(vec::IntoIter<Value>);
+
+impl<'de> SeqAccess<'de> for ArrayAccess {
+    type Error = ValueError;
+
+    fn next_element_seed<T>(&mut self, seed: T) -> Result<Option<T::Value>, ValueError>
+    where
+        T: DeserializeSeed<'de>,
+    {
+        match self.0.next() {
+            Some(value) => seed.deserialize(ValueDeserializer(value)).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.0.len())
+    }
+}
+
+struct CompositeAccess {
+    fields: vec::IntoIter<(String, Value)>,
+    value: Option<Value>,
+}
+
+impl<'de> MapAccess<'de> for CompositeAccess {
+    type Error = ValueError;
+
+    fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>, ValueError>
+    where
+        K: DeserializeSeed<'de>,
+    {
+        match self.fields.next() {
+            Some((name, value)) => {
+                self.value = Some(value);
+                seed.deserialize(name.into_deserializer()).map(Some)
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<V>(&mut self, seed: V) -> Result<V::Value, ValueError>
+    where
+        V: DeserializeSeed<'de>,
+    {
+        let value = self.value.take().unwrap_or(Value::Null);
+        seed.deserialize(ValueDeserializer(value))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.fields.len())
+    }
+}
@@ -346,6 +346,7 @@ enum Kind {
     FromSql(usize),
     Column(String),
     Parameters(usize, usize),
+    NamedParameters,
     Closed,
     Db,
     Parse,
@@ -354,9 +355,14 @@ enum Kind {
     ConfigParse,
     Config,
     RowCount,
+    #[cfg(feature = "serde")]
+    Deserialize,
     #[cfg(feature = "runtime")]
     Connect,
     Timeout,
+    #[cfg(feature = "runtime")]
+    StatementTimeout,
+    StaleStatement,
 }
 
 struct ErrorInner {
@@ -388,6 +394,7 @@ impl fmt::Display for Error {
             Kind::Parameters(real, expected) => {
                 write!(fmt, "expected {expected} parameters but got {real}")?
             }
+            Kind::NamedParameters => fmt.write_str("invalid named parameters")?,
             Kind::Closed => fmt.write_str("connection closed")?,
             Kind::Db => fmt.write_str("db error")?,
             Kind::Parse => fmt.write_str("error parsing response from server")?,
@@ -396,9 +403,14 @@ impl fmt::Display for Error {
             Kind::ConfigParse => fmt.write_str("invalid connection string")?,
             Kind::Config => fmt.write_str("invalid configuration")?,
             Kind::RowCount => fmt.write_str("query returned an unexpected number of rows")?,
+            #[cfg(feature = "serde")]
+            Kind::Deserialize => fmt.write_str("error deserializing row")?,
             #[cfg(feature = "runtime")]
             Kind::Connect => fmt.write_str("error connecting to server")?,
             Kind::Timeout => fmt.write_str("timeout waiting for server")?,
+            #[cfg(feature = "runtime")]
+            Kind::StatementTimeout => fmt.write_str("statement timed out")?,
+            Kind::StaleStatement => fmt.write_str("statement must be prepared again")?,
         };
         if let Some(ref cause) = self.0.cause {
             write!(fmt, ": {}", cause)?;
@@ -431,6 +443,19 @@ impl Error {
         self.0.kind == Kind::Closed
     }
 
+    /// Determines if the error was caused by a timeout expiring.
+    ///
+    /// This covers both statement timeouts enforced by the client and timeouts waiting for the server in
+    /// `is_valid`-style health checks.
+    pub fn is_timeout(&self) -> bool {
+        match self.0.kind {
+            Kind::Timeout => true,
+            #[cfg(feature = "runtime")]
+            Kind::StatementTimeout => true,
+            _ => false,
+        }
+    }
+
     /// Returns the SQLSTATE error code associated with the error.
     ///
     /// This is a convenience method that downcasts the cause to a `DbError` and returns its code.
@@ -438,6 +463,19 @@ impl Error {
         self.as_db_error().map(DbError::code)
     }
 
+    /// Determines if the error indicates that a prepared statement can no longer be used on the server, either
+    /// because the schema of the objects it references has changed in a way which alters its result type, or because
+    /// it no longer exists (e.g. after `DISCARD ALL`).
+    pub(crate) fn is_stale_statement(&self) -> bool {
+        match self.as_db_error() {
+            Some(e) if *e.code() == SqlState::FEATURE_NOT_SUPPORTED => e
+                .message()
+                .contains("cached plan must not change result type"),
+            Some(e) => *e.code() == SqlState::INVALID_SQL_STATEMENT_NAME,
+            None => false,
+        }
+    }
+
     fn new(kind: Kind, cause: Option<Box<dyn error::Error + Sync + Send>>) -> Error {
         Error(Box::new(ErrorInner { kind, cause }))
     }
@@ -483,6 +521,10 @@ impl Error {
         Error::new(Kind::Parameters(real, expected), None)
     }
 
+    pub(crate) fn named_parameters(e: String) -> Error {
+        Error::new(Kind::NamedParameters, Some(e.into()))
+    }
+
     pub(crate) fn tls(e: Box<dyn error::Error + Sync + Send>) -> Error {
         Error::new(Kind::Tls, Some(e))
     }
@@ -507,11 +549,25 @@ impl Error {
         Error::new(Kind::RowCount, None)
     }
 
+    #[cfg(feature = "serde")]
+    pub(crate) fn deserialize(e: Box<dyn error::Error + Sync + Send>) -> Error {
+        Error::new(Kind::Deserialize, Some(e))
+    }
+
     #[cfg(feature = "runtime")]
     pub(crate) fn connect(e: io::Error) -> Error {
         Error::new(Kind::Connect, Some(Box::new(e)))
     }
 
+    #[cfg(feature = "runtime")]
+    pub(crate) fn statement_timeout() -> Error {
+        Error::new(Kind::StatementTimeout, None)
+    }
+
+    pub(crate) fn stale_statement(e: Error) -> Error {
+        Error::new(Kind::StaleStatement, e.into_source())
+    }
+
     #[doc(hidden)]
     pub fn __private_api_timeout() -> Error {
         Error::new(Kind::Timeout, None)
@@ -1,6 +1,6 @@
 use crate::query::RowStream;
 use crate::types::{BorrowToSql, ToSql, Type};
-use crate::{Client, Error, Row, SimpleQueryMessage, Statement, ToStatement, Transaction};
+use crate::{Client, Error, FromRow, Row, SimpleQueryMessage, Statement, ToStatement, Transaction};
 use async_trait::async_trait;
 
 mod private {
@@ -30,6 +30,15 @@ pub trait GenericClient: private::Sealed {
     where
         T: ?Sized + ToStatement + Sync + Send;
 
+    /// Like [`Client::query_text`].
+    async fn query_text<T>(
+        &self,
+        query: &T,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Vec<Row>, Error>
+    where
+        T: ?Sized + ToStatement + Sync + Send;
+
     /// Like [`Client::query_one`].
     async fn query_one<T>(
         &self,
@@ -48,6 +57,36 @@ pub trait GenericClient: private::Sealed {
     where
         T: ?Sized + ToStatement + Sync + Send;
 
+    /// Like [`Client::query_as`].
+    async fn query_as<R, T>(
+        &self,
+        statement: &T,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Vec<R>, Error>
+    where
+        R: FromRow + Send,
+        T: ?Sized + ToStatement + Sync + Send;
+
+    /// Like [`Client::query_one_as`].
+    async fn query_one_as<R, T>(
+        &self,
+        statement: &T,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<R, Error>
+    where
+        R: FromRow + Send,
+        T: ?Sized + ToStatement + Sync + Send;
+
+    /// Like [`Client::query_opt_as`].
+    async fn query_opt_as<R, T>(
+        &self,
+        statement: &T,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<R>, Error>
+    where
+        R: FromRow + Send,
+        T: ?Sized + ToStatement + Sync + Send;
+
     /// Like [`Client::query_raw`].
     async fn query_raw<T, P, I>(&self, statement: &T, params: I) -> Result<RowStream, Error>
     where
@@ -69,6 +108,34 @@ pub trait GenericClient: private::Sealed {
         P: BorrowToSql,
         I: IntoIterator<Item = (P, Type)> + Sync + Send;
 
+    /// Like [`Client::query_named`].
+    async fn query_named(
+        &self,
+        query: &str,
+        params: &[(&str, &(dyn ToSql + Sync))],
+    ) -> Result<Vec<Row>, Error>;
+
+    /// Like [`Client::query_one_named`].
+    async fn query_one_named(
+        &self,
+        query: &str,
+        params: &[(&str, &(dyn ToSql + Sync))],
+    ) -> Result<Row, Error>;
+
+    /// Like [`Client::query_opt_named`].
+    async fn query_opt_named(
+        &self,
+        query: &str,
+        params: &[(&str, &(dyn ToSql + Sync))],
+    ) -> Result<Option<Row>, Error>;
+
+    /// Like [`Client::execute_named`].
+    async fn execute_named(
+        &self,
+        query: &str,
+        params: &[(&str, &(dyn ToSql + Sync))],
+    ) -> Result<u64, Error>;
+
     /// Like [`Client::prepare`].
     async fn prepare(&self, query: &str) -> Result<Statement, Error>;
 
@@ -120,6 +187,17 @@ impl GenericClient for Client {
         self.query(query, params).await
     }
 
+    async fn query_text<T>(
+        &self,
+        query: &T,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Vec<Row>, Error>
+    where
+        T: ?Sized + ToStatement + Sync + Send,
+    {
+        self.query_text(query, params).await
+    }
+
     async fn query_one<T>(
         &self,
         statement: &T,
@@ -142,6 +220,42 @@ impl GenericClient for Client {
         self.query_opt(statement, params).await
     }
 
+    async fn query_as<R, T>(
+        &self,
+        statement: &T,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Vec<R>, Error>
+    where
+        R: FromRow + Send,
+        T: ?Sized + ToStatement + Sync + Send,
+    {
+        self.query_as(statement, params).await
+    }
+
+    async fn query_one_as<R, T>(
+        &self,
+        statement: &T,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<R, Error>
+    where
+        R: FromRow + Send,
+        T: ?Sized + ToStatement + Sync + Send,
+    {
+        self.query_one_as(statement, params).await
+    }
+
+    async fn query_opt_as<R, T>(
+        &self,
+        statement: &T,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<R>, Error>
+    where
+        R: FromRow + Send,
+        T: ?Sized + ToStatement + Sync + Send,
+    {
+        self.query_opt_as(statement, params).await
+    }
+
     async fn query_raw<T, P, I>(&self, statement: &T, params: I) -> Result<RowStream, Error>
     where
         T: ?Sized + ToStatement + Sync + Send,
@@ -168,6 +282,38 @@ impl GenericClient for Client {
         self.query_typed_raw(statement, params).await
     }
 
+    async fn query_named(
+        &self,
+        query: &str,
+        params: &[(&str, &(dyn ToSql + Sync))],
+    ) -> Result<Vec<Row>, Error> {
+        self.query_named(query, params).await
+    }
+
+    async fn query_one_named(
+        &self,
+        query: &str,
+        params: &[(&str, &(dyn ToSql + Sync))],
+    ) -> Result<Row, Error> {
+        self.query_one_named(query, params).await
+    }
+
+    async fn query_opt_named(
+        &self,
+        query: &str,
+        params: &[(&str, &(dyn ToSql + Sync))],
+    ) -> Result<Option<Row>, Error> {
+        self.query_opt_named(query, params).await
+    }
+
+    async fn execute_named(
+        &self,
+        query: &str,
+        params: &[(&str, &(dyn ToSql + Sync))],
+    ) -> Result<u64, Error> {
+        self.execute_named(query, params).await
+    }
+
     async fn prepare(&self, query: &str) -> Result<Statement, Error> {
         self.prepare(query).await
     }
@@ -226,6 +372,17 @@ impl GenericClient for Transaction<'_> {
         self.query(query, params).await
     }
 
+    async fn query_text<T>(
+        &self,
+        query: &T,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Vec<Row>, Error>
+    where
+        T: ?Sized + ToStatement + Sync + Send,
+    {
+        self.query_text(query, params).await
+    }
+
     async fn query_one<T>(
         &self,
         statement: &T,
@@ -248,6 +405,42 @@ impl GenericClient for Transaction<'_> {
         self.query_opt(statement, params).await
     }
 
+    async fn query_as<R, T>(
+        &self,
+        statement: &T,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Vec<R>, Error>
+    where
+        R: FromRow + Send,
+        T: ?Sized + ToStatement + Sync + Send,
+    {
+        self.query_as(statement, params).await
+    }
+
+    async fn query_one_as<R, T>(
+        &self,
+        statement: &T,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<R, Error>
+    where
+        R: FromRow + Send,
+        T: ?Sized + ToStatement + Sync + Send,
+    {
+        self.query_one_as(statement, params).await
+    }
+
+    async fn query_opt_as<R, T>(
+        &self,
+        statement: &T,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<R>, Error>
+    where
+        R: FromRow + Send,
+        T: ?Sized + ToStatement + Sync + Send,
+    {
+        self.query_opt_as(statement, params).await
+    }
+
     async fn query_raw<T, P, I>(&self, statement: &T, params: I) -> Result<RowStream, Error>
     where
         T: ?Sized + ToStatement + Sync + Send,
@@ -274,6 +467,38 @@ impl GenericClient for Transaction<'_> {
         self.query_typed_raw(statement, params).await
     }
 
+    async fn query_named(
+        &self,
+        query: &str,
+        params: &[(&str, &(dyn ToSql + Sync))],
+    ) -> Result<Vec<Row>, Error> {
+        self.query_named(query, params).await
+    }
+
+    async fn query_one_named(
+        &self,
+        query: &str,
+        params: &[(&str, &(dyn ToSql + Sync))],
+    ) -> Result<Row, Error> {
+        self.query_one_named(query, params).await
+    }
+
+    async fn query_opt_named(
+        &self,
+        query: &str,
+        params: &[(&str, &(dyn ToSql + Sync))],
+    ) -> Result<Option<Row>, Error> {
+        self.query_opt_named(query, params).await
+    }
+
+    async fn execute_named(
+        &self,
+        query: &str,
+        params: &[(&str, &(dyn ToSql + Sync))],
+    ) -> Result<u64, Error> {
+        self.execute_named(query, params).await
+    }
+
     async fn prepare(&self, query: &str) -> Result<Statement, Error> {
         self.prepare(query).await
     }
@@ -105,13 +105,23 @@
 //! | ------- | ----------- | ------------------ | ------- |
 //! | `runtime` | Enable convenience API for the connection process based on the `tokio` crate. | [tokio](https://crates.io/crates/tokio) 1.0 with the features `net` and `time` | yes |
 //! | `array-impls` | Enables `ToSql` and `FromSql` trait impls for arrays | - | no |
+//! | `arrow` | Enable conversion of query results into Apache Arrow record batches in the `arrow` module. | [arrow-array](https://crates.io/crates/arrow-array) 54 | no |
+//! | `derive` | Enable the `FromRow` derive, and the `ToSql` and `FromSql` derives in the `types` module. | [gaussdb-derive](https://crates.io/crates/gaussdb-derive) 0.1 | no |
+//! | `serde` | Enable `Serialize` for the dynamically typed `types::Value`, and deserializing rows with `Row::deserialize`. | [serde](https://crates.io/crates/serde) 1.0 and [serde_json](https://crates.io/crates/serde_json) 1.0 | no |
+//! | `with-bigdecimal-0_4` | Enable support for the `bigdecimal` crate. | [bigdecimal](https://crates.io/crates/bigdecimal) 0.4 | no |
 //! | `with-bit-vec-0_6` | Enable support for the `bit-vec` crate. | [bit-vec](https://crates.io/crates/bit-vec) 0.6 | no |
+//! | `with-bytes-1` | Has no effect, since the `Bytes` and `BytesMut` types of the `bytes` crate are always supported. Kept for compatibility. | - | no |
 //! | `with-chrono-0_4` | Enable support for the `chrono` crate. | [chrono](https://crates.io/crates/chrono) 0.4 | no |
+//! | `with-chrono-tz-0_10` | Enable support for the `chrono-tz` crate. | [chrono-tz](https://crates.io/crates/chrono-tz) 0.10 | no |
 //! | `with-eui48-0_4` | Enable support for the 0.4 version of the `eui48` crate. This is deprecated and will be removed. | [eui48](https://crates.io/crates/eui48) 0.4 | no |
 //! | `with-eui48-1` | Enable support for the 1.0 version of the `eui48` crate. | [eui48](https://crates.io/crates/eui48) 1.0 | no |
 //! | `with-geo-types-0_6` | Enable support for the 0.6 version of the `geo-types` crate. | [geo-types](https://crates.io/crates/geo-types/0.6.0) 0.6 | no |
 //! | `with-geo-types-0_7` | Enable support for the 0.7 version of the `geo-types` crate. | [geo-types](https://crates.io/crates/geo-types/0.7.0) 0.7 | no |
+//! | `with-ipnetwork-0_20` | Enable support for the `ipnetwork` crate. | [ipnetwork](https://crates.io/crates/ipnetwork) 0.20 | no |
 //! | `with-jiff-0_1` | Enable support for the 0.1 version of the `jiff` crate. | [jiff](https://crates.io/crates/jiff/0.1.0) 0.1 | no |
+//! | `with-ndarray-0_16` | Enable support for multidimensional arrays as the `ArrayD` type of the `ndarray` crate. | [ndarray](https://crates.io/crates/ndarray) 0.16 | no |
+//! | `with-num-bigint-0_4` | Enable support for the `BigInt` type of the `num-bigint` crate. | [num-bigint](https://crates.io/crates/num-bigint) 0.4 | no |
+//! | `with-rust_decimal-1` | Enable support for the `rust_decimal` crate. | [rust_decimal](https://crates.io/crates/rust_decimal) 1.23 | no |
 //! | `with-serde_json-1` | Enable support for the `serde_json` crate. | [serde_json](https://crates.io/crates/serde_json) 1.0 | no |
 //! | `with-uuid-0_8` | Enable support for the `uuid` crate. | [uuid](https://crates.io/crates/uuid) 0.8 | no |
 //! | `with-uuid-1` | Enable support for the `uuid` crate. | [uuid](https://crates.io/crates/uuid) 1.0 | no |
@@ -120,21 +130,26 @@
 #![warn(rust_2018_idioms, clippy::all, missing_docs)]
 
 pub use crate::cancel_token::CancelToken;
+pub use crate::chunked::ChunkedRowStream;
 pub use crate::client::Client;
 pub use crate::config::Config;
 pub use crate::connection::Connection;
 pub use crate::copy_in::CopyInSink;
 pub use crate::copy_out::CopyOutStream;
+pub use crate::cursor::{Cursor, CursorBuilder, FetchDirection};
+#[cfg(feature = "serde")]
+pub use crate::de::{DeserializeStream, RowDeserializer};
 use crate::error::DbError;
 pub use crate::error::Error;
 pub use crate::generic_client::GenericClient;
 pub use crate::portal::Portal;
 pub use crate::query::RowStream;
-pub use crate::row::{Row, SimpleQueryRow};
+pub use crate::row::{FromRow, Row, SimpleQueryRow};
 pub use crate::simple_query::{SimpleColumn, SimpleQueryStream};
 #[cfg(feature = "runtime")]
 pub use crate::socket::Socket;
 pub use crate::statement::{Column, Statement};
+pub use crate::statement_cache::StatementCache;
 #[cfg(feature = "runtime")]
 use crate::tls::MakeTlsConnect;
 pub use crate::tls::NoTls;
@@ -145,11 +160,14 @@ use crate::types::ToSql;
 use std::sync::Arc;
 
 pub mod binary_copy;
+#[cfg(feature = "arrow")]
+pub mod arrow;
 mod bind;
 #[cfg(feature = "runtime")]
 mod cancel_query;
 mod cancel_query_raw;
 mod cancel_token;
+mod chunked;
 mod client;
 mod codec;
 pub mod config;
@@ -162,11 +180,15 @@ mod connect_tls;
 mod connection;
 mod copy_in;
 mod copy_out;
+mod cursor;
+#[cfg(feature = "serde")]
+mod de;
 pub mod error;
 mod generic_client;
 #[cfg(not(target_arch = "wasm32"))]
 mod keepalive;
 mod maybe_tls_stream;
+mod named_params;
 mod portal;
 mod prepare;
 mod query;
@@ -175,6 +197,7 @@ mod simple_query;
 #[cfg(feature = "runtime")]
 mod socket;
 mod statement;
+mod statement_cache;
 pub mod tls;
 pub mod adaptive_auth;
 mod to_statement;
@@ -0,0 +1,348 @@
+use crate::types::ToSql;
+use crate::Error;
+
+/// A query with `:name` or `@name` placeholders rewritten to positional `$n` placeholders.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct NamedQuery {
+    sql: String,
+    names: Vec<String>,
+}
+
+impl NamedQuery {
+    /// Rewrites the named placeholders in `query`.
+    ///
+    /// Placeholders inside string literals, quoted identifiers, comments and dollar-quoted strings are left alone, as
+    /// are `::` casts. Each distinct name is assigned the next positional parameter the first time it appears.
+    pub(crate) fn parse(query: &str) -> Result<NamedQuery, Error> {
+        let bytes = query.as_bytes();
+        let mut sql = String::with_capacity(query.len());
+        let mut names: Vec<String> = vec![];
+        let mut positional = false;
+        // whether each enclosing bracket is an array subscript, in which `:` separates the bounds of a slice
+        let mut brackets: Vec<bool> = vec![];
+        let mut copied = 0;
+        let mut i = 0;
+
+        while i < bytes.len() {
+            match bytes[i] {
+                b'\'' => {
+                    let escapes = i > 0
+                        && matches!(bytes[i - 1], b'E' | b'e')
+                        && (i == 1 || !is_ident(bytes[i - 2]));
+                    i = skip_quoted(bytes, i, b'\'', escapes);
+                }
+                b'"' => i = skip_quoted(bytes, i, b'"', false),
+                b'-' if bytes.get(i + 1) == Some(&b'-') => {
+                    i = bytes[i..]
+                        .iter()
+                        .position(|&b| b == b'\n')
+                        .map_or(bytes.len(), |p| i + p + 1);
+                }
+                b'/' if bytes.get(i + 1) == Some(&b'*') => i = skip_block_comment(bytes, i),
+                // `$` is also allowed within identifiers
+                b'$' if i > 0 && is_ident(bytes[i - 1]) => i += 1,
+                b'$' if bytes.get(i + 1).is_some_and(u8::is_ascii_digit) => {
+                    positional = true;
+                    i += 1;
+                }
+                b'$' => i = skip_dollar_quoted(bytes, i),
+                b'[' => {
+                    brackets.push(!ends_with_keyword(&bytes[..i], b"array"));
+                    i += 1;
+                }
+                b']' => {
+                    brackets.pop();
+                    i += 1;
+                }
+                b':' if bytes.get(i + 1) == Some(&b':') => i += 2,
+                b':' if brackets.last() == Some(&true)
+                    && last_token(&bytes[..i]).is_some_and(|b| b == b'[' || is_ident(b)) =>
+                {
+                    i += 1
+                }
+                // part of an operator like `@@`, `<@` or `@>`, or of an identifier like `a@b`
+                b'@' if i > 0 && (is_ident(bytes[i - 1]) || is_operator(bytes[i - 1])) => i += 1,
+                b':' | b'@' if bytes.get(i + 1).is_some_and(|&b| is_ident_start(b)) => {
+                    let start = i + 1;
+                    let end = bytes[start..]
+                        .iter()
+                        .position(|&b| !is_ident(b))
+                        .map_or(bytes.len(), |p| start + p);
+                    let name = &query[start..end];
+                    let idx = match names.iter().position(|n| n == name) {
+                        Some(idx) => idx,
+                        None => {
+                            names.push(name.to_string());
+                            names.len() - 1
+                        }
+                    };
+
+                    sql.push_str(&query[copied..i]);
+                    sql.push('$');
+                    sql.push_str(&(idx + 1).to_string());
+                    copied = end;
+                    i = end;
+                }
+                _ => i += 1,
+            }
+        }
+        sql.push_str(&query[copied..]);
+
+        if positional && !names.is_empty() {
+            return Err(Error::named_parameters(
+                "query mixes named and positional parameters".to_string(),
+            ));
+        }
+
+        Ok(NamedQuery { sql, names })
+    }
+
+    /// Returns the rewritten query.
+    pub(crate) fn sql(&self) -> &str {
+        &self.sql
+    }
+
+    /// Returns the parameter names, in positional order.
+    #[cfg(test)]
+    pub(crate) fn names(&self) -> &[String] {
+        &self.names
+    }
+
+    /// Orders the values of named parameters to match the rewritten query.
+    pub(crate) fn params<'a>(
+        &self,
+        params: &[(&str, &'a (dyn ToSql + Sync))],
+    ) -> Result<Vec<&'a (dyn ToSql + Sync)>, Error> {
+        for (i, (name, _)) in params.iter().enumerate() {
+            if !self.names.iter().any(|n| n == name) {
+                return Err(Error::named_parameters(format!(
+                    "parameter `{}` does not appear in the query",
+                    name
+                )));
+            }
+            if params[..i].iter().any(|(n, _)| n == name) {
+                return Err(Error::named_parameters(format!(
+                    "parameter `{}` was given more than once",
+                    name
+                )));
+            }
+        }
+
+        self.names
+            .iter()
+            .map(|name| {
+                params
+                    .iter()
+                    .find(|(n, _)| n == name)
+                    .map(|(_, value)| *value)
+                    .ok_or_else(|| {
+                        Error::named_parameters(format!("no value for parameter `{}`", name))
+                    })
+            })
+            .collect()
+    }
+}
+
+fn is_ident_start(b: u8) -> bool {
+    b.is_ascii_alphabetic() || b == b'_'
+}
+
+fn is_ident(b: u8) -> bool {
+    b.is_ascii_alphanumeric() || b == b'_'
+}
+
+/// Returns whether `b` can appear in the name of an operator.
+fn is_operator(b: u8) -> bool {
+    b"+-*/<>=~!@#%^&|`?".contains(&b)
+}
+
+/// Returns the last byte of `bytes` which is not whitespace.
+fn last_token(bytes: &[u8]) -> Option<u8> {
+    bytes
+        .iter()
+        .rev()
+        .copied()
+        .find(|b| !b.is_ascii_whitespace())
+}
+
+/// Determines if `bytes` ends with `keyword`, ignoring case and trailing whitespace.
+fn ends_with_keyword(bytes: &[u8], keyword: &[u8]) -> bool {
+    let end = bytes
+        .iter()
+        .rposition(|b| !b.is_ascii_whitespace())
+        .map_or(0, |p| p + 1);
+    let bytes = &bytes[..end];
+    bytes.len() >= keyword.len()
+        && bytes[end - keyword.len()..].eq_ignore_ascii_case(keyword)
+        && (bytes.len() == keyword.len() || !is_ident(bytes[end - keyword.len() - 1]))
+}
+
+/// Returns the index just past the literal or quoted identifier starting at `start`.
+fn skip_quoted(bytes: &[u8], start: usize, quote: u8, escapes: bool) -> usize {
+    let mut i = start + 1;
+    while i < bytes.len() {
+        if escapes && bytes[i] == b'\\' {
+            i += 2;
+        } else if bytes[i] == quote {
+            // a doubled quote is an escaped quote
+            if bytes.get(i + 1) == Some(&quote) {
+                i += 2;
+            } else {
+                return i + 1;
+            }
+        } else {
+            i += 1;
+        }
+    }
+    bytes.len()
+}
+
+/// Returns the index just past the (possibly nested) block comment starting at `start`.
+fn skip_block_comment(bytes: &[u8], start: usize) -> usize {
+    let mut depth = 0;
+    let mut i = start;
+    while i < bytes.len() {
+        if bytes[i..].starts_with(b"/*") {
+            depth += 1;
+            i += 2;
+        } else if bytes[i..].starts_with(b"*/") {
+            depth -= 1;
+            i += 2;
+            if depth == 0 {
+                return i;
+            }
+        } else {
+            i += 1;
+        }
+    }
+    bytes.len()
+}
+
+/// Returns the index just past the dollar-quoted string starting at `start`, or just past the `$` if it does not
+/// start one.
+fn skip_dollar_quoted(bytes: &[u8], start: usize) -> usize {
+    let tag_len = match bytes[start + 1..].iter().position(|&b| !is_ident(b)) {
+        Some(p) if bytes[start + 1 + p] == b'$' => p + 2,
+        _ => return start + 1,
+    };
+
+    let tag = &bytes[start..start + tag_len];
+    let body = start + tag_len;
+    bytes[body..]
+        .windows(tag_len)
+        .position(|w| w == tag)
+        .map_or(bytes.len(), |p| body + p + tag_len)
+}
+
+#[cfg(test)]
+mod test {
+    use super::NamedQuery;
+
+    fn rewrite(query: &str) -> (String, Vec<String>) {
+        let query = NamedQuery::parse(query).unwrap();
+        (query.sql().to_string(), query.names().to_vec())
+    }
+
+    #[test]
+    fn placeholders() {
+        assert_eq!(
+            rewrite("SELECT * FROM foo WHERE id = :id AND name = @name OR id > :id"),
+            (
+                "SELECT * FROM foo WHERE id = $1 AND name = $2 OR id > $1".to_string(),
+                vec!["id".to_string(), "name".to_string()],
+            ),
+        );
+    }
+
+    #[test]
+    fn skipped() {
+        let query = r#"SELECT ':a', E'\':b', 'it''s :c', ":d", $$ :e $$, $tag$ :f $ $tag$, x::int -- :g
+            /* :h /* :i */ :j */ FROM foo WHERE ARRAY[1] <@ ARRAY[1, 2] AND y = :k"#;
+        let (sql, names) = rewrite(query);
+        assert_eq!(names, vec!["k".to_string()]);
+        assert_eq!(sql, query.replace(":k", "$1"));
+    }
+
+    #[test]
+    fn array_slices() {
+        assert_eq!(
+            rewrite("SELECT arr[lo:hi], arr[:hi], arr[lo : hi], arr[1:2] FROM foo WHERE id = :id"),
+            (
+                "SELECT arr[lo:hi], arr[:hi], arr[lo : hi], arr[1:2] FROM foo WHERE id = $1"
+                    .to_string(),
+                vec!["id".to_string()],
+            ),
+        );
+        assert_eq!(
+            rewrite("SELECT arr[:lo + 1:hi], ARRAY[:a, :b], array [:a][:b]"),
+            (
+                "SELECT arr[:lo + 1:hi], ARRAY[$1, $2], array [$1][:b]".to_string(),
+                vec!["a".to_string(), "b".to_string()],
+            ),
+        );
+    }
+
+    #[test]
+    fn operators() {
+        assert_eq!(
+            rewrite("SELECT * FROM foo WHERE doc @@b AND tsv @@ @query"),
+            (
+                "SELECT * FROM foo WHERE doc @@b AND tsv @@ $1".to_string(),
+                vec!["query".to_string()],
+            ),
+        );
+        assert_eq!(
+            rewrite("SELECT * FROM foo WHERE tags <@arr AND arr @>x AND arr@>x AND b = @b"),
+            (
+                "SELECT * FROM foo WHERE tags <@arr AND arr @>x AND arr@>x AND b = $1".to_string(),
+                vec!["b".to_string()],
+            ),
+        );
+    }
+
+    #[test]
+    fn at_in_identifiers() {
+        assert_eq!(
+            rewrite("SELECT a@b FROM foo WHERE id = @id"),
+            (
+                "SELECT a@b FROM foo WHERE id = $1".to_string(),
+                vec!["id".to_string()],
+            ),
+        );
+    }
+
+    #[test]
+    fn dollar_identifiers() {
+        assert_eq!(
+            rewrite("SELECT col$1, col$a FROM foo WHERE id = :id"),
+            (
+                "SELECT col$1, col$a FROM foo WHERE id = $1".to_string(),
+                vec!["id".to_string()],
+            ),
+        );
+    }
+
+    #[test]
+    fn positional() {
+        assert_eq!(
+            rewrite("SELECT $1::TEXT"),
+            ("SELECT $1::TEXT".to_string(), vec![])
+        );
+        assert!(NamedQuery::parse("SELECT $1, :a").is_err());
+    }
+
+    #[test]
+    fn params() {
+        let query = NamedQuery::parse("SELECT :a, :b").unwrap();
+        let params = query.params(&[("b", &2i32), ("a", &"x")]).unwrap();
+        assert_eq!(params.len(), 2);
+
+        assert!(query.params(&[("a", &1i32)]).is_err());
+        assert!(query
+            .params(&[("a", &1i32), ("b", &2i32), ("c", &3i32)])
+            .is_err());
+        assert!(query
+            .params(&[("a", &1i32), ("a", &1i32), ("b", &2i32)])
+            .is_err());
+    }
+}
@@ -64,6 +64,15 @@ pub async fn prepare(
     types: &[Type],
 ) -> Result<Statement, Error> {
     let name = format!("s{}", NEXT_ID.fetch_add(1, Ordering::SeqCst));
+    prepare_named(client, name, query, types).await
+}
+
+pub async fn prepare_named(
+    client: &Arc<InnerClient>,
+    name: String,
+    query: &str,
+    types: &[Type],
+) -> Result<Statement, Error> {
     let buf = encode(client, &name, query, types)?;
     let mut responses = client.send(RequestMessages::Single(FrontendMessage::Raw(buf)))?;
 
@@ -100,12 +109,16 @@ pub async fn prepare(
                 table_oid: Some(field.table_oid()).filter(|n| *n != 0),
                 column_id: Some(field.column_id()).filter(|n| *n != 0),
                 r#type: type_,
+                type_modifier: field.type_modifier(),
+                format: field.format(),
             };
             columns.push(column);
         }
     }
 
-    Ok(Statement::new(client, name, parameters, columns))
+    Ok(Statement::new(
+        client, name, query, types, parameters, columns,
+    ))
 }
 
 fn prepare_rec<'a>(
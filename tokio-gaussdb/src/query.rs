@@ -7,7 +7,7 @@ use crate::{Column, Error, Portal, Row, Statement};
 use bytes::{Bytes, BytesMut};
 use fallible_iterator::FallibleIterator;
 use futures_util::{ready, Stream};
-use gaussdb_protocol::message::backend::{CommandCompleteBody, Message};
+use gaussdb_protocol::message::backend::{CommandCompleteBody, DataRowBody, Message};
 use gaussdb_protocol::message::frontend;
 use gaussdb_types::Type;
 use log::{debug, log_enabled, Level};
@@ -41,22 +41,25 @@ where
     I: IntoIterator<Item = P>,
     I::IntoIter: ExactSizeIterator,
 {
-    let buf = if log_enabled!(Level::Debug) {
-        let params = params.into_iter().collect::<Vec<_>>();
-        debug!(
-            "executing statement {} with parameters: {:?}",
-            statement.name(),
-            BorrowToSqlParamsDebug(params.as_slice()),
-        );
-        encode(client, &statement, params)?
-    } else {
-        encode(client, &statement, params)?
-    };
+    let buf = encode_logged(client, &statement, params, false)?;
+    query_encoded(client, statement, buf, false).await
+}
+
+/// Like `query`, but with the parameters already encoded by `encode_logged`.
+///
+/// `text` must match the result format the parameters were encoded with.
+pub async fn query_encoded(
+    client: &InnerClient,
+    statement: Statement,
+    buf: Bytes,
+    text: bool,
+) -> Result<RowStream, Error> {
     let responses = start(client, buf).await?;
     Ok(RowStream {
         statement,
         responses,
         rows_affected: None,
+        text,
         _p: PhantomPinned,
     })
 }
@@ -76,7 +79,7 @@ where
 
         client.with_buf(|buf| {
             frontend::parse("", query, param_oids.into_iter(), buf).map_err(Error::parse)?;
-            encode_bind_raw("", params, "", buf)?;
+            encode_bind_raw("", params, "", false, buf)?;
             frontend::describe(b'S', "", buf).map_err(Error::encode)?;
             frontend::execute("", 0, buf).map_err(Error::encode)?;
             frontend::sync(buf);
@@ -95,6 +98,7 @@ where
                     statement: Statement::unnamed(vec![], vec![]),
                     responses,
                     rows_affected: None,
+                    text: false,
                     _p: PhantomPinned,
                 });
             }
@@ -108,6 +112,8 @@ where
                         table_oid: Some(field.table_oid()).filter(|n| *n != 0),
                         column_id: Some(field.column_id()).filter(|n| *n != 0),
                         r#type: type_,
+                        type_modifier: field.type_modifier(),
+                        format: field.format(),
                     };
                     columns.push(column);
                 }
@@ -115,6 +121,7 @@ where
                     statement: Statement::unnamed(vec![], columns),
                     responses,
                     rows_affected: None,
+                    text: false,
                     _p: PhantomPinned,
                 });
             }
@@ -140,6 +147,7 @@ pub async fn query_portal(
         statement: portal.statement().clone(),
         responses,
         rows_affected: None,
+        text: false,
         _p: PhantomPinned,
     })
 }
@@ -157,27 +165,8 @@ pub fn extract_row_affected(body: &CommandCompleteBody) -> Result<u64, Error> {
     Ok(rows)
 }
 
-pub async fn execute<P, I>(
-    client: &InnerClient,
-    statement: Statement,
-    params: I,
-) -> Result<u64, Error>
-where
-    P: BorrowToSql,
-    I: IntoIterator<Item = P>,
-    I::IntoIter: ExactSizeIterator,
-{
-    let buf = if log_enabled!(Level::Debug) {
-        let params = params.into_iter().collect::<Vec<_>>();
-        debug!(
-            "executing statement {} with parameters: {:?}",
-            statement.name(),
-            BorrowToSqlParamsDebug(params.as_slice()),
-        );
-        encode(client, &statement, params)?
-    } else {
-        encode(client, &statement, params)?
-    };
+/// Executes a statement whose parameters were encoded by `encode_logged`, returning the number of rows modified.
+pub async fn execute(client: &InnerClient, buf: Bytes) -> Result<u64, Error> {
     let mut responses = start(client, buf).await?;
 
     let mut rows = 0;
@@ -205,14 +194,45 @@ async fn start(client: &InnerClient, buf: Bytes) -> Result<Responses, Error> {
     Ok(responses)
 }
 
-pub fn encode<P, I>(client: &InnerClient, statement: &Statement, params: I) -> Result<Bytes, Error>
+/// Like `encode`, but logs the parameters first.
+pub fn encode_logged<P, I>(
+    client: &InnerClient,
+    statement: &Statement,
+    params: I,
+    text: bool,
+) -> Result<Bytes, Error>
+where
+    P: BorrowToSql,
+    I: IntoIterator<Item = P>,
+    I::IntoIter: ExactSizeIterator,
+{
+    if log_enabled!(Level::Debug) {
+        let params = params.into_iter().collect::<Vec<_>>();
+        debug!(
+            "executing statement {} with parameters: {:?}",
+            statement.name(),
+            BorrowToSqlParamsDebug(params.as_slice()),
+        );
+        encode(client, statement, params, text)
+    } else {
+        encode(client, statement, params, text)
+    }
+}
+
+/// Encodes the messages to execute a statement, requesting its results in the text format if `text` is set.
+pub fn encode<P, I>(
+    client: &InnerClient,
+    statement: &Statement,
+    params: I,
+    text: bool,
+) -> Result<Bytes, Error>
 where
     P: BorrowToSql,
     I: IntoIterator<Item = P>,
     I::IntoIter: ExactSizeIterator,
 {
     client.with_buf(|buf| {
-        encode_bind(statement, params, "", buf)?;
+        encode_bind_formatted(statement, params, "", text, buf)?;
         frontend::execute("", 0, buf).map_err(Error::encode)?;
         frontend::sync(buf);
         Ok(buf.split().freeze())
@@ -225,6 +245,21 @@ pub fn encode_bind<P, I>(
     portal: &str,
     buf: &mut BytesMut,
 ) -> Result<(), Error>
+where
+    P: BorrowToSql,
+    I: IntoIterator<Item = P>,
+    I::IntoIter: ExactSizeIterator,
+{
+    encode_bind_formatted(statement, params, portal, false, buf)
+}
+
+fn encode_bind_formatted<P, I>(
+    statement: &Statement,
+    params: I,
+    portal: &str,
+    text: bool,
+    buf: &mut BytesMut,
+) -> Result<(), Error>
 where
     P: BorrowToSql,
     I: IntoIterator<Item = P>,
@@ -239,6 +274,7 @@ where
         statement.name(),
         params.zip(statement.params().iter().cloned()),
         portal,
+        text,
         buf,
     )
 }
@@ -247,6 +283,7 @@ fn encode_bind_raw<P, I>(
     statement_name: &str,
     params: I,
     portal: &str,
+    text: bool,
     buf: &mut BytesMut,
 ) -> Result<(), Error>
 where
@@ -273,7 +310,7 @@ where
                 Err(e)
             }
         },
-        Some(1),
+        Some(if text { 0 } else { 1 }),
         buf,
     );
     match r {
@@ -289,6 +326,7 @@ pin_project! {
         statement: Statement,
         responses: Responses,
         rows_affected: Option<u64>,
+        text: bool,
         #[pin]
         _p: PhantomPinned,
     }
@@ -297,25 +335,41 @@ pin_project! {
 impl Stream for RowStream {
     type Item = Result<Row, Error>;
 
-    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let body = match ready!(self.as_mut().poll_next_raw(cx)?) {
+            Some(body) => body,
+            None => return Poll::Ready(None),
+        };
+        let this = self.project();
+        Poll::Ready(Some(Row::new(this.statement.clone(), body, *this.text)))
+    }
+}
+
+impl RowStream {
+    #[cfg(feature = "arrow")]
+    pub(crate) fn statement(&self) -> &Statement {
+        &self.statement
+    }
+
+    /// Polls for the next row without decoding it.
+    pub(crate) fn poll_next_raw(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Result<Option<DataRowBody>, Error>> {
         let this = self.project();
         loop {
             match ready!(this.responses.poll_next(cx)?) {
-                Message::DataRow(body) => {
-                    return Poll::Ready(Some(Ok(Row::new(this.statement.clone(), body)?)))
-                }
+                Message::DataRow(body) => return Poll::Ready(Ok(Some(body))),
                 Message::CommandComplete(body) => {
                     *this.rows_affected = Some(extract_row_affected(&body)?);
                 }
                 Message::EmptyQueryResponse | Message::PortalSuspended => {}
-                Message::ReadyForQuery(_) => return Poll::Ready(None),
-                _ => return Poll::Ready(Some(Err(Error::unexpected_message()))),
+                Message::ReadyForQuery(_) => return Poll::Ready(Ok(None)),
+                _ => return Poll::Ready(Err(Error::unexpected_message())),
             }
         }
     }
-}
 
-impl RowStream {
     /// Returns the number of rows affected by the query.
     ///
     /// This function will return `None` until the stream has been exhausted.
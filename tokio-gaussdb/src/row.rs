@@ -3,10 +3,12 @@
 use crate::row::sealed::{AsName, Sealed};
 use crate::simple_query::SimpleColumn;
 use crate::statement::Column;
-use crate::types::{FromSql, Type, WrongType};
+use crate::types::{FromSql, FromSqlText, Value, WrongType};
 use crate::{Error, Statement};
+use bytes::Bytes;
 use fallible_iterator::FallibleIterator;
 use gaussdb_protocol::message::backend::DataRowBody;
+use std::error;
 use std::fmt;
 use std::ops::Range;
 use std::str;
@@ -94,12 +96,60 @@ where
     }
 }
 
+/// A type which can be built from a row returned by a query.
+///
+/// The row type defaults to [`Row`]; implementations for [`SimpleQueryRow`] build the value from the text
+/// representation of its columns instead.
+///
+/// # Derive
+///
+/// If the `derive` cargo feature is enabled, `FromRow` can be derived for structs with named fields. Each field is
+/// read from the column of the same name, and the implementations for both `Row` and `SimpleQueryRow` are derived.
+/// Fields can be configured with the following attributes:
+///
+/// * `#[gaussdb(rename = "name")]` reads the field from the column `name`.
+/// * `#[gaussdb(flatten)]` builds the field from the same row with its own `FromRow` implementation.
+/// * `#[gaussdb(default)]` uses `Default::default()` if the row has no column for the field.
+/// * `#[gaussdb(try_from = "Type")]` reads the column as `Type` and converts it with `TryFrom`.
+/// * `#[gaussdb(with = "path")]` reads the column as the argument type of the function `path`, and converts it by
+///   calling the function, which returns a `Result`.
+///
+/// On the struct itself, `#[gaussdb(rename_all = "...")]` renames all fields with one of the rules supported by the
+/// `ToSql` and `FromSql` derives, and `#[gaussdb(crate = "path")]` sets the path to this crate used by the generated
+/// code, for example `"gaussdb"` when using the synchronous client.
+///
+/// ```rust
+/// # #[cfg(feature = "derive")]
+/// use tokio_gaussdb::FromRow;
+///
+/// # #[cfg(feature = "derive")]
+/// #[derive(Debug, FromRow)]
+/// #[gaussdb(rename_all = "camelCase")]
+/// struct User {
+///     user_id: i32,
+///     #[gaussdb(rename = "name")]
+///     display_name: String,
+///     #[gaussdb(default)]
+///     email: Option<String>,
+///     #[gaussdb(try_from = "i32")]
+///     age: u8,
+/// }
+/// ```
+pub trait FromRow<R = Row>: Sized {
+    /// Builds a value from a row.
+    fn from_row(row: &R) -> Result<Self, Error>;
+}
+
+#[cfg(feature = "derive")]
+pub use gaussdb_derive::FromRow;
+
 /// A row of data returned from the database by a query.
 #[derive(Clone)]
 pub struct Row {
     statement: Statement,
     body: DataRowBody,
     ranges: Vec<Option<Range<usize>>>,
+    text: bool,
 }
 
 impl fmt::Debug for Row {
@@ -111,12 +161,13 @@ impl fmt::Debug for Row {
 }
 
 impl Row {
-    pub(crate) fn new(statement: Statement, body: DataRowBody) -> Result<Row, Error> {
+    pub(crate) fn new(statement: Statement, body: DataRowBody, text: bool) -> Result<Row, Error> {
         let ranges = body.ranges().collect().map_err(Error::parse)?;
         Ok(Row {
             statement,
             body,
             ranges,
+            text,
         })
     }
 
@@ -135,6 +186,14 @@ impl Row {
         self.columns().len()
     }
 
+    /// Determines if the values in the row are in the text format.
+    ///
+    /// This is the case for rows returned by `Client::query_text`, whose values are read with `Row::get_text`
+    /// rather than `Row::get`.
+    pub fn is_text(&self) -> bool {
+        self.text
+    }
+
     /// Deserializes a value from the row.
     ///
     /// The value can be specified either by its numeric index in the row, or by its column name.
@@ -173,6 +232,10 @@ impl Row {
             None => return Err(Error::column(idx.to_string())),
         };
 
+        if self.text {
+            return Err(Error::from_sql(Box::new(WrongFormat { text: true }), idx));
+        }
+
         let ty = self.columns()[idx].type_();
         if !T::accepts(ty) {
             return Err(Error::from_sql(
@@ -184,6 +247,124 @@ impl Row {
         FromSql::from_sql_nullable(ty, self.col_buffer(idx)).map_err(|e| Error::from_sql(e, idx))
     }
 
+    /// Returns the value of a `BYTEA`, `RAW` or `BLOB` column as `Bytes` sharing the row's buffer, without copying it.
+    ///
+    /// The value can be specified either by its numeric index in the row, or by its column name. `None` is returned
+    /// for `NULL`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is out of bounds, the row is in the text format, or the column is of another type.
+    #[track_caller]
+    pub fn get_bytes<I>(&self, idx: I) -> Option<Bytes>
+    where
+        I: RowIndex + fmt::Display,
+    {
+        match self.get_bytes_inner(&idx) {
+            Ok(ok) => ok,
+            Err(err) => panic!("error retrieving column {}: {}", idx, err),
+        }
+    }
+
+    /// Like `Row::get_bytes`, but returns a `Result` rather than panicking.
+    pub fn try_get_bytes<I>(&self, idx: I) -> Result<Option<Bytes>, Error>
+    where
+        I: RowIndex + fmt::Display,
+    {
+        self.get_bytes_inner(&idx)
+    }
+
+    fn get_bytes_inner<I>(&self, idx: &I) -> Result<Option<Bytes>, Error>
+    where
+        I: RowIndex + fmt::Display,
+    {
+        let idx = match idx.__idx(self.columns()) {
+            Some(idx) => idx,
+            None => return Err(Error::column(idx.to_string())),
+        };
+
+        if self.text {
+            return Err(Error::from_sql(Box::new(WrongFormat { text: true }), idx));
+        }
+
+        let ty = self.columns()[idx].type_();
+        if !<Bytes as FromSql>::accepts(ty) {
+            return Err(Error::from_sql(
+                Box::new(WrongType::new::<Bytes>(ty.clone())),
+                idx,
+            ));
+        }
+
+        // the binary format of these types is just their bytes
+        Ok(self.ranges[idx]
+            .clone()
+            .map(|range| self.body.buffer_bytes().slice(range)))
+    }
+
+    /// Deserializes a value from the text representation of a column of the row.
+    ///
+    /// The row must be in the text format, as returned by `Client::query_text`. The value can be specified either by
+    /// its numeric index in the row, or by its column name.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is out of bounds, the row is not in the text format, or if the value cannot be converted
+    /// to the specified type.
+    #[track_caller]
+    pub fn get_text<'a, I, T>(&'a self, idx: I) -> T
+    where
+        I: RowIndex + fmt::Display,
+        T: FromSqlText<'a>,
+    {
+        match self.get_text_inner(&idx) {
+            Ok(ok) => ok,
+            Err(err) => panic!("error retrieving column {}: {}", idx, err),
+        }
+    }
+
+    /// Like `Row::get_text`, but returns a `Result` rather than panicking.
+    pub fn try_get_text<'a, I, T>(&'a self, idx: I) -> Result<T, Error>
+    where
+        I: RowIndex + fmt::Display,
+        T: FromSqlText<'a>,
+    {
+        self.get_text_inner(&idx)
+    }
+
+    fn get_text_inner<'a, I, T>(&'a self, idx: &I) -> Result<T, Error>
+    where
+        I: RowIndex + fmt::Display,
+        T: FromSqlText<'a>,
+    {
+        let idx = match idx.__idx(self.columns()) {
+            Some(idx) => idx,
+            None => return Err(Error::column(idx.to_string())),
+        };
+
+        if !self.text {
+            return Err(Error::from_sql(Box::new(WrongFormat { text: false }), idx));
+        }
+
+        from_text(self.col_buffer(idx), idx)
+    }
+
+    /// Returns an iterator over the values of the row, decoded as dynamically typed `Value`s.
+    ///
+    /// The values of rows in the text format are returned as `Value::Text`, holding the server's text
+    /// representation of each value.
+    pub fn values(&self) -> impl Iterator<Item = Result<Value, Error>> + '_ {
+        (0..self.len()).map(move |idx| self.value(idx))
+    }
+
+    pub(crate) fn value(&self, idx: usize) -> Result<Value, Error> {
+        if self.text {
+            self.get_text_inner::<_, Option<String>>(&idx)
+                .map(|v| v.map_or(Value::Null, Value::Text))
+        } else {
+            self.get_inner(&idx)
+        }
+    }
+
     /// Get the raw bytes for the column at the given index.
     fn col_buffer(&self, idx: usize) -> Option<&[u8]> {
         let range = self.ranges[idx].to_owned()?;
@@ -252,17 +433,22 @@ impl SimpleQueryRow {
         }
     }
 
-    /// Like `SimpleQueryRow::get`, but returns a `Result` rather than panicking.
-    pub fn try_get<I>(&self, idx: I) -> Result<Option<&str>, Error>
+    /// Like `SimpleQueryRow::get`, but returns a `Result` rather than panicking, and decodes the value into any
+    /// type implementing `FromSqlText`.
+    ///
+    /// Requesting an `Option<&str>` returns the raw text of the value, as `SimpleQueryRow::get` does.
+    pub fn try_get<'a, I, T>(&'a self, idx: I) -> Result<T, Error>
     where
         I: RowIndex + fmt::Display,
+        T: FromSqlText<'a>,
     {
         self.get_inner(&idx)
     }
 
-    fn get_inner<I>(&self, idx: &I) -> Result<Option<&str>, Error>
+    fn get_inner<'a, I, T>(&'a self, idx: &I) -> Result<T, Error>
     where
         I: RowIndex + fmt::Display,
+        T: FromSqlText<'a>,
     {
         let idx = match idx.__idx(&self.columns) {
             Some(idx) => idx,
@@ -270,6 +456,57 @@ impl SimpleQueryRow {
         };
 
         let buf = self.ranges[idx].clone().map(|r| &self.body.buffer()[r]);
-        FromSql::from_sql_nullable(&Type::TEXT, buf).map_err(|e| Error::from_sql(e, idx))
+        from_text(buf, idx)
+    }
+}
+
+fn from_text<'a, T>(buf: Option<&'a [u8]>, idx: usize) -> Result<T, Error>
+where
+    T: FromSqlText<'a>,
+{
+    let raw = buf
+        .map(str::from_utf8)
+        .transpose()
+        .map_err(|e| Error::from_sql(Box::new(e), idx))?;
+    T::from_sql_text_nullable(raw).map_err(|e| Error::from_sql(e, idx))
+}
+
+/// An error returned when a value is read in a different format than the one its row is in.
+#[derive(Debug)]
+struct WrongFormat {
+    text: bool,
+}
+
+impl fmt::Display for WrongFormat {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.text {
+            fmt.write_str("the row is in the text format; use `Row::get_text` to read its values")
+        } else {
+            fmt.write_str("the row is in the binary format; use `Row::get` to read its values")
+        }
+    }
+}
+
+impl error::Error for WrongFormat {}
+
+#[doc(hidden)]
+pub mod __private {
+    use super::sealed::AsName;
+    use super::*;
+
+    pub fn has_column<C>(columns: &[C], name: &str) -> bool
+    where
+        C: AsName,
+    {
+        name.__idx(columns).is_some()
+    }
+
+    pub fn convert<C, S, T, E, F>(columns: &[C], name: &str, value: S, f: F) -> Result<T, Error>
+    where
+        C: AsName,
+        F: FnOnce(S) -> Result<T, E>,
+        E: Into<Box<dyn error::Error + Sync + Send>>,
+    {
+        f(value).map_err(|e| Error::from_sql(e.into(), name.__idx(columns).unwrap_or(0)))
     }
 }
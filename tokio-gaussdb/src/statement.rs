@@ -8,6 +8,8 @@ use std::sync::{Arc, Weak};
 struct StatementInner {
     client: Weak<InnerClient>,
     name: String,
+    query: String,
+    types: Vec<Type>,
     params: Vec<Type>,
     columns: Vec<Column>,
 }
@@ -19,6 +21,7 @@ impl Drop for StatementInner {
             return;
         }
         if let Some(client) = self.client.upgrade() {
+            client.remove_reprepared(&self.name);
             let buf = client.with_buf(|buf| {
                 frontend::close(b'S', &self.name, buf).unwrap();
                 frontend::sync(buf);
@@ -39,12 +42,16 @@ impl Statement {
     pub(crate) fn new(
         inner: &Arc<InnerClient>,
         name: String,
+        query: &str,
+        types: &[Type],
         params: Vec<Type>,
         columns: Vec<Column>,
     ) -> Statement {
         Statement(Arc::new(StatementInner {
             client: Arc::downgrade(inner),
             name,
+            query: query.to_string(),
+            types: types.to_vec(),
             params,
             columns,
         }))
@@ -54,6 +61,8 @@ impl Statement {
         Statement(Arc::new(StatementInner {
             client: Weak::new(),
             name: String::new(),
+            query: String::new(),
+            types: vec![],
             params,
             columns,
         }))
@@ -63,6 +72,16 @@ impl Statement {
         &self.0.name
     }
 
+    /// Returns the query the statement was prepared from.
+    pub(crate) fn query(&self) -> &str {
+        &self.0.query
+    }
+
+    /// Returns the parameter types explicitly specified when the statement was prepared.
+    pub(crate) fn types(&self) -> &[Type] {
+        &self.0.types
+    }
+
     /// Returns the expected types of the statement's parameters.
     pub fn params(&self) -> &[Type] {
         &self.0.params
@@ -91,6 +110,8 @@ pub struct Column {
     pub(crate) table_oid: Option<u32>,
     pub(crate) column_id: Option<i16>,
     pub(crate) r#type: Type,
+    pub(crate) type_modifier: i32,
+    pub(crate) format: i16,
 }
 
 impl Column {
@@ -113,4 +134,85 @@ impl Column {
     pub fn type_(&self) -> &Type {
         &self.r#type
     }
+
+    /// Returns the raw type modifier of the column.
+    ///
+    /// The meaning of the modifier depends on the type, and is `-1` if the type has no modifier. The `numeric_precision`,
+    /// `numeric_scale`, `character_length`, `timestamp_precision` and `bit_length` methods decode it for the built-in
+    /// types which use one.
+    pub fn type_modifier(&self) -> i32 {
+        self.type_modifier
+    }
+
+    /// Returns the format code of the column: `0` for text or `1` for binary.
+    ///
+    /// The format of a statement's columns is not known until it is executed, so the server reports it as `0` when
+    /// describing a statement.
+    pub fn format(&self) -> i16 {
+        self.format
+    }
+
+    /// Returns the declared precision of a `NUMERIC` column, if it has one.
+    pub fn numeric_precision(&self) -> Option<u32> {
+        self.numeric_modifier()
+            .map(|modifier| ((modifier >> 16) & 0xffff) as u32)
+    }
+
+    /// Returns the declared scale of a `NUMERIC` column, if it has one.
+    ///
+    /// The scale may be negative, in which case values are rounded to the left of the decimal point.
+    pub fn numeric_scale(&self) -> Option<i32> {
+        // the scale is stored as an 11 bit two's complement integer
+        self.numeric_modifier()
+            .map(|modifier| ((modifier & 0x7ff) ^ 0x400) - 0x400)
+    }
+
+    /// Returns the declared length of a `CHAR` or `VARCHAR` column, if it has one.
+    pub fn character_length(&self) -> Option<u32> {
+        match self.r#type {
+            Type::BPCHAR | Type::VARCHAR => self.modifier(4).map(|modifier| modifier as u32),
+            _ => None,
+        }
+    }
+
+    /// Returns the declared fractional seconds precision of a `TIME`, `TIMETZ`, `TIMESTAMP`, `TIMESTAMPTZ` or
+    /// `INTERVAL` column, if it has one.
+    pub fn timestamp_precision(&self) -> Option<u32> {
+        match self.r#type {
+            Type::TIME | Type::TIMETZ | Type::TIMESTAMP | Type::TIMESTAMPTZ => {
+                self.modifier(0).map(|modifier| modifier as u32)
+            }
+            // the precision is stored below the field range, with all bits set if it was not declared
+            Type::INTERVAL => self
+                .modifier(0)
+                .map(|modifier| modifier & 0xffff)
+                .filter(|precision| *precision != 0xffff)
+                .map(|precision| precision as u32),
+            _ => None,
+        }
+    }
+
+    /// Returns the declared length of a `BIT` or `VARBIT` column, if it has one.
+    pub fn bit_length(&self) -> Option<u32> {
+        match self.r#type {
+            Type::BIT | Type::VARBIT => self.modifier(0).map(|modifier| modifier as u32),
+            _ => None,
+        }
+    }
+
+    fn numeric_modifier(&self) -> Option<i32> {
+        match self.r#type {
+            Type::NUMERIC => self.modifier(4),
+            _ => None,
+        }
+    }
+
+    // Type modifiers are negative when not declared, and some types offset them by the size of a varlena header.
+    fn modifier(&self, offset: i32) -> Option<i32> {
+        if self.type_modifier < offset {
+            None
+        } else {
+            Some(self.type_modifier - offset)
+        }
+    }
 }
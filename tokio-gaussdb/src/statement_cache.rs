@@ -0,0 +1,213 @@
+use crate::named_params::NamedQuery;
+use crate::types::Type;
+use crate::Statement;
+use linked_hash_map::LinkedHashMap;
+use parking_lot::Mutex;
+use std::fmt;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+
+struct Inner {
+    capacity: usize,
+    // the statements prepared for each query string with different parameter types, least recently used first
+    entries: LinkedHashMap<String, Vec<(Vec<Type>, Statement)>>,
+    len: usize,
+    named: LinkedHashMap<String, Arc<NamedQuery>>,
+}
+
+/// A cache of the statements prepared by a [`Client`](crate::Client) for query strings passed to its methods.
+///
+/// The cache is disabled by default; it is enabled by giving it a nonzero capacity with
+/// [`Config::statement_cache_capacity`](crate::Config::statement_cache_capacity) or
+/// [`Client::set_statement_cache_capacity`](crate::Client::set_statement_cache_capacity). While enabled, passing a
+/// query string rather than a `Statement` to methods like `query` and `execute` reuses the statement prepared the last
+/// time the same string was used, rather than preparing it again. When the cache is full, the statements prepared for
+/// the least recently used query string are evicted and closed. Queries with named parameters additionally cache their
+/// rewritten form.
+///
+/// If the server reports that a cached statement can no longer be used, for example because a table it references was
+/// altered in a way that changes its result type, it is prepared again transparently. Inside a `Transaction`, where
+/// the error has already aborted the transaction, and for `query_raw` and `execute_raw`, whose parameters can only be
+/// read once, the statement is removed from the cache instead.
+///
+/// Statements are keyed by their query string and parameter types. Connection pools use the same cache for their
+/// `prepare_cached` methods, through [`get`](StatementCache::get) and [`insert`](StatementCache::insert).
+pub struct StatementCache {
+    inner: Mutex<Inner>,
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+impl fmt::Debug for StatementCache {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StatementCache")
+            .field("capacity", &self.capacity())
+            .field("len", &self.len())
+            .field("hits", &self.hits())
+            .field("misses", &self.misses())
+            .finish()
+    }
+}
+
+impl StatementCache {
+    pub(crate) fn new(capacity: usize) -> StatementCache {
+        StatementCache {
+            inner: Mutex::new(Inner {
+                capacity,
+                entries: LinkedHashMap::new(),
+                len: 0,
+                named: LinkedHashMap::new(),
+            }),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+        }
+    }
+
+    /// Returns the maximum number of statements held by the cache.
+    pub fn capacity(&self) -> usize {
+        self.inner.lock().capacity
+    }
+
+    /// Returns the number of statements held by the cache.
+    pub fn len(&self) -> usize {
+        self.inner.lock().len
+    }
+
+    /// Determines if the cache is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the number of times a query string was found in the cache.
+    pub fn hits(&self) -> u64 {
+        self.hits.load(Ordering::Relaxed)
+    }
+
+    /// Returns the number of times a query string had to be prepared because it was not in the cache.
+    pub fn misses(&self) -> u64 {
+        self.misses.load(Ordering::Relaxed)
+    }
+
+    /// Removes all statements from the cache.
+    pub fn clear(&self) {
+        let mut inner = self.inner.lock();
+        inner.entries.clear();
+        inner.len = 0;
+        inner.named.clear();
+    }
+
+    pub(crate) fn is_enabled(&self) -> bool {
+        self.capacity() > 0
+    }
+
+    pub(crate) fn set_capacity(&self, capacity: usize) {
+        let mut inner = self.inner.lock();
+        inner.capacity = capacity;
+        inner.evict();
+    }
+
+    /// Looks up the statement prepared for a query and parameter types.
+    pub fn get(&self, query: &str, types: &[Type]) -> Option<Statement> {
+        let statement = self
+            .inner
+            .lock()
+            .entries
+            .get_refresh(query)
+            .and_then(|statements| statements.iter().find(|(t, _)| t == types))
+            .map(|(_, statement)| statement.clone());
+
+        let counter = match statement {
+            Some(_) => &self.hits,
+            None => &self.misses,
+        };
+        counter.fetch_add(1, Ordering::Relaxed);
+        statement
+    }
+
+    /// Adds a statement to the cache, evicting the least recently used statements if the cache is full.
+    ///
+    /// Does nothing if the cache is disabled.
+    pub fn insert(&self, query: &str, types: &[Type], statement: Statement) {
+        let mut inner = self.inner.lock();
+        if inner.capacity == 0 {
+            return;
+        }
+
+        let inner = &mut *inner;
+        match inner.entries.get_refresh(query) {
+            Some(statements) => match statements.iter_mut().find(|(t, _)| t == types) {
+                Some(entry) => entry.1 = statement,
+                None => {
+                    statements.push((types.to_vec(), statement));
+                    inner.len += 1;
+                }
+            },
+            None => {
+                inner
+                    .entries
+                    .insert(query.to_string(), vec![(types.to_vec(), statement)]);
+                inner.len += 1;
+            }
+        }
+        inner.evict();
+    }
+
+    /// Removes the statement prepared for a query and parameter types from the cache.
+    pub fn remove(&self, query: &str, types: &[Type]) -> Option<Statement> {
+        let mut inner = self.inner.lock();
+        let statements = inner.entries.get_mut(query)?;
+        let idx = statements.iter().position(|(t, _)| t == types)?;
+        let (_, statement) = statements.remove(idx);
+        if statements.is_empty() {
+            inner.entries.remove(query);
+        }
+        inner.len -= 1;
+        Some(statement)
+    }
+
+    /// Looks up the rewritten form of a query with named parameters.
+    pub(crate) fn get_named(&self, query: &str) -> Option<Arc<NamedQuery>> {
+        self.inner.lock().named.get_refresh(query).cloned()
+    }
+
+    pub(crate) fn insert_named(&self, query: &str, named: Arc<NamedQuery>) {
+        let mut inner = self.inner.lock();
+        if inner.capacity == 0 {
+            return;
+        }
+
+        inner.named.insert(query.to_string(), named);
+        inner.evict();
+    }
+
+    /// Removes a statement from the cache, if present.
+    pub(crate) fn invalidate(&self, statement: &Statement) {
+        let mut inner = self.inner.lock();
+        let statements = match inner.entries.get_mut(statement.query()) {
+            Some(statements) => statements,
+            None => return,
+        };
+        let len = statements.len();
+        statements.retain(|(_, s)| s.name() != statement.name());
+        let removed = len - statements.len();
+        if statements.is_empty() {
+            inner.entries.remove(statement.query());
+        }
+        inner.len -= removed;
+    }
+}
+
+impl Inner {
+    /// Evicts the least recently used entries until the cache fits its capacity.
+    fn evict(&mut self) {
+        while self.len > self.capacity {
+            match self.entries.pop_front() {
+                Some((_, statements)) => self.len -= statements.len(),
+                None => break,
+            }
+        }
+        while self.named.len() > self.capacity {
+            self.named.pop_front();
+        }
+    }
+}
@@ -15,9 +15,13 @@ mod private {
         pub async fn into_statement(self, client: &Client) -> Result<Statement, Error> {
             match self {
                 ToStatementType::Statement(s) => Ok(s.clone()),
-                ToStatementType::Query(s) => client.prepare(s).await,
+                ToStatementType::Query(s) => client.prepare_cached(s).await,
             }
         }
+
+        pub fn is_query(&self) -> bool {
+            matches!(self, ToStatementType::Query(_))
+        }
     }
 }
 
@@ -9,8 +9,8 @@ use crate::types::{BorrowToSql, ToSql, Type};
 #[cfg(feature = "runtime")]
 use crate::Socket;
 use crate::{
-    bind, query, slice_iter, CancelToken, Client, CopyInSink, Error, Portal, Row,
-    SimpleQueryMessage, Statement, ToStatement,
+    bind, query, slice_iter, CancelToken, ChunkedRowStream, Client, Column, CopyInSink, Cursor,
+    CursorBuilder, Error, FromRow, Portal, Row, SimpleQueryMessage, Statement, ToStatement,
 };
 use bytes::Buf;
 use futures_util::TryStreamExt;
@@ -35,6 +35,9 @@ struct Savepoint {
 
 impl Drop for Transaction<'_> {
     fn drop(&mut self) {
+        if self.savepoint.is_none() {
+            self.client.set_in_transaction(false);
+        }
         if self.done {
             return;
         }
@@ -57,6 +60,7 @@ impl Drop for Transaction<'_> {
 
 impl<'a> Transaction<'a> {
     pub(crate) fn new(client: &'a mut Client) -> Transaction<'a> {
+        client.set_in_transaction(true);
         Transaction {
             client,
             savepoint: None,
@@ -102,6 +106,11 @@ impl<'a> Transaction<'a> {
         self.client.prepare_typed(query, parameter_types).await
     }
 
+    /// Like `Client::column_nullability`.
+    pub async fn column_nullability(&self, columns: &[Column]) -> Result<Vec<Option<bool>>, Error> {
+        self.client.column_nullability(columns).await
+    }
+
     /// Like `Client::query`.
     pub async fn query<T>(
         &self,
@@ -114,6 +123,33 @@ impl<'a> Transaction<'a> {
         self.client.query(statement, params).await
     }
 
+    /// Like `Client::query_text`.
+    pub async fn query_text<T>(
+        &self,
+        statement: &T,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Vec<Row>, Error>
+    where
+        T: ?Sized + ToStatement,
+    {
+        self.client.query_text(statement, params).await
+    }
+
+    /// Like `Client::query_chunked`.
+    pub async fn query_chunked<T>(
+        &self,
+        statement: &T,
+        params: &[&(dyn ToSql + Sync)],
+        fetch_size: i32,
+    ) -> Result<ChunkedRowStream, Error>
+    where
+        T: ?Sized + ToStatement,
+    {
+        self.client
+            .query_chunked(statement, params, fetch_size)
+            .await
+    }
+
     /// Like `Client::query_one`.
     pub async fn query_one<T>(
         &self,
@@ -138,6 +174,45 @@ impl<'a> Transaction<'a> {
         self.client.query_opt(statement, params).await
     }
 
+    /// Like `Client::query_as`.
+    pub async fn query_as<R, T>(
+        &self,
+        statement: &T,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Vec<R>, Error>
+    where
+        R: FromRow,
+        T: ?Sized + ToStatement,
+    {
+        self.client.query_as(statement, params).await
+    }
+
+    /// Like `Client::query_one_as`.
+    pub async fn query_one_as<R, T>(
+        &self,
+        statement: &T,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<R, Error>
+    where
+        R: FromRow,
+        T: ?Sized + ToStatement,
+    {
+        self.client.query_one_as(statement, params).await
+    }
+
+    /// Like `Client::query_opt_as`.
+    pub async fn query_opt_as<R, T>(
+        &self,
+        statement: &T,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<R>, Error>
+    where
+        R: FromRow,
+        T: ?Sized + ToStatement,
+    {
+        self.client.query_opt_as(statement, params).await
+    }
+
     /// Like `Client::query_raw`.
     pub async fn query_raw<T, P, I>(&self, statement: &T, params: I) -> Result<RowStream, Error>
     where
@@ -190,6 +265,42 @@ impl<'a> Transaction<'a> {
         self.client.execute_raw(statement, params).await
     }
 
+    /// Like `Client::query_named`.
+    pub async fn query_named(
+        &self,
+        query: &str,
+        params: &[(&str, &(dyn ToSql + Sync))],
+    ) -> Result<Vec<Row>, Error> {
+        self.client.query_named(query, params).await
+    }
+
+    /// Like `Client::query_one_named`.
+    pub async fn query_one_named(
+        &self,
+        query: &str,
+        params: &[(&str, &(dyn ToSql + Sync))],
+    ) -> Result<Row, Error> {
+        self.client.query_one_named(query, params).await
+    }
+
+    /// Like `Client::query_opt_named`.
+    pub async fn query_opt_named(
+        &self,
+        query: &str,
+        params: &[(&str, &(dyn ToSql + Sync))],
+    ) -> Result<Option<Row>, Error> {
+        self.client.query_opt_named(query, params).await
+    }
+
+    /// Like `Client::execute_named`.
+    pub async fn execute_named(
+        &self,
+        query: &str,
+        params: &[(&str, &(dyn ToSql + Sync))],
+    ) -> Result<u64, Error> {
+        self.client.execute_named(query, params).await
+    }
+
     /// Binds a statement to a set of parameters, creating a `Portal` which can be incrementally queried.
     ///
     /// Portals only last for the duration of the transaction in which they are created, and can only be used on the
@@ -220,7 +331,9 @@ impl<'a> Transaction<'a> {
         I::IntoIter: ExactSizeIterator,
     {
         let statement = statement.__convert().into_statement(self.client).await?;
-        bind::bind(self.client.inner(), statement, params).await
+        let current = self.client.inner().reprepared(&statement);
+        let result = bind::bind(self.client.inner(), current, params).await;
+        self.client.invalidate_if_stale(&statement, result)
     }
 
     /// Continues execution of a portal, returning a stream of the resulting rows.
@@ -245,6 +358,25 @@ impl<'a> Transaction<'a> {
         query::query_portal(self.client.inner(), portal, max_rows).await
     }
 
+    /// Declares a server-side cursor over the rows of a query.
+    ///
+    /// Unlike portals, cursors can be scrolled backwards and repositioned, subject to the options they were declared
+    /// with. Use [`build_cursor`] to set those options.
+    ///
+    /// [`build_cursor`]: #method.build_cursor
+    pub async fn cursor(
+        &self,
+        query: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Cursor, Error> {
+        self.build_cursor(query).declare(params).await
+    }
+
+    /// Like `Client::build_cursor`.
+    pub fn build_cursor(&self, query: &str) -> CursorBuilder<'_> {
+        self.client.build_cursor(query)
+    }
+
     /// Like `Client::copy_in`.
     pub async fn copy_in<T, U>(&self, statement: &T) -> Result<CopyInSink<U>, Error>
     where
@@ -0,0 +1,157 @@
+use crate::connect;
+use arrow_array_54::cast::AsArray;
+use arrow_array_54::types::{
+    Date32Type, Decimal128Type, Int32Type, Int64Type, TimestampMicrosecondType,
+};
+use arrow_array_54::Array;
+use arrow_schema_54::{DataType, TimeUnit};
+use futures_util::TryStreamExt;
+use tokio_gaussdb::binary_copy::BinaryCopyOutStream;
+use tokio_gaussdb::types::Type;
+
+#[tokio::test]
+async fn row_stream() {
+    let client = connect("user=postgres").await;
+
+    let stream = client
+        .query_raw(
+            "SELECT x::INT4 AS id, \
+                    CASE WHEN x % 2 = 0 THEN 'v' || x END AS name, \
+                    x::NUMERIC(10, 2) / 4 AS amount, \
+                    DATE '1970-01-01' + x AS day, \
+                    TIMESTAMPTZ '1970-01-01 00:00:00+00' + x * INTERVAL '1 second' AS at, \
+                    ARRAY[x, NULL, x * 2]::INT8[] AS list, \
+                    '{\"a\": 1}'::JSONB AS doc, \
+                    '\\x0102'::BYTEA AS data, \
+                    'a0eebc99-9c0b-4ef8-bb6d-6bb9bd380a11'::UUID AS uid \
+             FROM generate_series(1, 5) x",
+            Vec::<String>::new(),
+        )
+        .await
+        .unwrap();
+    let stream = stream.into_record_batches(2).unwrap();
+
+    let schema = stream.schema();
+    assert_eq!(schema.field(0).name(), "id");
+    assert_eq!(schema.field(0).data_type(), &DataType::Int32);
+    assert_eq!(schema.field(1).data_type(), &DataType::Utf8);
+    assert_eq!(schema.field(2).data_type(), &DataType::Decimal128(38, 10));
+    assert_eq!(schema.field(3).data_type(), &DataType::Date32);
+    assert_eq!(
+        schema.field(4).data_type(),
+        &DataType::Timestamp(TimeUnit::Microsecond, Some("UTC".into()))
+    );
+    assert!(matches!(schema.field(5).data_type(), DataType::List(_)));
+    assert_eq!(
+        schema.field(6).metadata()["ARROW:extension:name"],
+        "arrow.json"
+    );
+    assert_eq!(schema.field(8).data_type(), &DataType::FixedSizeBinary(16));
+
+    let batches = stream.try_collect::<Vec<_>>().await.unwrap();
+    assert_eq!(
+        batches.iter().map(|b| b.num_rows()).collect::<Vec<_>>(),
+        [2, 2, 1]
+    );
+
+    let batch = &batches[1];
+    assert_eq!(batch.column(0).as_primitive::<Int32Type>().value(0), 3);
+    let names = batch.column(1).as_string::<i32>();
+    assert!(names.is_null(0));
+    assert_eq!(names.value(1), "v4");
+    assert_eq!(
+        batch.column(2).as_primitive::<Decimal128Type>().value(0),
+        7_500_000_000
+    );
+    assert_eq!(batch.column(3).as_primitive::<Date32Type>().value(0), 3);
+    assert_eq!(
+        batch
+            .column(4)
+            .as_primitive::<TimestampMicrosecondType>()
+            .value(0),
+        3_000_000
+    );
+    let list = batch.column(5).as_list::<i32>().value(0);
+    let list = list.as_primitive::<Int64Type>();
+    assert_eq!(list.len(), 3);
+    assert_eq!(list.value(0), 3);
+    assert!(list.is_null(1));
+    assert_eq!(list.value(2), 6);
+    assert_eq!(batch.column(6).as_string::<i32>().value(0), "{\"a\": 1}");
+    assert_eq!(batch.column(7).as_binary::<i32>().value(0), [1, 2]);
+    assert_eq!(batch.column(8).as_fixed_size_binary().value(0).len(), 16);
+}
+
+#[tokio::test]
+async fn declared_numeric() {
+    let client = connect("user=postgres").await;
+
+    let stream = client
+        .query_raw("SELECT 12.345::NUMERIC(7, 3)", Vec::<String>::new())
+        .await
+        .unwrap();
+    let stream = stream.into_record_batches(10).unwrap();
+    assert_eq!(
+        stream.schema().field(0).data_type(),
+        &DataType::Decimal128(7, 3)
+    );
+    let batches = stream.try_collect::<Vec<_>>().await.unwrap();
+    assert_eq!(
+        batches[0]
+            .column(0)
+            .as_primitive::<Decimal128Type>()
+            .value(0),
+        12345
+    );
+
+    let stream = client
+        .query_raw("SELECT 'NaN'::NUMERIC", Vec::<String>::new())
+        .await
+        .unwrap();
+    let stream = stream.into_record_batches(10).unwrap();
+    assert!(stream.try_collect::<Vec<_>>().await.is_err());
+}
+
+#[tokio::test]
+async fn unsupported_type() {
+    let client = connect("user=postgres").await;
+
+    let stream = client
+        .query_raw("SELECT 1, POINT(1, 2)", Vec::<String>::new())
+        .await
+        .unwrap();
+    assert!(stream.into_record_batches(10).is_err());
+}
+
+#[tokio::test]
+async fn binary_copy_out() {
+    let client = connect("user=postgres").await;
+
+    client
+        .batch_execute(
+            "CREATE TEMPORARY TABLE foo (id INT, bar TEXT);
+             INSERT INTO foo SELECT x, 'bar ' || x FROM generate_series(1, 10) x",
+        )
+        .await
+        .unwrap();
+
+    let stream = client
+        .copy_out("COPY foo (id, bar) TO STDOUT BINARY")
+        .await
+        .unwrap();
+    let stream = BinaryCopyOutStream::new(stream, &[Type::INT4, Type::TEXT])
+        .into_record_batches(4)
+        .unwrap();
+    assert_eq!(stream.schema().field(1).name(), "column2");
+
+    let batches = stream.try_collect::<Vec<_>>().await.unwrap();
+    assert_eq!(
+        batches.iter().map(|b| b.num_rows()).collect::<Vec<_>>(),
+        [4, 4, 2]
+    );
+    assert_eq!(
+        batches[2].column(0).as_primitive::<Int32Type>().value(1),
+        10
+    );
+    assert_eq!(batches[2].column(1).as_string::<i32>().value(1), "bar 10");
+}
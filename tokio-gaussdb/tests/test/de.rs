@@ -0,0 +1,121 @@
+use crate::connect;
+use futures_util::TryStreamExt;
+use serde_1::Deserialize;
+use std::collections::HashMap;
+
+#[derive(Deserialize, Debug, PartialEq)]
+#[serde(crate = "serde_1")]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[derive(Deserialize, Debug, PartialEq)]
+#[serde(crate = "serde_1", rename_all = "lowercase")]
+enum Color {
+    Red,
+    Green,
+}
+
+#[derive(Deserialize, Debug, PartialEq)]
+#[serde(crate = "serde_1")]
+struct Item {
+    id: i64,
+    name: String,
+    note: Option<String>,
+    price: f64,
+    tags: Vec<String>,
+    location: Point,
+    color: Color,
+    data: Vec<u8>,
+    missing: Option<i32>,
+}
+
+#[tokio::test]
+async fn deserialize_row() {
+    let client = connect("user=postgres").await;
+
+    let row = client
+        .query_one(
+            "SELECT 1::INT8 AS id, 'foo' AS name, NULL::TEXT AS note, 1.5::NUMERIC AS price, \
+                    ARRAY['a', 'b'] AS tags, '{\"x\": 1, \"y\": 2}'::JSONB AS location, \
+                    'green' AS color, '\\x0102'::BYTEA AS data, 'ignored' AS extra",
+            &[],
+        )
+        .await
+        .unwrap();
+    let item = row.deserialize::<Item>().unwrap();
+    assert_eq!(
+        item,
+        Item {
+            id: 1,
+            name: "foo".to_string(),
+            note: None,
+            price: 1.5,
+            tags: vec!["a".to_string(), "b".to_string()],
+            location: Point { x: 1, y: 2 },
+            color: Color::Green,
+            data: vec![1, 2],
+            missing: None,
+        }
+    );
+
+    let row = client
+        .query_one("SELECT 1::INT4, 'foo'::TEXT, NULL::INT2", &[])
+        .await
+        .unwrap();
+    assert_eq!(
+        row.deserialize::<(i64, String, Option<i16>)>().unwrap(),
+        (1, "foo".to_string(), None)
+    );
+
+    let row = client
+        .query_one("SELECT 1::INT4 AS a, 2::INT4 AS b", &[])
+        .await
+        .unwrap();
+    let map = row.deserialize::<HashMap<String, i32>>().unwrap();
+    assert_eq!(map["a"], 1);
+    assert_eq!(map["b"], 2);
+}
+
+#[tokio::test]
+async fn deserialize_error() {
+    let client = connect("user=postgres").await;
+
+    let row = client
+        .query_one("SELECT 'foo'::TEXT AS x, 2 AS y", &[])
+        .await
+        .unwrap();
+    let err = row.deserialize::<Point>().unwrap_err().to_string();
+    assert!(err.contains("column `x` of type `text`"), "{}", err);
+
+    let row = client.query_one("SELECT 1 AS x", &[]).await.unwrap();
+    let err = row.deserialize::<Point>().unwrap_err().to_string();
+    assert!(err.contains("missing field `y`"), "{}", err);
+}
+
+#[tokio::test]
+async fn deserialize_stream() {
+    let client = connect("user=postgres").await;
+
+    let stream = client
+        .query_raw(
+            "SELECT x AS x, x * 2 AS y FROM generate_series(1, 3) x",
+            Vec::<String>::new(),
+        )
+        .await
+        .unwrap();
+    let points = stream
+        .deserialize::<Point>()
+        .try_collect::<Vec<_>>()
+        .await
+        .unwrap();
+    assert_eq!(
+        points,
+        [
+            Point { x: 1, y: 2 },
+            Point { x: 2, y: 4 },
+            Point { x: 3, y: 6 },
+        ]
+    );
+}
@@ -6,6 +6,7 @@ use futures_util::{
     future, join, pin_mut, stream, try_join, Future, FutureExt, SinkExt, StreamExt, TryStreamExt,
 };
 use pin_project_lite::pin_project;
+use std::collections::HashMap;
 use std::fmt::Write;
 use std::pin::Pin;
 use std::task::{Context, Poll};
@@ -14,12 +15,17 @@ use tokio::net::TcpStream;
 use tokio::time;
 use tokio_gaussdb::error::SqlState;
 use tokio_gaussdb::tls::{NoTls, NoTlsStream};
-use tokio_gaussdb::types::{Kind, Type};
+use tokio_gaussdb::types::{Kind, NamedParams, Type};
 use tokio_gaussdb::{
-    AsyncMessage, Client, Config, Connection, Error, IsolationLevel, SimpleQueryMessage,
+    AsyncMessage, Client, Config, Connection, Error, FetchDirection, FromRow, GenericClient,
+    IsolationLevel, Row, SimpleQueryMessage,
 };
 
+#[cfg(feature = "arrow")]
+mod arrow;
 mod binary_copy;
+#[cfg(feature = "serde")]
+mod de;
 mod parse;
 #[cfg(feature = "runtime")]
 mod runtime;
@@ -1178,3 +1184,559 @@ async fn query_typed_with_transaction() {
         .unwrap();
     assert_eq!(updated_rows.len(), 0);
 }
+
+#[tokio::test]
+async fn statement_cache() {
+    let mut client = connect("user=postgres").await;
+    client.set_statement_cache_capacity(2);
+
+    client
+        .batch_execute("CREATE TEMPORARY TABLE foo (id INT); INSERT INTO foo VALUES (1)")
+        .await
+        .unwrap();
+
+    for _ in 0..3 {
+        let row = client.query_one("SELECT * FROM foo", &[]).await.unwrap();
+        assert_eq!(row.len(), 1);
+    }
+    assert_eq!(client.statement_cache().misses(), 1);
+    assert_eq!(client.statement_cache().hits(), 2);
+
+    client.execute("SELECT 1", &[]).await.unwrap();
+    client.execute("SELECT 2", &[]).await.unwrap();
+    assert_eq!(client.statement_cache().len(), 2);
+
+    client.query_one("SELECT * FROM foo", &[]).await.unwrap();
+    assert_eq!(client.statement_cache().misses(), 4);
+
+    // the cached plan for `SELECT *` no longer matches the table's row type
+    client
+        .batch_execute("ALTER TABLE foo ADD COLUMN name TEXT")
+        .await
+        .unwrap();
+    let row = client.query_one("SELECT * FROM foo", &[]).await.unwrap();
+    assert_eq!(row.len(), 2);
+    assert_eq!(client.statement_cache().len(), 2);
+
+    // inside a transaction the error aborts it, so the statement is dropped from the cache instead
+    client
+        .batch_execute("ALTER TABLE foo ADD COLUMN age INT")
+        .await
+        .unwrap();
+    let transaction = client.transaction().await.unwrap();
+    let err = transaction
+        .query_one("SELECT * FROM foo", &[])
+        .await
+        .unwrap_err();
+    assert_eq!(err.code(), Some(&SqlState::FEATURE_NOT_SUPPORTED));
+    drop(transaction);
+    assert_eq!(client.statement_cache().len(), 1);
+
+    let row = client.query_one("SELECT * FROM foo", &[]).await.unwrap();
+    assert_eq!(row.len(), 3);
+
+    client.set_statement_cache_capacity(0);
+    assert!(client.statement_cache().is_empty());
+    client.query_one("SELECT * FROM foo", &[]).await.unwrap();
+    assert!(client.statement_cache().is_empty());
+}
+
+#[tokio::test]
+async fn reprepare_stale_statement() {
+    let mut client = connect("user=postgres").await;
+
+    client
+        .batch_execute("CREATE TEMPORARY TABLE foo (id INT); INSERT INTO foo VALUES (1)")
+        .await
+        .unwrap();
+
+    let stmt = client
+        .prepare_typed("SELECT * FROM foo WHERE id = $1", &[Type::INT4])
+        .await
+        .unwrap();
+    let rows = client.query(&stmt, &[&1i32]).await.unwrap();
+    assert_eq!(rows[0].len(), 1);
+
+    // statements dropped on the server are prepared again transparently
+    client.batch_execute("DEALLOCATE ALL").await.unwrap();
+    let rows = client.query(&stmt, &[&1i32]).await.unwrap();
+    assert_eq!(rows[0].len(), 1);
+    assert_eq!(client.execute(&stmt, &[&1i32]).await.unwrap(), 1);
+
+    // but the caller has to prepare a statement whose columns changed again
+    client
+        .batch_execute("ALTER TABLE foo ADD COLUMN name TEXT")
+        .await
+        .unwrap();
+    let err = client.query(&stmt, &[&1i32]).await.unwrap_err();
+    assert_eq!(err.code(), Some(&SqlState::FEATURE_NOT_SUPPORTED));
+    assert!(err
+        .to_string()
+        .starts_with("statement must be prepared again"));
+
+    let stmt = client
+        .prepare_typed("SELECT * FROM foo WHERE id = $1", &[Type::INT4])
+        .await
+        .unwrap();
+    let rows = client.query(&stmt, &[&1i32]).await.unwrap();
+    assert_eq!(rows[0].len(), 2);
+
+    client
+        .batch_execute("ALTER TABLE foo ADD COLUMN age INT")
+        .await
+        .unwrap();
+    let transaction = client.transaction().await.unwrap();
+    let err = transaction.query(&stmt, &[&1i32]).await.unwrap_err();
+    assert_eq!(err.code(), Some(&SqlState::FEATURE_NOT_SUPPORTED));
+    transaction.rollback().await.unwrap();
+
+    // query strings are prepared again regardless, since their statement is not visible to the caller
+    client.set_statement_cache_capacity(10);
+    let rows = client.query("SELECT * FROM foo", &[]).await.unwrap();
+    assert_eq!(rows[0].len(), 3);
+    client
+        .batch_execute("ALTER TABLE foo DROP COLUMN age")
+        .await
+        .unwrap();
+    let rows = client.query("SELECT * FROM foo", &[]).await.unwrap();
+    assert_eq!(rows[0].len(), 2);
+    assert_eq!(client.statement_cache().len(), 1);
+}
+
+#[tokio::test]
+async fn query_named() {
+    let mut client = connect("user=postgres").await;
+    client.set_statement_cache_capacity(10);
+
+    client
+        .batch_execute(
+            "CREATE TEMPORARY TABLE foo (id INT, name TEXT);
+             INSERT INTO foo VALUES (1, 'alice'), (2, 'bob'), (3, ':name')",
+        )
+        .await
+        .unwrap();
+
+    let rows = client
+        .query_named(
+            "SELECT id, name FROM foo WHERE id >= :min AND id <= :max AND name <> ':name' ORDER BY id",
+            &[("max", &2i32), ("min", &1i32)],
+        )
+        .await
+        .unwrap();
+    assert_eq!(rows.len(), 2);
+    assert_eq!(rows[1].get::<_, &str>(1), "bob");
+
+    let row = client
+        .query_one_named(
+            "SELECT name::TEXT FROM foo WHERE id = @id -- AND name = :ignored",
+            &[("id", &3i32)],
+        )
+        .await
+        .unwrap();
+    assert_eq!(row.get::<_, &str>(0), ":name");
+
+    let row = client
+        .query_opt_named(
+            "SELECT $$:name$$, name FROM foo WHERE name = :name",
+            &[("name", &"alice")],
+        )
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(row.get::<_, &str>(0), ":name");
+    assert_eq!(row.get::<_, &str>(1), "alice");
+
+    let transaction = client.transaction().await.unwrap();
+    let modified = transaction
+        .execute_named(
+            "UPDATE foo SET name = :name WHERE id = :id",
+            &[("id", &2i32), ("name", &"carol")],
+        )
+        .await
+        .unwrap();
+    assert_eq!(modified, 1);
+    transaction.commit().await.unwrap();
+
+    let mut params = HashMap::new();
+    params.insert("id", 2i32);
+    let row = client
+        .query_one_named(
+            "SELECT name FROM foo WHERE id = :id",
+            &params.named_params(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(row.get::<_, &str>(0), "carol");
+
+    let err = client
+        .query_named("SELECT :a, :b", &[("a", &1i32)])
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("no value for parameter `b`"));
+    let err = client
+        .query_named("SELECT :a", &[("a", &1i32), ("b", &1i32)])
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("`b` does not appear"));
+}
+
+#[tokio::test]
+async fn query_as() {
+    #[derive(Debug, PartialEq)]
+    struct Pair {
+        id: i32,
+        name: String,
+    }
+
+    impl FromRow for Pair {
+        fn from_row(row: &Row) -> Result<Pair, Error> {
+            Ok(Pair {
+                id: row.try_get("id")?,
+                name: row.try_get("name")?,
+            })
+        }
+    }
+
+    async fn first<C: GenericClient>(client: &C) -> Result<Pair, Error> {
+        client
+            .query_one_as::<Pair, _>("SELECT 1 AS id, 'a' AS name", &[])
+            .await
+    }
+
+    let client = connect("user=postgres").await;
+
+    let pairs = client
+        .query_as::<Pair, _>(
+            "SELECT i AS id, i::TEXT AS name FROM generate_series(1, 3) i",
+            &[],
+        )
+        .await
+        .unwrap();
+    assert_eq!(pairs.len(), 3);
+    assert_eq!(
+        pairs[2],
+        Pair {
+            id: 3,
+            name: "3".to_string()
+        }
+    );
+
+    assert_eq!(first(&client).await.unwrap().name, "a");
+
+    let pair = client
+        .query_opt_as::<Pair, _>("SELECT 1 AS id, 'a' AS name WHERE false", &[])
+        .await
+        .unwrap();
+    assert_eq!(pair, None);
+
+    let err = client
+        .query_one_as::<Pair, _>("SELECT 1 AS id", &[])
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("invalid column `name`"));
+}
+
+#[tokio::test]
+async fn column_type_modifiers() {
+    let client = connect("user=postgres").await;
+
+    client
+        .batch_execute(
+            "CREATE TEMPORARY TABLE foo (
+                amount NUMERIC(12, 2) NOT NULL,
+                rounded NUMERIC(4, -2),
+                plain NUMERIC,
+                code CHAR(3),
+                name VARCHAR(20) NOT NULL,
+                ts TIMESTAMP(3),
+                span INTERVAL DAY TO SECOND(2),
+                flags BIT(5),
+                bits VARBIT
+            )",
+        )
+        .await
+        .unwrap();
+
+    let stmt = client
+        .prepare(
+            "SELECT amount, rounded, plain, code, name, ts, span, flags, bits, 1 AS one FROM foo",
+        )
+        .await
+        .unwrap();
+    let columns = stmt.columns();
+
+    assert_eq!(columns[0].numeric_precision(), Some(12));
+    assert_eq!(columns[0].numeric_scale(), Some(2));
+    assert_eq!(columns[1].numeric_precision(), Some(4));
+    assert_eq!(columns[1].numeric_scale(), Some(-2));
+    assert_eq!(columns[2].type_modifier(), -1);
+    assert_eq!(columns[2].numeric_precision(), None);
+    assert_eq!(columns[2].numeric_scale(), None);
+    assert_eq!(columns[3].character_length(), Some(3));
+    assert_eq!(columns[4].character_length(), Some(20));
+    assert_eq!(columns[4].numeric_precision(), None);
+    assert_eq!(columns[5].timestamp_precision(), Some(3));
+    assert_eq!(columns[6].timestamp_precision(), Some(2));
+    assert_eq!(columns[7].bit_length(), Some(5));
+    assert_eq!(columns[8].bit_length(), None);
+    assert_eq!(columns[9].type_modifier(), -1);
+    assert!(columns.iter().all(|c| c.format() == 0));
+
+    let nullability = client.column_nullability(columns).await.unwrap();
+    assert_eq!(nullability[0], Some(false));
+    assert_eq!(nullability[1], Some(true));
+    assert_eq!(nullability[4], Some(false));
+    assert_eq!(nullability[9], None);
+
+    client
+        .execute("INSERT INTO foo (amount, name) VALUES (1.5, 'x')", &[])
+        .await
+        .unwrap();
+    let rows = client
+        .query_typed("SELECT amount FROM foo", &[])
+        .await
+        .unwrap();
+    assert_eq!(rows[0].columns()[0].numeric_scale(), Some(2));
+}
+
+#[tokio::test]
+async fn query_text() {
+    let client = connect("user=postgres").await;
+
+    let rows = client
+        .query_text(
+            "SELECT $1::INT4 AS num, '1 day'::INTERVAL AS span, ARRAY['a', NULL] AS arr, NULL::TEXT AS nothing",
+            &[&7i32],
+        )
+        .await
+        .unwrap();
+    let row = &rows[0];
+    assert!(row.is_text());
+    assert_eq!(row.get_text::<_, i32>("num"), 7);
+    assert_eq!(row.get_text::<_, &str>(1), "1 day");
+    assert_eq!(
+        row.get_text::<_, Vec<Option<String>>>("arr"),
+        vec![Some("a".to_string()), None]
+    );
+    assert_eq!(row.get_text::<_, Option<&str>>("nothing"), None);
+    assert!(row.try_get_text::<_, &str>("nothing").is_err());
+    assert!(row.try_get::<_, i32>(0).is_err());
+
+    let rows = client.query("SELECT 1::INT4", &[]).await.unwrap();
+    assert!(!rows[0].is_text());
+    assert!(rows[0].try_get_text::<_, i32>(0).is_err());
+
+    let messages = client
+        .simple_query("SELECT 1::INT4, 't'::BOOL, NULL::TEXT")
+        .await
+        .unwrap();
+    let row = match &messages[1] {
+        SimpleQueryMessage::Row(row) => row,
+        _ => panic!("unexpected message"),
+    };
+    assert_eq!(row.try_get::<_, i32>(0).unwrap(), 1);
+    assert!(row.try_get::<_, bool>(1).unwrap());
+    assert_eq!(row.try_get::<_, Option<String>>(2).unwrap(), None);
+    assert!(row.try_get::<_, i32>(1).is_err());
+}
+
+#[tokio::test]
+async fn cursor_scroll() {
+    let mut client = connect("user=postgres").await;
+
+    let transaction = client.transaction().await.unwrap();
+    let cursor = transaction
+        .build_cursor("SELECT * FROM generate_series(1, $1::INT4) AS i")
+        .scroll(true)
+        .declare(&[&10i32])
+        .await
+        .unwrap();
+
+    let ids = |rows: Vec<Row>| rows.iter().map(|r| r.get(0)).collect::<Vec<i32>>();
+
+    assert_eq!(ids(cursor.fetch_forward(3).await.unwrap()), [1, 2, 3]);
+    assert_eq!(
+        cursor.fetch_next().await.unwrap().unwrap().get::<_, i32>(0),
+        4
+    );
+    assert_eq!(ids(cursor.fetch_backward(2).await.unwrap()), [3, 2]);
+    assert_eq!(
+        cursor.fetch_last().await.unwrap().unwrap().get::<_, i32>(0),
+        10
+    );
+    assert!(cursor.fetch_next().await.unwrap().is_none());
+    assert_eq!(
+        cursor
+            .fetch_prior()
+            .await
+            .unwrap()
+            .unwrap()
+            .get::<_, i32>(0),
+        10
+    );
+    assert_eq!(
+        cursor
+            .fetch_absolute(5)
+            .await
+            .unwrap()
+            .unwrap()
+            .get::<_, i32>(0),
+        5
+    );
+    assert_eq!(
+        cursor
+            .fetch_absolute(-2)
+            .await
+            .unwrap()
+            .unwrap()
+            .get::<_, i32>(0),
+        9
+    );
+    assert_eq!(
+        cursor
+            .fetch_relative(-3)
+            .await
+            .unwrap()
+            .unwrap()
+            .get::<_, i32>(0),
+        6
+    );
+    assert_eq!(cursor.seek(FetchDirection::First).await.unwrap(), 1);
+    assert_eq!(cursor.seek(FetchDirection::Forward(4)).await.unwrap(), 4);
+    assert_eq!(
+        ids(cursor.fetch(FetchDirection::ForwardAll).await.unwrap()),
+        [6, 7, 8, 9, 10]
+    );
+    assert_eq!(
+        ids(cursor.fetch(FetchDirection::Backward(3)).await.unwrap()),
+        [10, 9, 8]
+    );
+
+    let count = || async {
+        transaction
+            .query_one("SELECT count(*) FROM pg_cursors WHERE name <> ''", &[])
+            .await
+            .unwrap()
+            .get::<_, i64>(0)
+    };
+    assert_eq!(count().await, 1);
+    drop(cursor);
+    assert_eq!(count().await, 0);
+
+    let cursor = transaction
+        .cursor("SELECT * FROM generate_series(1, 3)", &[])
+        .await
+        .unwrap();
+    assert_eq!(ids(cursor.fetch_forward(5).await.unwrap()), [1, 2, 3]);
+    cursor.close().await.unwrap();
+    assert_eq!(count().await, 0);
+
+    let cursor = transaction
+        .build_cursor("SELECT * FROM generate_series(1, 3)")
+        .scroll(false)
+        .declare(&[])
+        .await
+        .unwrap();
+    cursor.fetch_next().await.unwrap();
+    assert!(cursor.fetch_prior().await.is_err());
+}
+
+#[tokio::test]
+async fn cursor_hold() {
+    let mut client = connect("user=postgres").await;
+
+    let transaction = client.transaction().await.unwrap();
+    let cursor = transaction
+        .build_cursor("SELECT * FROM generate_series(1, 5)")
+        .hold(true)
+        .declare(&[])
+        .await
+        .unwrap();
+    assert_eq!(
+        cursor.fetch_next().await.unwrap().unwrap().get::<_, i32>(0),
+        1
+    );
+    transaction.commit().await.unwrap();
+
+    assert_eq!(
+        cursor.fetch_next().await.unwrap().unwrap().get::<_, i32>(0),
+        2
+    );
+
+    let held = client
+        .build_cursor("SELECT * FROM generate_series(1, 2)")
+        .hold(true)
+        .declare(&[])
+        .await
+        .unwrap();
+    assert_eq!(held.fetch_forward(2).await.unwrap().len(), 2);
+    assert!(client.build_cursor("SELECT 1").declare(&[]).await.is_err());
+
+    drop(cursor);
+    drop(held);
+    let rows = client
+        .query("SELECT count(*) FROM pg_cursors WHERE name <> ''", &[])
+        .await
+        .unwrap();
+    assert_eq!(rows[0].get::<_, i64>(0), 0);
+}
+
+#[tokio::test]
+async fn query_chunked() {
+    let client = connect("user=postgres").await;
+
+    let stream = client
+        .query_chunked("SELECT * FROM generate_series(1, $1)", &[&10i32], 3)
+        .await
+        .unwrap();
+    pin_mut!(stream);
+    let mut values = vec![];
+    while let Some(row) = stream.try_next().await.unwrap() {
+        values.push(row.get::<_, i32>(0));
+    }
+    assert_eq!(values, (1..=10).collect::<Vec<_>>());
+    assert_eq!(stream.rows_affected(), Some(10));
+
+    let stream = client
+        .query_chunked("SELECT 1 / (x - 5) FROM generate_series(1, 10) x", &[], 2)
+        .await
+        .unwrap();
+    let rows = stream.collect::<Vec<_>>().await;
+    assert_eq!(rows.len(), 5);
+    assert!(rows[..4].iter().all(|r| r.is_ok()));
+    assert!(rows[4].is_err());
+
+    let rows = client.query("SELECT 1", &[]).await.unwrap();
+    assert_eq!(rows[0].get::<_, i32>(0), 1);
+}
+
+#[tokio::test]
+async fn query_chunked_drop() {
+    let mut client = connect("user=postgres").await;
+
+    let stream = client
+        .query_chunked("SELECT * FROM generate_series(1, 1000000)", &[], 10)
+        .await
+        .unwrap();
+    let rows = stream.take(15).try_collect::<Vec<_>>().await.unwrap();
+    assert_eq!(rows.len(), 15);
+
+    let rows = client.query("SELECT 1", &[]).await.unwrap();
+    assert_eq!(rows[0].get::<_, i32>(0), 1);
+
+    let transaction = client.transaction().await.unwrap();
+    let stream = transaction
+        .query_chunked("SELECT * FROM generate_series(1, 100)", &[], 10)
+        .await
+        .unwrap();
+    let mut stream = Box::pin(stream);
+    assert_eq!(
+        stream.try_next().await.unwrap().unwrap().get::<_, i32>(0),
+        1
+    );
+    drop(stream);
+
+    let rows = transaction.query("SELECT 1", &[]).await.unwrap();
+    assert_eq!(rows[0].get::<_, i32>(0), 1);
+    transaction.commit().await.unwrap();
+}
@@ -2,7 +2,7 @@ use futures_util::{join, FutureExt};
 use std::time::Duration;
 use tokio::time;
 use tokio_gaussdb::error::SqlState;
-use tokio_gaussdb::{Client, NoTls};
+use tokio_gaussdb::{Client, Config, NoTls};
 
 async fn connect(s: &str) -> Client {
     let (client, connection) = tokio_gaussdb::connect(s, NoTls).await.unwrap();
@@ -140,3 +140,51 @@ async fn cancel_query() {
         t => panic!("unexpected return: {:?}", t),
     }
 }
+
+#[tokio::test]
+async fn statement_timeout() {
+    let mut config = "host=localhost port=5433 user=gaussdb password=Gaussdb@123 dbname=postgres"
+        .parse::<Config>()
+        .unwrap();
+    config.statement_timeout(Duration::from_millis(100));
+    let (client, connection) = config.connect(NoTls).await.unwrap();
+    tokio::spawn(connection.map(|e| e.unwrap()));
+
+    let err = client
+        .batch_execute("SELECT pg_sleep(100)")
+        .await
+        .unwrap_err();
+    assert!(err.is_timeout());
+
+    // the connection is usable again once the cancelled query has been drained
+    let row = client.query_one("SELECT 1::INT", &[]).await.unwrap();
+    assert_eq!(row.get::<_, i32>(0), 1);
+}
+
+#[tokio::test]
+async fn with_timeout() {
+    let mut client =
+        connect("host=localhost port=5433 user=gaussdb password=Gaussdb@123 dbname=postgres").await;
+
+    let err = client
+        .with_timeout(
+            Duration::from_millis(100),
+            client.query("SELECT pg_sleep(100)", &[]),
+        )
+        .await
+        .unwrap_err();
+    assert!(err.is_timeout());
+
+    let rows = client
+        .with_timeout(Duration::from_secs(10), client.query("SELECT 1", &[]))
+        .await
+        .unwrap();
+    assert_eq!(rows.len(), 1);
+
+    client.set_statement_timeout(Some(Duration::from_millis(100)));
+    let err = client
+        .execute("SELECT pg_sleep(100)", &[])
+        .await
+        .unwrap_err();
+    assert!(err.is_timeout());
+}
@@ -0,0 +1,39 @@
+use bigdecimal_04::BigDecimal;
+use std::str::FromStr;
+
+use crate::connect;
+use crate::types::test_type;
+
+#[tokio::test]
+async fn test_bigdecimal_params() {
+    let dec = |s: &str| Some(BigDecimal::from_str(s).unwrap());
+    test_type(
+        "NUMERIC",
+        &[
+            (dec("0"), "0"),
+            (dec("1.50"), "1.50"),
+            (dec("-12345678.0001"), "-12345678.0001"),
+            (dec("0.000000012"), "0.000000012"),
+            (
+                dec("123456789012345678901234567890.123456789012345678901234567890"),
+                "123456789012345678901234567890.123456789012345678901234567890",
+            ),
+            (dec("1e40"), "1e40"),
+            (None, "NULL"),
+        ],
+    )
+    .await
+}
+
+#[tokio::test]
+async fn test_bigdecimal_special_values() {
+    let client = connect("user=postgres").await;
+
+    for value in ["'NaN'", "'Infinity'", "'-Infinity'"] {
+        let row = client
+            .query_one(&*format!("SELECT {}::NUMERIC", value), &[])
+            .await
+            .unwrap();
+        assert!(row.try_get::<_, BigDecimal>(0).is_err(), "{}", value);
+    }
+}
@@ -0,0 +1,43 @@
+use bytes::{Bytes, BytesMut};
+
+use crate::connect;
+use crate::types::test_type;
+
+#[tokio::test]
+async fn test_bytes_params() {
+    test_type(
+        "BYTEA",
+        &[
+            (
+                Some(Bytes::from_static(&[0, 1, 2, 3, 254, 255])),
+                "'\\x00010203feff'",
+            ),
+            (Some(Bytes::new()), "''"),
+            (None, "NULL"),
+        ],
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_bytes_mut_params() {
+    test_type(
+        "BYTEA",
+        &[(Some(BytesMut::from(&b"foo"[..])), "'foo'"), (None, "NULL")],
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_row_get_bytes() {
+    let client = connect("user=postgres").await;
+
+    let row = client
+        .query_one("SELECT '\\x00ff'::BYTEA, NULL::BYTEA, 1::INT4", &[])
+        .await
+        .unwrap();
+    assert_eq!(row.get_bytes(0), Some(Bytes::from_static(&[0, 255])));
+    assert_eq!(row.get_bytes(1), None);
+    assert!(row.try_get_bytes(2).is_err());
+    assert!(row.try_get_bytes(3).is_err());
+}
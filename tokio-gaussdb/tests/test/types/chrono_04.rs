@@ -1,6 +1,8 @@
-use chrono_04::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+use chrono_04::{DateTime, Duration, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+use std::convert::TryFrom;
 use std::fmt;
-use tokio_gaussdb::types::{Date, FromSqlOwned, Timestamp};
+use std::ops::Bound;
+use tokio_gaussdb::types::{Date, FromSqlOwned, Interval, Range, Timestamp};
 use tokio_gaussdb::Client;
 
 use crate::connect;
@@ -159,6 +161,29 @@ async fn test_time_params() {
     .await;
 }
 
+#[tokio::test]
+async fn test_timetz_params() {
+    fn make_check(time: &str, offset: i32) -> (Option<(NaiveTime, FixedOffset)>, String) {
+        (
+            Some((
+                NaiveTime::parse_from_str(time, "%H:%M:%S%.f").unwrap(),
+                FixedOffset::east_opt(offset).unwrap(),
+            )),
+            format!("'{}{:+}'", time, offset / 3600),
+        )
+    }
+    test_type(
+        "TIMETZ",
+        &[
+            make_check("00:00:00.01", 0),
+            make_check("11:19:33.100314", 5 * 3600),
+            make_check("23:11:45.1202", -8 * 3600),
+            (None, "NULL".to_string()),
+        ],
+    )
+    .await;
+}
+
 #[tokio::test]
 async fn test_special_params_without_wrapper() {
     async fn assert_overflows<T>(client: &mut Client, val: &str, sql_type: &str)
@@ -188,3 +213,59 @@ async fn test_special_params_without_wrapper() {
     assert_overflows::<NaiveDate>(&mut client, "'-infinity'", "date").await;
     assert_overflows::<NaiveDate>(&mut client, "'infinity'", "date").await;
 }
+
+#[tokio::test]
+async fn test_interval_duration() {
+    let client = connect("user=postgres").await;
+
+    let interval = client
+        .query_one("SELECT INTERVAL '1 day -00:00:01.5'", &[])
+        .await
+        .unwrap()
+        .get::<_, Interval>(0);
+    let duration = Duration::try_from(interval).unwrap();
+    assert_eq!(duration, Duration::microseconds(86_398_500_000));
+
+    let interval = Interval::try_from(-duration).unwrap();
+    let row = client
+        .query_one("SELECT $1::INTERVAL = INTERVAL '-23:59:58.5'", &[&interval])
+        .await
+        .unwrap();
+    assert!(row.get::<_, bool>(0));
+
+    assert!(Duration::try_from(Interval::new(1, 0, 0)).is_err());
+    assert!(Interval::try_from(Duration::MAX).is_err());
+}
+
+#[tokio::test]
+async fn test_range_params() {
+    let date = |s: &str| NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap();
+    test_type(
+        "DATERANGE",
+        &[
+            (
+                Some(Range::from(date("2010-02-09")..date("2010-03-01"))),
+                "'[2010-02-09,2010-03-01)'",
+            ),
+            (Some(Range::from(date("1965-09-25")..)), "'[1965-09-25,)'"),
+            (None, "NULL"),
+        ],
+    )
+    .await;
+
+    let time = |s: &str| NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S%.f").unwrap();
+    test_type(
+        "TSRANGE",
+        &[
+            (
+                Some(Range::new(
+                    Bound::Excluded(time("1970-01-01 00:00:00.01")),
+                    Bound::Included(time("2010-02-09 23:11:45.1202")),
+                )),
+                "'(\"1970-01-01 00:00:00.01\",\"2010-02-09 23:11:45.1202\"]'",
+            ),
+            (None, "NULL"),
+        ],
+    )
+    .await;
+}
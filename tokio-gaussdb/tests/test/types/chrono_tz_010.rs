@@ -0,0 +1,39 @@
+use chrono_tz_010::Tz;
+
+use crate::connect;
+use crate::types::test_type;
+
+#[tokio::test]
+async fn test_tz_params() {
+    test_type(
+        "TEXT",
+        &[
+            (Some(Tz::UTC), "'UTC'"),
+            (Some(Tz::Europe__Berlin), "'Europe/Berlin'"),
+            (
+                Some(Tz::America__Argentina__Buenos_Aires),
+                "'America/Argentina/Buenos_Aires'",
+            ),
+            (None, "NULL"),
+        ],
+    )
+    .await
+}
+
+#[tokio::test]
+async fn test_tz_server_names() {
+    let client = connect("user=postgres").await;
+
+    client
+        .batch_execute("SET TIME ZONE 'Asia/Tokyo'")
+        .await
+        .unwrap();
+    let row = client.query_one("SHOW TIME ZONE", &[]).await.unwrap();
+    assert_eq!(row.get::<_, Tz>(0), Tz::Asia__Tokyo);
+
+    let row = client
+        .query_one("SELECT 'Mars/Olympus_Mons'::TEXT", &[])
+        .await
+        .unwrap();
+    assert!(row.try_get::<_, Tz>(0).is_err());
+}
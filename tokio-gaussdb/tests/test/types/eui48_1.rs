@@ -16,3 +16,18 @@ async fn test_eui48_params() {
     )
     .await
 }
+
+#[tokio::test]
+async fn test_eui48_macaddr8_params() {
+    test_type(
+        "MACADDR8",
+        &[
+            (
+                Some(MacAddress::parse_str("12-34-56-AB-CD-EF").unwrap()),
+                "'12:34:56:ff:fe:ab:cd:ef'",
+            ),
+            (None, "NULL"),
+        ],
+    )
+    .await
+}
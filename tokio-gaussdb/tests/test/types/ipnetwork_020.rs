@@ -0,0 +1,37 @@
+use ipnetwork_020::IpNetwork;
+
+use crate::types::test_type;
+
+#[tokio::test]
+async fn test_ipnetwork_inet_params() {
+    let net = |s: &str| Some(s.parse::<IpNetwork>().unwrap());
+    test_type(
+        "INET",
+        &[
+            (net("127.0.0.1/32"), "'127.0.0.1'"),
+            (net("10.1.2.3/16"), "'10.1.2.3/16'"),
+            (net("2001:4f8:3:ba::/64"), "'2001:4f8:3:ba::/64'"),
+            (
+                net("2001:4f8:3:ba:2e0:81ff:fe22:d1f1/128"),
+                "'2001:4f8:3:ba:2e0:81ff:fe22:d1f1'",
+            ),
+            (None, "NULL"),
+        ],
+    )
+    .await
+}
+
+#[tokio::test]
+async fn test_ipnetwork_cidr_params() {
+    let net = |s: &str| Some(s.parse::<IpNetwork>().unwrap());
+    test_type(
+        "CIDR",
+        &[
+            (net("10.1.0.0/16"), "'10.1.0.0/16'"),
+            (net("192.168.0.1/32"), "'192.168.0.1/32'"),
+            (net("2001:4f8:3:ba::/64"), "'2001:4f8:3:ba::/64'"),
+            (None, "NULL"),
+        ],
+    )
+    .await
+}
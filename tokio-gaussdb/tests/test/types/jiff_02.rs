@@ -1,10 +1,11 @@
 use jiff_02::{
     civil::{Date as JiffDate, DateTime, Time},
-    Timestamp as JiffTimestamp,
+    Span, Timestamp as JiffTimestamp,
 };
+use std::convert::TryFrom;
 use std::fmt;
 use tokio_gaussdb::{
-    types::{Date, FromSqlOwned, Timestamp},
+    types::{Date, FromSqlOwned, Interval, Timestamp},
     Client,
 };
 
@@ -173,3 +174,32 @@ async fn test_special_params_without_wrapper() {
     assert_overflows::<JiffDate>(&mut client, "'-infinity'", "date").await;
     assert_overflows::<JiffDate>(&mut client, "'infinity'", "date").await;
 }
+
+#[tokio::test]
+async fn test_interval_span() {
+    let client = connect("user=postgres").await;
+
+    let interval = client
+        .query_one(
+            "SELECT INTERVAL '1 year 2 mons 3 days 04:05:06.000007'",
+            &[],
+        )
+        .await
+        .unwrap()
+        .get::<_, Interval>(0);
+    let span = Span::try_from(interval).unwrap();
+    assert_eq!(span.get_months(), 14);
+    assert_eq!(span.get_days(), 3);
+    assert_eq!(span.get_microseconds(), 14_706_000_007);
+
+    let span = Span::new().years(-1).weeks(-1).hours(-1).nanoseconds(-1500);
+    let interval = Interval::try_from(span).unwrap();
+    assert_eq!(interval, Interval::new(-12, -7, -3_600_000_001));
+    let row = client
+        .query_one("SELECT $1::INTERVAL::TEXT", &[&interval])
+        .await
+        .unwrap();
+    assert_eq!(row.get::<_, &str>(0), "-1 years -7 days -01:00:00.000001");
+
+    assert!(Span::try_from(Interval::new(1, -1, 0)).is_err());
+}
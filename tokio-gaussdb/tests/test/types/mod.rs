@@ -1,32 +1,51 @@
 use gaussdb_types::to_sql_checked;
 use std::collections::HashMap;
+use std::convert::TryFrom;
 use std::error::Error;
 use std::f32;
 use std::f64;
 use std::fmt;
 use std::net::IpAddr;
+use std::ops::Bound;
 use std::result;
 use std::str::FromStr;
 use std::time::{Duration, UNIX_EPOCH};
-use tokio_gaussdb::types::{FromSql, FromSqlOwned, IsNull, Kind, PgLsn, ToSql, Type, WrongType};
+use tokio_gaussdb::types::{
+    Array, Circle, Dimension, FromSql, FromSqlOwned, FromSqlText, FromSqlTextOwned, Interval,
+    IsNull, Kind, Line, Lseg, Money, Numeric, PgLsn, Point, Polygon, Range, Record, TinyInt, ToSql,
+    TsLexeme, TsPosition, TsQuery, TsVector, TsWeight, Type, Value, WrongType,
+};
 
 use crate::connect;
 use bytes::BytesMut;
 
+#[cfg(feature = "with-bigdecimal-0_4")]
+mod bigdecimal_04;
 #[cfg(feature = "with-bit-vec-0_6")]
 mod bit_vec_06;
+mod bytes_1;
 #[cfg(feature = "with-chrono-0_4")]
 mod chrono_04;
+#[cfg(feature = "with-chrono-tz-0_10")]
+mod chrono_tz_010;
 #[cfg(feature = "with-eui48-1")]
 mod eui48_1;
 #[cfg(feature = "with-geo-types-0_6")]
 mod geo_types_06;
 #[cfg(feature = "with-geo-types-0_7")]
 mod geo_types_07;
+#[cfg(feature = "with-ipnetwork-0_20")]
+mod ipnetwork_020;
 #[cfg(feature = "with-jiff-0_1")]
 mod jiff_01;
 #[cfg(feature = "with-jiff-0_2")]
 mod jiff_02;
+#[cfg(feature = "with-ndarray-0_16")]
+mod ndarray_016;
+#[cfg(feature = "with-num-bigint-0_4")]
+mod num_bigint_04;
+#[cfg(feature = "with-rust_decimal-1")]
+mod rust_decimal_1;
 #[cfg(feature = "with-serde_json-1")]
 mod serde_json_1;
 #[cfg(feature = "with-smol_str-01")]
@@ -42,7 +61,7 @@ mod uuid_1;
 
 async fn test_type<T, S>(sql_type: &str, checks: &[(T, S)])
 where
-    T: PartialEq + for<'a> FromSqlOwned + ToSql + Sync,
+    T: PartialEq + for<'a> FromSqlOwned + FromSqlTextOwned + ToSql + Sync,
     S: fmt::Display,
 {
     let client = connect("user=postgres").await;
@@ -61,6 +80,13 @@ where
             .unwrap();
         let result = rows[0].get(0);
         assert_eq!(val, &result);
+
+        let rows = client
+            .query_text(&*format!("SELECT $1::{}", sql_type), &[&val])
+            .await
+            .unwrap();
+        let result = rows[0].get_text(0);
+        assert_eq!(val, &result);
     }
 }
 
@@ -159,6 +185,140 @@ async fn test_lsn_params() {
     .await
 }
 
+#[tokio::test]
+async fn test_numeric_params() {
+    let num = |s: &str| Some(Numeric::from_str(s).unwrap());
+    test_type(
+        "NUMERIC",
+        &[
+            (num("0"), "0"),
+            (num("1.50"), "1.50"),
+            (num("-12345678.0001"), "-12345678.0001"),
+            (num("0.000000012"), "0.000000012"),
+            (
+                num("123456789012345678901234567890.123456789012345678901234567890"),
+                "123456789012345678901234567890.123456789012345678901234567890",
+            ),
+            (num("1e20"), "1e20"),
+            (Some(Numeric::nan()), "'NaN'"),
+            (Some(Numeric::infinity()), "'Infinity'"),
+            (Some(Numeric::neg_infinity()), "'-Infinity'"),
+            (None, "NULL"),
+        ],
+    )
+    .await
+}
+
+#[tokio::test]
+async fn numeric_scale() {
+    let client = connect("user=postgres").await;
+
+    let row = client
+        .query_one(
+            "SELECT 1.500::NUMERIC, 0.0::NUMERIC, $1::NUMERIC(10, 4)",
+            &[&Numeric::from_str("-2.5").unwrap()],
+        )
+        .await
+        .unwrap();
+    assert_eq!(row.get::<_, Numeric>(0).to_string(), "1.500");
+    assert_eq!(row.get::<_, Numeric>(1).to_string(), "0.0");
+    assert_eq!(row.get::<_, Numeric>(2).to_string(), "-2.5000");
+    assert_eq!(row.get::<_, Numeric>(2).scale(), 4);
+}
+
+#[tokio::test]
+async fn test_interval_params() {
+    test_type(
+        "INTERVAL",
+        &[
+            (Some(Interval::ZERO), "'0'"),
+            (
+                Some(Interval::new(14, 3, 14_706_500_000)),
+                "'1 year 2 mons 3 days 04:05:06.5'",
+            ),
+            (
+                Some(Interval::new(-1, 2, -1)),
+                "'-1 mons +2 days -00:00:00.000001'",
+            ),
+            (Some(Interval::new(0, -7, 0)), "'-1 week'"),
+            (Some(Interval::new(0, 0, 3_600_000_000_000)), "'1000 hours'"),
+            (
+                Some(Interval::new(i32::MAX, i32::MIN, i64::MAX)),
+                "'178956970 years 7 mons -2147483648 days 2562047788:00:54.775807'",
+            ),
+            (None, "NULL"),
+        ],
+    )
+    .await
+}
+
+#[tokio::test]
+async fn interval_styles() {
+    let client = connect("user=postgres").await;
+
+    let intervals = [
+        Interval::ZERO,
+        Interval::new(14, 3, 14_706_500_000),
+        Interval::new(-14, -3, -14_706_500_000),
+        Interval::new(-1, 2, -1),
+        Interval::new(0, 0, 59_000_001),
+    ];
+    for style in ["postgres", "postgres_verbose", "iso_8601"] {
+        client
+            .batch_execute(&format!("SET IntervalStyle = {}", style))
+            .await
+            .unwrap();
+        for interval in &intervals {
+            let row = client
+                .query_text("SELECT $1::INTERVAL::TEXT", &[interval])
+                .await
+                .unwrap();
+            let text = row[0].get_text::<_, String>(0);
+            assert_eq!(text.parse::<Interval>().unwrap(), *interval, "{}", text);
+            match style {
+                "postgres" => assert_eq!(text, interval.to_string()),
+                "iso_8601" => assert_eq!(text, interval.to_iso8601()),
+                _ => {}
+            }
+        }
+    }
+
+    assert_eq!(
+        "P1Y2M3W4DT5H6M7.5S".parse::<Interval>().unwrap(),
+        Interval::new(14, 25, 18_367_500_000)
+    );
+    assert_eq!(
+        "1 day 2 hours ago".parse::<Interval>().unwrap(),
+        Interval::new(0, -1, -7_200_000_000)
+    );
+    assert!("1.5 days".parse::<Interval>().is_err());
+    assert!("P".parse::<Interval>().is_err());
+    assert!("".parse::<Interval>().is_err());
+}
+
+#[test]
+fn interval_conversions() {
+    let interval = Interval::new(0, 1, 1_500_000);
+    assert_eq!(
+        Duration::try_from(interval).unwrap(),
+        Duration::from_micros(86_401_500_000)
+    );
+    assert!(Duration::try_from(Interval::new(1, 0, 0)).is_err());
+    assert!(Duration::try_from(Interval::new(0, -1, 0)).is_err());
+    assert_eq!(
+        Interval::try_from(Duration::new(1, 500_999)).unwrap(),
+        Interval::new(0, 0, 1_000_500)
+    );
+
+    assert_eq!(interval + interval, Interval::new(0, 2, 3_000_000));
+    assert_eq!(-interval, Interval::new(0, -1, -1_500_000));
+    assert_eq!(
+        interval.checked_mul(3),
+        Some(Interval::new(0, 3, 4_500_000))
+    );
+    assert_eq!(Interval::new(0, 2, 0).checked_mul(i32::MAX), None);
+}
+
 #[tokio::test]
 async fn test_f32_params() {
     test_type(
@@ -386,6 +546,138 @@ async fn test_array_array_params() {
     .await;
 }
 
+#[tokio::test]
+async fn test_multidimensional_array_params() {
+    let array = |elements: Vec<f64>, dimensions: &[(i32, i32)]| {
+        let dimensions = dimensions
+            .iter()
+            .map(|&(len, lower_bound)| Dimension::new(len, lower_bound))
+            .collect();
+        Some(Array::from_parts(elements, dimensions).unwrap())
+    };
+    test_type(
+        "float8[]",
+        &[
+            (
+                array(vec![1., 2., 3., 4., 5., 6.], &[(2, 1), (3, 1)]),
+                "'{{1,2,3},{4,5,6}}'",
+            ),
+            (
+                array(vec![1.5, 2., 3., 4.], &[(2, 0), (2, -1)]),
+                "'[0:1][-1:0]={{1.5,2},{3,4}}'",
+            ),
+            (
+                array((1..=8).map(f64::from).collect(), &[(2, 1), (2, 1), (2, 1)]),
+                "'{{{1,2},{3,4}},{{5,6},{7,8}}}'",
+            ),
+            (array(vec![1.], &[(1, 3)]), "'[3:3]={1}'"),
+            (array(vec![], &[]), "'{}'"),
+            (None, "NULL"),
+        ],
+    )
+    .await;
+
+    let text = |s: &str| Some(s.to_string());
+    test_type(
+        "text[]",
+        &[
+            (
+                Some(
+                    Array::from_parts(
+                        vec![text("a,b"), None, text("\\\"}"), text("NULL")],
+                        vec![Dimension::new(2, 1), Dimension::new(2, 1)],
+                    )
+                    .unwrap(),
+                ),
+                r#"'{{"a,b",NULL},{"\\\"}","NULL"}}'"#,
+            ),
+            (None, "NULL"),
+        ],
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_multidimensional_array_access() {
+    let client = connect("user=postgres").await;
+
+    let row = client
+        .query_one("SELECT '[0:1][2:4]={{1,2,3},{4,5,6}}'::INT4[]", &[])
+        .await
+        .unwrap();
+    let array = row.get::<_, Array<i32>>(0);
+    assert_eq!(
+        array.dimensions(),
+        &[Dimension::new(2, 0), Dimension::new(3, 2)]
+    );
+    assert_eq!(array.get(&[0, 2]), Some(&1));
+    assert_eq!(array.get(&[1, 3]), Some(&5));
+    assert_eq!(array.get(&[1, 5]), None);
+    assert_eq!(array.get(&[1]), None);
+    assert_eq!(array.into_inner(), vec![1, 2, 3, 4, 5, 6]);
+
+    // one-dimensional types reject arrays with more dimensions
+    let row = client
+        .query_one("SELECT '{{1,2},{3,4}}'::INT4[]", &[])
+        .await
+        .unwrap();
+    assert!(row.try_get::<_, Vec<i32>>(0).is_err());
+}
+
+#[tokio::test]
+async fn test_multidimensional_array_nulls() {
+    let client = connect("user=postgres").await;
+
+    let query = "SELECT '{{1,NULL},{3,4}}'::INT4[]";
+    let row = client.query_one(query, &[]).await.unwrap();
+    assert_eq!(
+        row.get::<_, Array<Option<i32>>>(0).into_inner(),
+        vec![Some(1), None, Some(3), Some(4)]
+    );
+    assert!(row.try_get::<_, Array<i32>>(0).is_err());
+
+    let rows = client.query_text(query, &[]).await.unwrap();
+    assert_eq!(
+        rows[0].get_text::<_, Array<Option<i32>>>(0).into_inner(),
+        vec![Some(1), None, Some(3), Some(4)]
+    );
+    assert!(rows[0].try_get_text::<_, Array<i32>>(0).is_err());
+}
+
+#[test]
+fn test_multidimensional_array_shape() {
+    assert!(Array::from_parts(
+        vec![1, 2, 3],
+        vec![Dimension::new(2, 1), Dimension::new(2, 1)]
+    )
+    .is_err());
+    assert!(Array::from_parts(vec![1], vec![]).is_err());
+    assert!(
+        Array::from_parts(Vec::<i32>::new(), vec![Dimension::new(0, 1)])
+            .unwrap()
+            .dimensions()
+            .is_empty()
+    );
+    assert_eq!(Array::from_vec(vec![1, 2], 0).get(&[1]), Some(&2));
+
+    for raw in [
+        "{{1,2},{3}}",
+        "{{1,2},3}",
+        "{1,{2,3}}",
+        "{{1},{2,3}}",
+        "[1:3]={1,2}",
+        "{1,2",
+    ] {
+        assert!(Array::<i32>::from_sql_text(raw).is_err(), "{}", raw);
+    }
+    assert_eq!(
+        Array::<i32>::from_sql_text("{{1,2},{3,4}}")
+            .unwrap()
+            .dimensions(),
+        &[Dimension::new(2, 1), Dimension::new(2, 1)]
+    );
+}
+
 #[allow(clippy::eq_op)]
 async fn test_nan_param<T>(sql_type: &str)
 where
@@ -495,6 +787,348 @@ async fn test_slice_range() {
     };
 }
 
+#[tokio::test]
+async fn test_int4range_params() {
+    test_type(
+        "INT4RANGE",
+        &[
+            (Some(Range::from(1..10)), "'[1,10)'"),
+            (Some(Range::from(-5..)), "'[-5,)'"),
+            (Some(Range::from(..5)), "'(,5)'"),
+            (Some(Range::from(..)), "'(,)'"),
+            (Some(Range::Empty), "'empty'"),
+            (None, "NULL"),
+        ],
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_numrange_params() {
+    let num = |s: &str| Numeric::from_str(s).unwrap();
+    test_type(
+        "NUMRANGE",
+        &[
+            (
+                Some(Range::new(
+                    Bound::Excluded(num("1.5")),
+                    Bound::Included(num("2.25")),
+                )),
+                "'(1.5,2.25]'",
+            ),
+            (
+                Some(Range::new(Bound::Unbounded, Bound::Included(num("0")))),
+                "'(,0]'",
+            ),
+            (None, "NULL"),
+        ],
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_int8range_array_params() {
+    test_type(
+        "INT8RANGE[]",
+        &[
+            (
+                Some(vec![Range::from(1i64..3), Range::Empty, Range::from(10..)]),
+                "'{\"[1,3)\",empty,\"[10,)\"}'",
+            ),
+            (Some(vec![]), "'{}'"),
+            (None, "NULL"),
+        ],
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn custom_range() {
+    let client = connect("user=postgres").await;
+
+    client
+        .batch_execute("CREATE TYPE pg_temp.textrange AS RANGE (subtype = text, collation = \"C\")")
+        .await
+        .unwrap();
+
+    let range = Range::new(
+        Bound::Included("a \"b\"".to_string()),
+        Bound::Excluded("c,d\\".to_string()),
+    );
+    let row = client
+        .query_one("SELECT $1::pg_temp.textrange", &[&range])
+        .await
+        .unwrap();
+    assert_eq!(row.get::<_, Range<String>>(0), range);
+
+    let rows = client
+        .query_text("SELECT $1::pg_temp.textrange", &[&range])
+        .await
+        .unwrap();
+    assert_eq!(rows[0].get_text::<_, Range<String>>(0), range);
+}
+
+#[test]
+fn range_helpers() {
+    let range = Range::from(1..10);
+    assert!(range.contains(&1));
+    assert!(range.contains(&9));
+    assert!(!range.contains(&10));
+    assert!(!Range::Empty.contains(&1));
+    assert!(Range::from(..=10).contains(&10));
+
+    assert!(range.contains_range(&Range::from(2..10)));
+    assert!(range.contains_range(&Range::Empty));
+    assert!(!range.contains_range(&Range::from(0..5)));
+    assert!(!range.contains_range(&Range::from(5..)));
+    assert!(Range::from(..).contains_range(&range));
+    assert!(!Range::new(Bound::Excluded(1), Bound::Unbounded).contains_range(&range));
+
+    assert!(range.overlaps(&Range::from(9..20)));
+    assert!(!range.overlaps(&Range::from(10..20)));
+    assert!(!range.overlaps(&Range::Empty));
+    assert!(Range::from(1..=10).overlaps(&Range::from(10..20)));
+    assert!(Range::from(..0).overlaps(&Range::from(..)));
+
+    assert_eq!(range.to_string(), "[1,10)");
+    assert_eq!(Range::<i32>::Empty.to_string(), "empty");
+    assert_eq!(
+        Range::new(Bound::Excluded("a b"), Bound::Unbounded).to_string(),
+        "(\"a b\",)"
+    );
+}
+
+#[tokio::test]
+async fn test_lseg_params() {
+    test_type(
+        "LSEG",
+        &[
+            (
+                Some(Lseg::new(Point::new(1.5, 2.), Point::new(-3., 4.))),
+                "lseg '[(1.5, 2), (-3, 4)]'",
+            ),
+            (None, "NULL"),
+        ],
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_line_params() {
+    test_type(
+        "LINE",
+        &[
+            (Some(Line::new(1., -1., 0.5)), "line '{1, -1, 0.5}'"),
+            (None, "NULL"),
+        ],
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_polygon_params() {
+    let points = vec![
+        Point::new(0., 0.),
+        Point::new(-3.2, 1.618),
+        Point::new(160.0, 69701.5615),
+    ];
+    test_type(
+        "POLYGON",
+        &[
+            (
+                Some(Polygon::new(points)),
+                "polygon '((0, 0), (-3.2, 1.618), (160.0, 69701.5615))'",
+            ),
+            (None, "NULL"),
+        ],
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_circle_params() {
+    test_type(
+        "CIRCLE",
+        &[
+            (
+                Some(Circle::new(Point::new(1., -2.5), 3.)),
+                "circle '<(1, -2.5), 3>'",
+            ),
+            (None, "NULL"),
+        ],
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_tsvector_params() {
+    test_type(
+        "TSVECTOR",
+        &[
+            (
+                Some(TsVector::new(vec![
+                    TsLexeme::new("a", vec![TsPosition::new(1, TsWeight::A)]),
+                    TsLexeme::new("cat", vec![TsPosition::new(5, TsWeight::D)]),
+                    TsLexeme::new(
+                        "fat",
+                        vec![
+                            TsPosition::new(2, TsWeight::B),
+                            TsPosition::new(4, TsWeight::C),
+                        ],
+                    ),
+                ])),
+                "'a:1A fat:2B,4C cat:5D'",
+            ),
+            (
+                Some(TsVector::new(vec![
+                    TsLexeme::new("back\\slash", vec![TsPosition::new(3, TsWeight::A)]),
+                    TsLexeme::new("it's", vec![]),
+                ])),
+                "$$'it''s' 'back\\\\slash':3A$$",
+            ),
+            (Some(TsVector::default()), "''"),
+            (None, "NULL"),
+        ],
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_tsquery_params() {
+    let queries = [
+        "'fat':*A & !( 'rat' | 'cat' ) <2> 'dog'",
+        "'a' | 'b' & 'c':BD",
+        "'a' <-> ( 'b' <-> 'c' )",
+        "'it''s' & 'back\\\\slash'",
+    ];
+
+    let client = connect("user=postgres").await;
+    for query in queries {
+        let value = query.parse::<TsQuery>().unwrap();
+        let row = client
+            .query_one(&*format!("SELECT $${}$$::TSQUERY::TEXT", query), &[])
+            .await
+            .unwrap();
+        assert_eq!(value.to_string(), row.get::<_, &str>(0));
+    }
+
+    assert_eq!(
+        queries[1].parse::<TsQuery>().unwrap(),
+        TsQuery::Or(
+            Box::new(TsQuery::lexeme("a")),
+            Box::new(TsQuery::And(
+                Box::new(TsQuery::lexeme("b")),
+                Box::new(TsQuery::Lexeme {
+                    lexeme: "c".to_string(),
+                    weights: vec![TsWeight::B, TsWeight::D],
+                    prefix: false,
+                }),
+            )),
+        )
+    );
+
+    let row = client.query_one("SELECT ''::TSQUERY", &[]).await.unwrap();
+    assert_eq!(row.get::<_, TsQuery>(0), TsQuery::Empty);
+
+    let mut checks = queries
+        .iter()
+        .map(|q| (Some(q.parse::<TsQuery>().unwrap()), format!("$${}$$", q)))
+        .collect::<Vec<_>>();
+    checks.push((None, "NULL".to_string()));
+    test_type("TSQUERY", &checks).await;
+}
+
+#[tokio::test]
+async fn test_macaddr8_params() {
+    test_type(
+        "MACADDR8",
+        &[
+            (
+                Some([0x08u8, 0x00, 0x2b, 0x01, 0x02, 0x03, 0x04, 0x05]),
+                "'08:00:2b:01:02:03:04:05'",
+            ),
+            (Some([0xffu8; 8]), "'ff-ff-ff-ff-ff-ff-ff-ff'"),
+            (None, "NULL"),
+        ],
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_money_params() {
+    test_type(
+        "MONEY",
+        &[
+            (Some(Money(123_456)), "'1234.56'"),
+            (Some(Money(-5)), "'-0.05'"),
+            (Some(Money(0)), "'0'"),
+            (Some(Money(i64::MAX)), "'92233720368547758.07'"),
+            (Some(Money(i64::MIN)), "'-92233720368547758.08'"),
+            (None, "NULL"),
+        ],
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn year_domain() {
+    let client = connect("user=postgres").await;
+
+    client
+        .batch_execute("CREATE DOMAIN pg_temp.year AS SMALLINT")
+        .await
+        .unwrap();
+
+    // only openGauss's own `year` type is bound as `INT2`, not any type of that name
+    let stmt = client.prepare("SELECT $1::pg_temp.year").await.unwrap();
+    assert_eq!(stmt.params()[0].name(), "year");
+    assert!(client.query_one(&stmt, &[&2024i16]).await.is_err());
+}
+
+#[test]
+fn opengauss_types() {
+    assert_eq!(Type::from_oid(5545), Some(Type::INT1));
+    assert_eq!(Type::INT1_ARRAY.kind(), &Kind::Array(Type::INT1));
+    assert_eq!(Type::SMALLDATETIME.name(), "smalldatetime");
+
+    let mut buf = BytesMut::new();
+    TinyInt(200).to_sql(&Type::INT1, &mut buf).unwrap();
+    assert_eq!(&buf[..], &[200]);
+    assert_eq!(TinyInt::from_sql(&Type::INT1, &buf).unwrap(), TinyInt(200));
+
+    let mut buf = BytesMut::new();
+    i128::MIN.to_sql(&Type::INT16, &mut buf).unwrap();
+    assert_eq!(buf.len(), 16);
+    assert_eq!(i128::from_sql(&Type::INT16, &buf).unwrap(), i128::MIN);
+
+    assert!(<Vec<TinyInt> as ToSql>::accepts(&Type::INT1_ARRAY));
+    assert!(<&str as ToSql>::accepts(&Type::CLOB));
+    assert!(<String as FromSql>::accepts(&Type::NVARCHAR2));
+    assert!(<&[u8] as ToSql>::accepts(&Type::RAW));
+    assert!(<Vec<u8> as FromSql>::accepts(&Type::BLOB));
+    assert!(<std::time::SystemTime as FromSql>::accepts(
+        &Type::SMALLDATETIME
+    ));
+
+    let year = Type::new(
+        "year".to_string(),
+        16384,
+        Kind::Simple,
+        "pg_catalog".to_string(),
+    );
+    assert!(<i16 as FromSql>::accepts(&year));
+    assert!(<i16 as ToSql>::accepts(&year));
+
+    let year = Type::new(
+        "year".to_string(),
+        16384,
+        Kind::Simple,
+        "public".to_string(),
+    );
+    assert!(!<i16 as FromSql>::accepts(&year));
+    assert!(!<i16 as ToSql>::accepts(&year));
+}
+
 #[tokio::test]
 async fn domain() {
     #[derive(Debug, PartialEq)]
@@ -587,6 +1221,82 @@ async fn composite() {
     }
 }
 
+#[tokio::test]
+async fn record() {
+    let client = connect("user=postgres").await;
+
+    let row = client
+        .query_one("SELECT ROW(1, 'foo'::TEXT, NULL::INT4, ARRAY[1, 2])", &[])
+        .await
+        .unwrap();
+    let record = row.get::<_, Record>(0);
+    assert_eq!(record.len(), 4);
+    assert_eq!(
+        record.types(),
+        &[Type::INT4, Type::TEXT, Type::INT4, Type::INT4_ARRAY]
+    );
+    assert_eq!(record.get::<i32>(0), 1);
+    assert_eq!(record.get::<&str>(1), "foo");
+    assert_eq!(record.get::<Option<i32>>(2), None);
+    assert_eq!(record.get::<Vec<i32>>(3), vec![1, 2]);
+    assert_eq!(record.get::<Value>(1), Value::Text("foo".to_string()));
+    assert!(record.try_get::<String>(0).is_err());
+    assert!(record.try_get::<i32>(4).is_err());
+
+    let row = client
+        .query_one("SELECT ROW(1, 'foo'::TEXT, NULL::INT4)", &[])
+        .await
+        .unwrap();
+    let tuple = row.get::<_, (i32, String, Option<i32>)>(0);
+    assert_eq!(tuple, (1, "foo".to_string(), None));
+    assert!(row.try_get::<_, (i32, String)>(0).is_err());
+    assert!(row.try_get::<_, (i32, i32, Option<i32>)>(0).is_err());
+
+    client
+        .batch_execute(
+            "CREATE FUNCTION pg_temp.pair(a INT) RETURNS RECORD AS $$
+                SELECT a, a * 2
+            $$ LANGUAGE SQL",
+        )
+        .await
+        .unwrap();
+    let row = client
+        .query_one("SELECT pg_temp.pair(21)", &[])
+        .await
+        .unwrap();
+    assert_eq!(row.get::<_, (i32, i32)>(0), (21, 42));
+
+    let row = client
+        .query_one("SELECT ARRAY[ROW(1, 'a'::TEXT), ROW(2, NULL)]", &[])
+        .await
+        .unwrap();
+    assert_eq!(
+        row.get::<_, Vec<(i32, Option<&str>)>>(0),
+        [(1, Some("a")), (2, None)]
+    );
+}
+
+#[tokio::test]
+async fn composite_record() {
+    let client = connect("user=postgres").await;
+
+    client
+        .batch_execute("CREATE TYPE pg_temp.record_pair AS (id INTEGER, name TEXT)")
+        .await
+        .unwrap();
+
+    let row = client
+        .query_one("SELECT ROW(1, 'foo')::record_pair", &[])
+        .await
+        .unwrap();
+    assert_eq!(row.get::<_, (i32, String)>(0), (1, "foo".to_string()));
+    assert!(row.try_get::<_, (i32, i32)>(0).is_err());
+
+    let record = row.get::<_, Record>(0);
+    assert_eq!(record.types(), &[Type::INT4, Type::TEXT]);
+    assert_eq!(record.get::<&str>(1), "foo");
+}
+
 #[tokio::test]
 async fn enum_() {
     let client = connect("user=postgres").await;
@@ -802,3 +1512,161 @@ async fn int2vector() {
     )
     .await;
 }
+
+#[tokio::test]
+async fn value() {
+    let client = connect("user=postgres").await;
+
+    client
+        .batch_execute(
+            "SET TIME ZONE 'UTC';
+            CREATE TYPE pg_temp.value_mood AS ENUM ('happy');
+            CREATE TYPE pg_temp.value_pair AS (name TEXT, num INT4)",
+        )
+        .await
+        .unwrap();
+
+    let exprs = [
+        "true",
+        "'a'::\"char\"",
+        "-12::INT2",
+        "123456::INT4",
+        "-9876543210::INT8",
+        "26::OID",
+        "1.5::FLOAT4",
+        "-0.25::FLOAT8",
+        "'NaN'::FLOAT8",
+        "12345.6789::NUMERIC",
+        "-0.000120::NUMERIC",
+        "100000000::NUMERIC",
+        "'NaN'::NUMERIC",
+        "'hello world'::TEXT",
+        "'x'::VARCHAR",
+        "'ab'::CHAR(4)",
+        "'\\x00ff10'::BYTEA",
+        "'2020-02-29'::DATE",
+        "'0044-03-15 BC'::DATE",
+        "'infinity'::DATE",
+        "'13:45:07.0125'::TIME",
+        "'1999-12-31 23:59:59.5'::TIMESTAMP",
+        "'0001-01-01 00:00:00 BC'::TIMESTAMP",
+        "'2010-06-01 12:00:00+02'::TIMESTAMPTZ",
+        "'-infinity'::TIMESTAMPTZ",
+        "'1 year 2 mons 3 days 04:05:06.7'::INTERVAL",
+        "'-1 days +02:00:00'::INTERVAL",
+        "'-14 mons -00:00:01'::INTERVAL",
+        "'0'::INTERVAL",
+        "'{\"a\": [1, 2]}'::JSON",
+        "'{\"a\": [1, 2]}'::JSONB",
+        "'a0eebc99-9c0b-4ef8-bb6d-6bb9bd380a11'::UUID",
+        "'10.1.2.3'::INET",
+        "'10.1.2.3/16'::INET",
+        "'10.1.0.0/16'::CIDR",
+        "'::1'::INET",
+        "'08:00:2b:01:02:03'::MACADDR",
+        "ARRAY[1, NULL, 3]",
+        "ARRAY[['a b', 'NULL'], ['\"q\"', '']]",
+        "'{}'::INT4[]",
+        "'[1,10)'::INT4RANGE",
+        "'(,5]'::NUMRANGE",
+        "'empty'::INT4RANGE",
+        "'[2000-01-01 00:00:00,2000-01-02 00:00:00)'::TSRANGE",
+        "'happy'::pg_temp.value_mood",
+        "ROW('a \"b\"', NULL)::pg_temp.value_pair",
+        "ROW(1, 'x', NULL)",
+        "ARRAY[ROW(1, 'x'), ROW(2, NULL)]",
+        "'(1,2)'::POINT",
+    ];
+
+    for expr in &exprs {
+        let query = format!("SELECT {}", expr);
+        let value = client
+            .query_one(&*query, &[])
+            .await
+            .unwrap()
+            .get::<_, Value>(0);
+        if let Value::Unknown { .. } = value {
+            continue;
+        }
+        let rows = client.query_text(&*query, &[]).await.unwrap();
+        assert_eq!(
+            value.to_string(),
+            rows[0].get_text::<_, &str>(0),
+            "{}",
+            expr
+        );
+    }
+
+    let row = client
+        .query_one(
+            "SELECT 1::INT4, NULL::TEXT, ARRAY[[1, 2], [3, 4]], '(1,2)'::POINT, \
+             ROW('x', 2)::pg_temp.value_pair, '[1,3)'::INT4RANGE",
+            &[],
+        )
+        .await
+        .unwrap();
+    let values = row.values().collect::<Result<Vec<_>, _>>().unwrap();
+    assert_eq!(values[0], Value::Int4(1));
+    assert_eq!(values[1], Value::Null);
+    assert_eq!(
+        values[2],
+        Value::Array(vec![
+            Value::Array(vec![Value::Int4(1), Value::Int4(2)]),
+            Value::Array(vec![Value::Int4(3), Value::Int4(4)]),
+        ])
+    );
+    match &values[3] {
+        Value::Unknown { type_, raw } => {
+            assert_eq!(type_, &Type::POINT);
+            assert_eq!(raw.len(), 16);
+        }
+        value => panic!("unexpected value {:?}", value),
+    }
+    assert_eq!(
+        values[4],
+        Value::Composite(vec![
+            ("name".to_string(), Value::Text("x".to_string())),
+            ("num".to_string(), Value::Int4(2)),
+        ])
+    );
+    assert_eq!(
+        values[5],
+        Value::Range {
+            lower: Bound::Included(Box::new(Value::Int4(1))),
+            upper: Bound::Excluded(Box::new(Value::Int4(3))),
+        }
+    );
+
+    let rows = client
+        .query_text("SELECT 1::INT4, NULL::TEXT", &[])
+        .await
+        .unwrap();
+    let values = rows[0].values().collect::<Result<Vec<_>, _>>().unwrap();
+    assert_eq!(values, [Value::Text("1".to_string()), Value::Null]);
+}
+
+#[cfg(feature = "serde")]
+#[tokio::test]
+async fn value_serialize() {
+    let client = connect("user=postgres").await;
+
+    let row = client
+        .query_one(
+            "SELECT ROW(1, 'a', NULL, ARRAY[1.5::FLOAT8], '2000-01-02'::DATE, 1.50::NUMERIC)",
+            &[],
+        )
+        .await
+        .unwrap();
+    let value = ::serde_json_1::to_value(row.get::<_, Value>(0)).unwrap();
+    assert_eq!(
+        value,
+        ::serde_json_1::json!({
+            "f1": 1,
+            "f2": "a",
+            "f3": null,
+            "f4": [1.5],
+            "f5": "2000-01-02",
+            "f6": "1.50",
+        })
+    );
+}
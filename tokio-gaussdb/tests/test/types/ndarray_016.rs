@@ -0,0 +1,69 @@
+use ndarray_016::{array, ArrayD, IxDyn};
+
+use crate::connect;
+use crate::types::test_type;
+
+#[tokio::test]
+async fn test_ndarray_params() {
+    test_type(
+        "float8[]",
+        &[
+            (Some(array![1., 2., 3.].into_dyn()), "'{1,2,3}'"),
+            (
+                Some(array![[1., 2., 3.], [4., 5., 6.]].into_dyn()),
+                "'{{1,2,3},{4,5,6}}'",
+            ),
+            (
+                Some(array![[[1., 2.], [3., 4.]], [[5., 6.], [7., 8.]]].into_dyn()),
+                "'{{{1,2},{3,4}},{{5,6},{7,8}}}'",
+            ),
+            (Some(ArrayD::<f64>::zeros(IxDyn(&[0]))), "'{}'"),
+            (None, "NULL"),
+        ],
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_ndarray_layout() {
+    let client = connect("user=postgres").await;
+
+    // elements are sent in logical order regardless of the memory layout
+    let transposed = array![[1, 2, 3], [4, 5, 6]].reversed_axes().into_dyn();
+    let row = client
+        .query_one("SELECT $1::INT4[]::TEXT", &[&transposed])
+        .await
+        .unwrap();
+    assert_eq!(row.get::<_, &str>(0), "{{1,4},{2,5},{3,6}}");
+
+    // lower bounds are dropped
+    let row = client
+        .query_one("SELECT '[0:1][2:3]={{1,2},{3,4}}'::INT4[]", &[])
+        .await
+        .unwrap();
+    assert_eq!(
+        row.get::<_, ArrayD<i32>>(0),
+        array![[1, 2], [3, 4]].into_dyn()
+    );
+}
+
+#[tokio::test]
+async fn test_ndarray_errors() {
+    let client = connect("user=postgres").await;
+
+    let row = client
+        .query_one("SELECT '{{1,NULL},{3,4}}'::INT4[]", &[])
+        .await
+        .unwrap();
+    assert!(row.try_get::<_, ArrayD<i32>>(0).is_err());
+    assert_eq!(
+        row.get::<_, ArrayD<Option<i32>>>(0),
+        array![[Some(1), None], [Some(3), Some(4)]].into_dyn()
+    );
+
+    let scalar = ArrayD::from_elem(IxDyn(&[]), 1);
+    assert!(client
+        .query_one("SELECT $1::INT4[]", &[&scalar])
+        .await
+        .is_err());
+}
@@ -0,0 +1,43 @@
+use num_bigint_04::BigInt;
+
+use crate::connect;
+use crate::types::test_type;
+
+#[tokio::test]
+async fn test_bigint_params() {
+    let int = |s: &str| Some(s.parse::<BigInt>().unwrap());
+    test_type(
+        "NUMERIC",
+        &[
+            (int("0"), "0"),
+            (int("-1"), "-1"),
+            (int("10000"), "10000"),
+            (
+                int("-123456789012345678901234567890123456789"),
+                "-123456789012345678901234567890123456789",
+            ),
+            (int("1000000000000000000000000000000"), "1e30"),
+            (None, "NULL"),
+        ],
+    )
+    .await
+}
+
+#[tokio::test]
+async fn test_bigint_non_integers() {
+    let client = connect("user=postgres").await;
+
+    let row = client
+        .query_one("SELECT 12.000::NUMERIC", &[])
+        .await
+        .unwrap();
+    assert_eq!(row.get::<_, BigInt>(0), BigInt::from(12));
+
+    for value in ["1.5", "'NaN'", "'Infinity'"] {
+        let row = client
+            .query_one(&*format!("SELECT {}::NUMERIC", value), &[])
+            .await
+            .unwrap();
+        assert!(row.try_get::<_, BigInt>(0).is_err(), "{}", value);
+    }
+}
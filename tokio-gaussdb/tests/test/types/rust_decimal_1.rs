@@ -0,0 +1,46 @@
+use rust_decimal_1::Decimal;
+use std::str::FromStr;
+
+use crate::connect;
+use crate::types::test_type;
+
+#[tokio::test]
+async fn test_decimal_params() {
+    let dec = |s: &str| Some(Decimal::from_str(s).unwrap());
+    test_type(
+        "NUMERIC",
+        &[
+            (dec("0"), "0"),
+            (dec("1.50"), "1.50"),
+            (dec("-12345678.0001"), "-12345678.0001"),
+            (
+                dec("0.0000000000000000000000000001"),
+                "0.0000000000000000000000000001",
+            ),
+            (
+                dec("79228162514264337593543950335"),
+                "79228162514264337593543950335",
+            ),
+            (None, "NULL"),
+        ],
+    )
+    .await
+}
+
+#[tokio::test]
+async fn test_decimal_out_of_range() {
+    let client = connect("user=postgres").await;
+
+    for value in [
+        "'NaN'",
+        "'Infinity'",
+        "1e30",
+        "0.00000000000000000000000000001",
+    ] {
+        let row = client
+            .query_one(&*format!("SELECT {}::NUMERIC", value), &[])
+            .await
+            .unwrap();
+        assert!(row.try_get::<_, Decimal>(0).is_err(), "{}", value);
+    }
+}
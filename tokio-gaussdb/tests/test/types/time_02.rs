@@ -1,4 +1,4 @@
-use time_02::{OffsetDateTime, PrimitiveDateTime};
+use time_02::{OffsetDateTime, PrimitiveDateTime, UtcOffset};
 use tokio_gaussdb::types::{Date, Timestamp};
 
 use crate::types::test_type;
@@ -148,3 +148,26 @@ async fn test_time_params() {
     )
     .await;
 }
+
+#[tokio::test]
+async fn test_timetz_params() {
+    fn make_check(time: &str, offset: i8) -> (Option<(time_02::Time, UtcOffset)>, String) {
+        (
+            Some((
+                time_02::Time::parse(time, "%H:%M:%S").unwrap(),
+                UtcOffset::hours(offset),
+            )),
+            format!("'{}{:+03}'", time, offset),
+        )
+    }
+    test_type(
+        "TIMETZ",
+        &[
+            make_check("00:00:00", 0),
+            make_check("11:19:33", 5),
+            make_check("23:11:45", -8),
+            (None, "NULL".to_string()),
+        ],
+    )
+    .await;
+}
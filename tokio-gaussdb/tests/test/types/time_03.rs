@@ -1,12 +1,14 @@
+use std::convert::TryFrom;
 use std::fmt;
 
 use gaussdb_types::FromSqlOwned;
-use time_03::{format_description, OffsetDateTime, PrimitiveDateTime};
+use time_03::{format_description, Duration, OffsetDateTime, PrimitiveDateTime, UtcOffset};
 use tokio_gaussdb::{
-    types::{Date, Timestamp},
+    types::{Date, Interval, Timestamp},
     Client,
 };
 
+use crate::connect;
 use crate::types::test_type;
 
 // time 0.2 does not [yet?] support parsing fractional seconds
@@ -154,6 +156,33 @@ async fn test_time_params() {
     .await;
 }
 
+#[tokio::test]
+async fn test_timetz_params() {
+    fn make_check(
+        (hour, minute, second): (u8, u8, u8),
+        offset: i8,
+        time: &str,
+    ) -> (Option<(time_03::Time, UtcOffset)>, &str) {
+        (
+            Some((
+                time_03::Time::from_hms(hour, minute, second).unwrap(),
+                UtcOffset::from_hms(offset, 0, 0).unwrap(),
+            )),
+            time,
+        )
+    }
+    test_type(
+        "TIMETZ",
+        &[
+            make_check((0, 0, 0), 0, "'00:00:00+00'"),
+            make_check((11, 19, 33), 5, "'11:19:33+05'"),
+            make_check((23, 11, 45), -8, "'23:11:45-08'"),
+            (None, "NULL"),
+        ],
+    )
+    .await;
+}
+
 #[tokio::test]
 async fn test_special_params_without_wrapper() {
     async fn assert_overflows<T>(client: &mut Client, val: &str, sql_type: &str)
@@ -183,3 +212,21 @@ async fn test_special_params_without_wrapper() {
     assert_overflows::<time_03::Date>(&mut client, "'-infinity'", "date").await;
     assert_overflows::<time_03::Date>(&mut client, "'infinity'", "date").await;
 }
+
+#[tokio::test]
+async fn test_interval_duration() {
+    let client = connect("user=postgres").await;
+
+    let interval = client
+        .query_one("SELECT INTERVAL '-1 day 00:00:00.25'", &[])
+        .await
+        .unwrap()
+        .get::<_, Interval>(0);
+    let duration = Duration::try_from(interval).unwrap();
+    assert_eq!(duration, Duration::microseconds(-86_399_750_000));
+
+    let interval = Interval::try_from(duration).unwrap();
+    assert_eq!(interval, Interval::new(0, 0, -86_399_750_000));
+    assert!(Duration::try_from(Interval::new(-1, 0, 0)).is_err());
+    assert!(Interval::try_from(Duration::MAX).is_err());
+}